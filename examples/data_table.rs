@@ -0,0 +1,95 @@
+use iocraft::prelude::*;
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Server {
+    name: &'static str,
+    region: &'static str,
+    cpus: u32,
+    memory_gb: u32,
+    healthy: bool,
+}
+
+fn servers() -> Vec<Server> {
+    let server = |name, region, cpus, memory_gb, healthy| Server {
+        name,
+        region,
+        cpus,
+        memory_gb,
+        healthy,
+    };
+    vec![
+        server("web-1", "us-east", 4, 16, true),
+        server("web-2", "us-east", 4, 16, false),
+        server("web-3", "eu-west", 8, 32, true),
+        server("db-1", "us-east", 16, 128, true),
+        server("db-2", "eu-west", 16, 128, true),
+        server("cache-1", "ap-south", 2, 64, true),
+        server("worker-1", "ap-south", 32, 64, false),
+    ]
+}
+
+#[derive(Default, Props)]
+struct ServersProps<'a> {
+    export_out: Option<&'a mut Option<DataExport>>,
+}
+
+#[component]
+fn Servers<'a>(props: &mut ServersProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'a>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut export = hooks.use_state(|| None);
+    let mut should_exit = hooks.use_state(|| false);
+
+    hooks.use_terminal_events(move |event| match event {
+        TerminalEvent::Key(KeyEvent {
+            code: KeyCode::Char('q'),
+            kind,
+            ..
+        }) if kind != KeyEventKind::Release => should_exit.set(true),
+        _ => {}
+    });
+
+    if let Some(export) = export.read().clone() {
+        if let Some(export_out) = props.export_out.as_mut() {
+            **export_out = Some(export);
+        }
+        system.exit();
+    } else if should_exit.get() {
+        system.exit();
+    }
+
+    element! {
+        View(flex_direction: FlexDirection::Column) {
+            DataTable(
+                rows: servers(),
+                has_focus: true,
+                border_style: BorderStyle::Round,
+                border_color: Color::Cyan,
+                column_dividers: true,
+                on_export: move |e| export.set(Some(e)),
+            )
+            Text(
+                content: "←/→: sort, r: reverse, /: filter, c/j: export as CSV/JSON, q: quit",
+                color: Color::DarkGrey,
+            )
+        }
+    }
+}
+
+fn main() {
+    // The same rows can be printed for machines without any interaction.
+    match std::env::args().nth(1).as_deref() {
+        Some("--csv") => return print!("{}", DataRows::from(servers()).to_csv()),
+        Some("--json") => return println!("{}", DataRows::from(servers()).to_json()),
+        _ => {}
+    }
+
+    let mut export: Option<DataExport> = None;
+    smol::block_on(element!(Servers(export_out: &mut export)).render_loop()).unwrap();
+    if let Some(export) = export {
+        print!("{}", export.content);
+        if export.format == DataExportFormat::Json {
+            println!();
+        }
+    }
+}
@@ -52,21 +52,19 @@ fn Form<'a>(props: &mut FormProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement
     let first_name = hooks.use_state(|| "".to_string());
     let last_name = hooks.use_state(|| "".to_string());
     let life_story = hooks.use_state(|| "".to_string());
+    let mut contact_method = hooks.use_state(|| Some(0));
+    let mut newsletter = hooks.use_state(|| false);
     let mut focus = hooks.use_state(|| 0);
     let mut should_submit = hooks.use_state(|| false);
 
     hooks.use_terminal_events(move |event| match event {
         TerminalEvent::Key(KeyEvent { code, kind, .. }) if kind != KeyEventKind::Release => {
             match code {
-                KeyCode::Enter | KeyCode::Char(' ') => {
-                    if focus == 3 {
-                        should_submit.set(true)
-                    }
-                }
-                KeyCode::BackTab => focus.set((focus + 3) % 4),
-                KeyCode::Tab => focus.set((focus + 1) % 4),
-                KeyCode::Up if focus != 2 => focus.set((focus + 3) % 4),
-                KeyCode::Down if focus != 2 => focus.set((focus + 1) % 4),
+                KeyCode::Enter | KeyCode::Char(' ') if focus == 5 => should_submit.set(true),
+                KeyCode::BackTab => focus.set((focus + 5) % 6),
+                KeyCode::Tab => focus.set((focus + 1) % 6),
+                KeyCode::Up if focus != 2 => focus.set((focus + 5) % 6),
+                KeyCode::Down if focus != 2 => focus.set((focus + 1) % 6),
                 _ => {}
             }
         }
@@ -100,10 +98,33 @@ fn Form<'a>(props: &mut FormProps<'a>, mut hooks: Hooks) -> impl Into<AnyElement
                 FormField(label: "First Name", value: first_name, has_focus: focus == 0)
                 FormField(label: "Last Name", value: last_name, has_focus: focus == 1)
                 FormField(label: "Life Story", value: life_story, has_focus: focus == 2, multiline: true)
+                View(width: 47, padding: 1) {
+                    View(width: 15) {
+                        Text(content: "Contact Me: ")
+                    }
+                    RadioGroup(
+                        options: vec!["Email".to_string(), "Phone".to_string(), "Never".to_string()],
+                        value: contact_method.get(),
+                        on_change: move |i| contact_method.set(Some(i)),
+                        has_focus: focus == 3,
+                        horizontal: true,
+                    )
+                }
+                View(width: 47, padding: 1) {
+                    View(width: 15) {
+                        Text(content: "Newsletter: ")
+                    }
+                    Switch(
+                        label: "Subscribe",
+                        on: newsletter.get(),
+                        on_change: move |on| newsletter.set(on),
+                        has_focus: focus == 4,
+                    )
+                }
                 View(
-                    border_style: if focus == 3 { BorderStyle::Round } else { BorderStyle::None },
+                    border_style: if focus == 5 { BorderStyle::Round } else { BorderStyle::None },
                     border_color: Color::Green,
-                    padding: if focus == 3 { 0 } else { 1 },
+                    padding: if focus == 5 { 0 } else { 1 },
                 ) {
                     Text(content: "Submit", color: Color::White, weight: Weight::Bold)
                 }
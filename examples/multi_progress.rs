@@ -0,0 +1,33 @@
+use iocraft::prelude::*;
+use std::{thread, time::Duration};
+
+fn main() {
+    let progress = MultiProgress::new();
+
+    let resolving = progress.add_indeterminate();
+    resolving.set_message("Resolving");
+    thread::sleep(Duration::from_secs(1));
+    resolving.finish_with_message("Resolved 4 packages");
+
+    let workers = ["serde", "tokio", "regex", "rand"]
+        .into_iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let bar = progress.add(100);
+            bar.set_message(format!("{:<6}", name));
+            let progress = progress.clone();
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    thread::sleep(Duration::from_millis(20 + 15 * i as u64));
+                    bar.inc(1);
+                }
+                progress.println(format!("Downloaded {}", name));
+                bar.finish();
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for worker in workers {
+        worker.join().unwrap();
+    }
+}
@@ -34,6 +34,17 @@ fn main() {
             ) {
                 Text(content: "This element is overlapping the text!")
             }
+            View(
+                background_color: Color::DarkBlue,
+                padding_left: 1,
+                padding_right: 1,
+                position: Position::Absolute,
+                top: 10,
+                left: 40,
+                z_index: 1,
+            ) {
+                Text(content: "A z-index puts us on top!")
+            }
             View(
                 background_color: Color::Reset,
                 border_color: Color::Red,
@@ -1,35 +1,52 @@
 use iocraft::prelude::*;
 use std::time::Duration;
 
+const TOTAL_BYTES: u64 = 5 * 1024 * 1024;
+
 #[component]
-fn ProgressBar(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+fn Download(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     let mut system = hooks.use_context_mut::<SystemContext>();
-    let mut progress = hooks.use_state::<f32, _>(|| 0.0);
+    let mut connected = hooks.use_state(|| false);
+    let mut downloaded = hooks.use_state(|| 0u64);
 
     hooks.use_future(async move {
-        loop {
-            smol::Timer::after(Duration::from_millis(100)).await;
-            progress.set((progress.get() + 2.0).min(100.0));
+        smol::Timer::after(Duration::from_secs(2)).await;
+        connected.set(true);
+        for i in 0.. {
+            smol::Timer::after(Duration::from_millis(50)).await;
+            // Vary the speed a little so that the rate and ETA have something to show.
+            let chunk = if i % 40 < 20 { 48 * 1024 } else { 16 * 1024 };
+            downloaded.set((downloaded.get() + chunk).min(TOTAL_BYTES));
         }
     });
 
-    if progress >= 100.0 {
+    if downloaded.get() >= TOTAL_BYTES {
         system.exit();
     }
 
     element! {
-        View {
-            View(border_style: BorderStyle::Round, border_color: Color::Blue, width: 60) {
-                View(width: Percent(progress.get()), height: 1, background_color: Color::Green)
-            }
-            View(padding: 1) {
-                Text(content: format!("{:.0}%", progress))
-            }
+        View(border_style: BorderStyle::Round, border_color: Color::Blue, width: 80, padding_left: 1, padding_right: 1) {
+            #(if connected.get() {
+                element! {
+                    ProgressBar(
+                        value: downloaded.get(),
+                        total: TOTAL_BYTES,
+                        template: "{bar} {percent} {rate} {elapsed} ETA {eta}",
+                        unit: "B",
+                        color: Color::Green,
+                        flex_grow: 1.0,
+                    )
+                }
+            } else {
+                element! {
+                    ProgressBar(template: "{bar} Connecting... {elapsed}", color: Color::Yellow, flex_grow: 1.0)
+                }
+            })
         }
     }
 }
 
 fn main() {
-    smol::block_on(element!(ProgressBar).render_loop()).unwrap();
+    smol::block_on(element!(Download).render_loop()).unwrap();
     println!("done!");
 }
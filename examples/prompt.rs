@@ -0,0 +1,20 @@
+use iocraft::prompt;
+use std::io;
+
+fn main() -> io::Result<()> {
+    let name = prompt::blocking::input("What's your name?")?;
+    let _password = prompt::blocking::password("Choose a password:")?;
+    let languages = ["Rust", "Go", "Python", "TypeScript"];
+    let favorite = prompt::blocking::select("What's your favorite language?", languages)?;
+    let others = prompt::blocking::multi_select("Which others do you use?", languages)?;
+
+    if prompt::blocking::confirm("Show a summary?", true)? {
+        println!(
+            "{} likes {} and also uses {} other language(s).",
+            name,
+            languages[favorite],
+            others.iter().filter(|&&i| i != favorite).count()
+        );
+    }
+    Ok(())
+}
@@ -8,19 +8,15 @@ struct Props<'a> {
 #[component]
 fn Example<'a>(props: &Props<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
     let mut system = hooks.use_context_mut::<SystemContext>();
-    let mut scroll_offset = hooks.use_state(|| 0i32);
     let mut should_exit = hooks.use_state(|| false);
 
     hooks.use_terminal_events({
         move |event| match event {
-            TerminalEvent::Key(KeyEvent { code, kind, .. }) if kind != KeyEventKind::Release => {
-                match code {
-                    KeyCode::Char('q') => should_exit.set(true),
-                    KeyCode::Up => scroll_offset.set((scroll_offset.get() - 1).max(0)),
-                    KeyCode::Down => scroll_offset.set(scroll_offset.get() + 1),
-                    _ => {}
-                }
-            }
+            TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Char('q'),
+                kind,
+                ..
+            }) if kind != KeyEventKind::Release => should_exit.set(true),
             _ => {}
         }
     });
@@ -35,19 +31,15 @@ fn Example<'a>(props: &Props<'a>, mut hooks: Hooks) -> impl Into<AnyElement<'sta
             padding: 2,
             align_items: AlignItems::Center
         ) {
-            Text(content: "Use arrow keys to scroll. Press \"q\" to exit.")
+            Text(content: "Use arrow keys, Page Up/Down and Home/End to scroll. Press \"q\" to exit.")
             View(
                 border_style: BorderStyle::DoubleLeftRight,
                 border_color: Color::Green,
                 margin: 1,
                 width: 78,
                 height: 10,
-                overflow: Overflow::Hidden,
             ) {
-                View(
-                    position: Position::Absolute,
-                    top: -scroll_offset.get(),
-                ) {
+                ScrollView(flex_grow: 1.0, has_focus: true, show_scrollbars: true) {
                     Text(content: props.text)
                 }
             }
@@ -69,6 +69,20 @@ impl Parse for ParsedElement {
     }
 }
 
+// Converts literals with unit suffixes such as `50pct` or `1fr` into their corresponding types.
+fn unit_literal(lit: &Lit) -> Option<proc_macro2::TokenStream> {
+    let (value, suffix) = match lit {
+        Lit::Int(lit) => (lit.base10_parse::<f32>().unwrap(), lit.suffix()),
+        Lit::Float(lit) => (lit.base10_parse::<f32>().unwrap(), lit.suffix()),
+        _ => return None,
+    };
+    match suffix {
+        "pct" => Some(quote!(::iocraft::Percent(#value))),
+        "fr" => Some(quote!(::iocraft::Fraction(#value))),
+        _ => None,
+    }
+}
+
 impl ToTokens for ParsedElement {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let ty = &self.ty;
@@ -90,17 +104,23 @@ impl ToTokens for ParsedElement {
             .filter_map(|FieldValue { member, expr, .. }| match member {
                 Member::Named(ident) if ident == "key" => None,
                 _ => Some(match expr {
-                    Expr::Lit(lit) => match &lit.lit {
-                        Lit::Int(lit) if lit.suffix() == "pct" => {
-                            let value = lit.base10_parse::<f32>().unwrap();
-                            quote!(_iocraft_props.#member = ::iocraft::Percent(#value).into())
-                        }
-                        Lit::Float(lit) if lit.suffix() == "pct" => {
-                            let value = lit.base10_parse::<f32>().unwrap();
-                            quote!(_iocraft_props.#member = ::iocraft::Percent(#value).into())
-                        }
-                        _ => quote!(_iocraft_props.#member = (#expr).into()),
+                    Expr::Lit(lit) => match unit_literal(&lit.lit) {
+                        Some(value) => quote!(_iocraft_props.#member = #value.into()),
+                        None => quote!(_iocraft_props.#member = (#expr).into()),
                     },
+                    // Arrays containing unit literals, e.g. `[1fr, 10, 2fr]`, typically mix
+                    // types, so each element is converted individually.
+                    Expr::Array(array)
+                        if array.elems.iter().any(
+                            |elem| matches!(elem, Expr::Lit(lit) if unit_literal(&lit.lit).is_some()),
+                        ) =>
+                    {
+                        let elems = array.elems.iter().map(|elem| match elem {
+                            Expr::Lit(lit) => unit_literal(&lit.lit).unwrap_or_else(|| quote!(#elem)),
+                            _ => quote!(#elem),
+                        });
+                        quote!(::iocraft::set_items(&mut _iocraft_props.#member, [#((#elems).into()),*]))
+                    }
                     _ => quote!(_iocraft_props.#member = (#expr).into()),
                 }),
             })
@@ -684,6 +704,20 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             /// Sets the maximum height of the element.
             pub max_height: ::iocraft::Size
         },
+        quote! {
+            /// Sets the preferred ratio of the element's width to its height. This is used to
+            /// calculate one dimension when only the other is known.
+            ///
+            /// See [the MDN documentation for aspect-ratio](https://developer.mozilla.org/en-US/docs/Web/CSS/aspect-ratio).
+            pub aspect_ratio: Option<f32>
+        },
+        quote! {
+            /// Controls whether the width and height include the element's padding and border.
+            /// Defaults to [`BoxSizing::BorderBox`](::iocraft::BoxSizing::BorderBox).
+            ///
+            /// See [the MDN documentation for box-sizing](https://developer.mozilla.org/en-US/docs/Web/CSS/box-sizing).
+            pub box_sizing: ::iocraft::BoxSizing
+        },
         quote! {
             /// Defines the gaps in between rows or columns of flex items.
             ///
@@ -828,12 +862,41 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             /// See [the MDN documentation for flex-shrink](https://developer.mozilla.org/en-US/docs/Web/CSS/flex-shrink).
             pub flex_shrink: Option<f32>
         },
+        quote! {
+            /// Sets the order in which the element is laid out relative to its siblings. Elements
+            /// are laid out in ascending order, with elements of the same order keeping their
+            /// order in the tree. This only affects layout. Use `z_index` to change which
+            /// elements are drawn on top.
+            ///
+            /// See [the MDN documentation for order](https://developer.mozilla.org/en-US/docs/Web/CSS/order).
+            pub order: i32
+        },
         quote! {
             /// Controls the alignment of items along the cross axis of a flex container.
             ///
             /// See [the MDN documentation for align-items](https://developer.mozilla.org/en-US/docs/Web/CSS/align-items).
             pub align_items: Option<::iocraft::AlignItems>
         },
+        quote! {
+            /// Controls the alignment of the element along the cross axis of its flex container,
+            /// overriding the container's `align_items`.
+            ///
+            /// See [the MDN documentation for align-self](https://developer.mozilla.org/en-US/docs/Web/CSS/align-self).
+            pub align_self: Option<::iocraft::AlignSelf>
+        },
+        quote! {
+            /// Controls the alignment of items within their grid areas along the row axis.
+            ///
+            /// See [the MDN documentation for justify-items](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-items).
+            pub justify_items: Option<::iocraft::JustifyItems>
+        },
+        quote! {
+            /// Controls the alignment of the element within its grid area along the row axis,
+            /// overriding the container's `justify_items`.
+            ///
+            /// See [the MDN documentation for justify-self](https://developer.mozilla.org/en-US/docs/Web/CSS/justify-self).
+            pub justify_self: Option<::iocraft::JustifySelf>
+        },
         quote! {
             /// Controls the distribution of space between and around items along a flex container's cross axis.
             ///
@@ -864,6 +927,59 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             /// See [the MDN documentation for overflow](https://developer.mozilla.org/en-US/docs/Web/CSS/overflow).
             pub overflow_y: Option<::iocraft::Overflow>
         },
+        quote! {
+            /// Defines the sizes of the rows of a grid container. Values can be written using the
+            /// `fr` and `pct` suffixes, e.g. `[1fr, 10, 2fr]`.
+            ///
+            /// See [the MDN documentation for grid-template-rows](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-rows).
+            pub grid_template_rows: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the sizes of the columns of a grid container. Values can be written using
+            /// the `fr` and `pct` suffixes, e.g. `[1fr, 10, 2fr]`.
+            ///
+            /// See [the MDN documentation for grid-template-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns).
+            pub grid_template_columns: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the sizes of rows which are created implicitly in a grid container.
+            ///
+            /// See [the MDN documentation for grid-auto-rows](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-rows).
+            pub grid_auto_rows: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Defines the sizes of columns which are created implicitly in a grid container.
+            ///
+            /// See [the MDN documentation for grid-auto-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-columns).
+            pub grid_auto_columns: Vec<::iocraft::GridTrack>
+        },
+        quote! {
+            /// Controls how items which aren't explicitly placed are flowed into a grid container.
+            ///
+            /// See [the MDN documentation for grid-auto-flow](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-auto-flow).
+            pub grid_auto_flow: ::iocraft::GridAutoFlow
+        },
+        quote! {
+            /// Sets the rows a grid item occupies, e.g. `2` or `1..3`.
+            ///
+            /// See [the MDN documentation for grid-row](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row).
+            pub grid_row: ::iocraft::GridLines
+        },
+        quote! {
+            /// Sets the columns a grid item occupies, e.g. `2` or `1..3`.
+            ///
+            /// See [the MDN documentation for grid-column](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-column).
+            pub grid_column: ::iocraft::GridLines
+        },
+        quote! {
+            /// Sets the stacking order of the element and its descendants. Elements with a higher
+            /// z-index are drawn on top of elements with a lower one, regardless of where they are
+            /// in the tree. Elements without a z-index inherit the stacking order of their parent,
+            /// with the root being at 0.
+            ///
+            /// See [the MDN documentation for z-index](https://developer.mozilla.org/en-US/docs/Web/CSS/z-index).
+            pub z_index: Option<i32>
+        },
     ]
     .map(|tokens| syn::Field::parse_named.parse2(tokens).unwrap());
 
@@ -877,7 +993,7 @@ pub fn with_layout_style_props(_attr: TokenStream, item: TokenStream) -> TokenSt
             let struct_name = &ast.ident;
             let field_assignments = layout_style_fields.iter().map(|field| {
                 let field_name = &field.ident;
-                quote! { ret.#field_name = self.#field_name; }
+                quote! { ret.#field_name = ::core::clone::Clone::clone(&self.#field_name); }
             });
 
             let where_clause = &ast.generics.where_clause;
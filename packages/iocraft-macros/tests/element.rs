@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use iocraft::{element, AnyElement, Component, Element, Percent, Props};
+use iocraft::{element, AnyElement, Component, Element, Fraction, GridTrack, Percent, Props};
 
 #[derive(Default)]
 struct MyComponent;
@@ -9,6 +9,8 @@ struct MyComponent;
 struct MyComponentProps {
     foo: String,
     percent: Percent,
+    fraction: Fraction,
+    tracks: Vec<GridTrack>,
     children: Vec<Element<'static, MyComponent>>,
 }
 
@@ -144,6 +146,24 @@ fn percent() {
     assert_eq!(e.props.children.len(), 2);
 }
 
+#[test]
+fn fraction() {
+    let e = element! {
+        MyComponent(fraction: 2fr, tracks: [1fr, 10, 50pct, GridTrack::Auto, 0.5fr])
+    };
+    assert_eq!(e.props.fraction, Fraction(2.0));
+    assert_eq!(
+        e.props.tracks,
+        vec![
+            GridTrack::Fraction(1.0),
+            GridTrack::Length(10),
+            GridTrack::Percent(50.0),
+            GridTrack::Auto,
+            GridTrack::Fraction(0.5),
+        ]
+    );
+}
+
 #[test]
 fn comment() {
     let e = element! {
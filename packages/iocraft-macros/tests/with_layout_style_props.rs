@@ -1,4 +1,5 @@
-use iocraft::Display;
+use indoc::indoc;
+use iocraft::prelude::*;
 use iocraft_macros::{with_layout_style_props, Props};
 
 #[with_layout_style_props]
@@ -25,6 +26,33 @@ struct MyPropsWithConstParam<const N: usize> {
     foo: Option<[u8; N]>,
 }
 
+#[with_layout_style_props]
+#[derive(Default, Props)]
+struct MyContainerProps {
+    children: Vec<AnyElement<'static>>,
+}
+
+#[derive(Default)]
+struct MyContainer;
+
+impl Component for MyContainer {
+    type Props<'a> = MyContainerProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        updater.set_layout_style_props(props.layout_style());
+        updater.update_children(props.children.iter_mut(), None);
+    }
+}
+
 #[test]
 fn layout_style_props() {
     let props: MyProps = Default::default();
@@ -43,3 +71,164 @@ fn layout_style_props() {
     assert_eq!(props.foo, None);
     assert_eq!(props.display, Display::DEFAULT);
 }
+
+#[test]
+fn layout_style_props_passthrough() {
+    let props = MyProps {
+        align_self: Some(AlignSelf::End),
+        justify_self: Some(JustifySelf::Center),
+        justify_items: Some(JustifyItems::Start),
+        aspect_ratio: Some(2.0),
+        box_sizing: BoxSizing::ContentBox,
+        order: -1,
+        width: Size::MaxContent,
+        ..Default::default()
+    };
+    let style = props.layout_style();
+    assert_eq!(style.align_self, Some(AlignSelf::End));
+    assert_eq!(style.justify_self, Some(JustifySelf::Center));
+    assert_eq!(style.justify_items, Some(JustifyItems::Start));
+    assert_eq!(style.aspect_ratio, Some(2.0));
+    assert_eq!(style.box_sizing, BoxSizing::ContentBox);
+    assert_eq!(style.order, -1);
+    assert_eq!(style.width, Size::MaxContent);
+}
+
+#[test]
+fn align_self() {
+    let actual = element! {
+        View(width: 4, height: 3, align_items: AlignItems::Start) {
+            Text(content: "a")
+            View(align_self: AlignSelf::End) {
+                Text(content: "b")
+            }
+        }
+    }
+    .to_string();
+    assert_eq!(actual, "a\n\n b\n");
+}
+
+#[test]
+fn justify_items_and_self() {
+    let actual = element! {
+        View(display: Display::Grid, width: 4, grid_template_columns: [1fr], justify_items: JustifyItems::End) {
+            Text(content: "a")
+            View(justify_self: JustifySelf::Start) {
+                Text(content: "b")
+            }
+        }
+    }
+    .to_string();
+    assert_eq!(actual, "   a\nb\n");
+}
+
+#[test]
+fn aspect_ratio() {
+    let actual = element! {
+        View(width: 4, aspect_ratio: 2.0, border_style: BorderStyle::Single)
+    }
+    .to_string();
+    assert_eq!(actual, "┌──┐\n└──┘\n");
+}
+
+#[test]
+fn box_sizing() {
+    let actual = element! {
+        View(flex_direction: FlexDirection::Column) {
+            View(width: 4, border_style: BorderStyle::Single) {
+                Text(content: "ab")
+            }
+            View(width: 2, border_style: BorderStyle::Single, box_sizing: BoxSizing::ContentBox) {
+                Text(content: "ab")
+            }
+        }
+    }
+    .to_string();
+    assert_eq!(actual, "┌──┐\n│ab│\n└──┘\n┌──┐\n│ab│\n└──┘\n");
+}
+
+#[test]
+fn order() {
+    let actual = element! {
+        View {
+            Text(content: "a")
+            View(order: 1) {
+                Text(content: "b")
+            }
+            View(order: -1) {
+                Text(content: "c")
+            }
+            Text(content: "d")
+        }
+    }
+    .to_string();
+    assert_eq!(actual, "cadb\n");
+}
+
+#[test]
+fn content_sizes() {
+    let actual = element! {
+        View(width: 20, flex_direction: FlexDirection::Column) {
+            View(width: Size::MinContent, border_style: BorderStyle::Single) {
+                Text(content: "foo bar")
+            }
+            View(width: Size::MaxContent, border_style: BorderStyle::Single) {
+                Text(content: "foo bar")
+            }
+            View(width: 8) {
+                View(width: Size::FitContent, border_style: BorderStyle::Single) {
+                    Text(content: "foo bar baz")
+                }
+            }
+            View(width: 20) {
+                View(width: Size::FitContent, border_style: BorderStyle::Single) {
+                    Text(content: "foo bar baz")
+                }
+            }
+        }
+    }
+    .to_string();
+    assert_eq!(
+        actual,
+        indoc! {"
+            ┌───┐
+            │foo│
+            │bar│
+            └───┘
+            ┌───────┐
+            │foo bar│
+            └───────┘
+            ┌──────┐
+            │foo   │
+            │bar   │
+            │baz   │
+            └──────┘
+            ┌───────────┐
+            │foo bar baz│
+            └───────────┘
+        "}
+    );
+}
+
+#[test]
+fn third_party_component() {
+    // Props which aren't part of the layout engine's style are applied to third-party components
+    // too.
+    let actual = element! {
+        View(width: 20, flex_direction: FlexDirection::Column) {
+            MyContainer(width: Size::MaxContent) {
+                Text(content: "a")
+                MyContainer(order: -1) {
+                    Text(content: "b")
+                }
+            }
+            View(width: 1, flex_direction: FlexDirection::Column) {
+                MyContainer(width: Size::MaxContent) {
+                    Text(content: "foo bar")
+                }
+            }
+        }
+    }
+    .to_string();
+    assert_eq!(actual, "ba\nfoo bar\n");
+}
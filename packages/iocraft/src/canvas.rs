@@ -1,15 +1,17 @@
-use crate::style::{Color, Weight};
+use crate::style::{Color, ColorDepth, UnderlineStyle, Weight};
 use crossterm::{
-    csi,
+    csi, cursor,
     style::{Attribute, Colored},
 };
 use std::{
     env,
     fmt::{self, Display},
     io::{self, Write},
-    sync::Once,
+    ops::Range,
+    sync::{Arc, Once},
 };
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 #[derive(Clone, Debug, PartialEq)]
 struct Character {
@@ -56,7 +58,7 @@ impl Character {
 
 /// Describes the style of text to be rendered via a [`Canvas`].
 #[non_exhaustive]
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct CanvasTextStyle {
     /// The color of the text.
     pub color: Option<Color>,
@@ -67,11 +69,80 @@ pub struct CanvasTextStyle {
     /// Whether the text is underlined.
     pub underline: bool,
 
+    /// The style of the underline, if the text is underlined.
+    pub underline_style: UnderlineStyle,
+
+    /// The color of the underline, if the text is underlined. If `None`, the text color is used.
+    pub underline_color: Option<Color>,
+
     /// Whether the text is italicized.
     pub italic: bool,
+
+    /// Whether the text has a line through it.
+    pub strikethrough: bool,
+
+    /// Whether the text has a line above it.
+    pub overline: bool,
+
+    /// Whether the foreground and background colors of the text are swapped.
+    pub reverse: bool,
+
+    /// Whether the text blinks.
+    pub blink: bool,
+
+    /// Whether the text is hidden. The space it occupies will still be filled with the
+    /// background color.
+    pub hidden: bool,
+
+    /// The URL that the text links to, if any. In terminals that support it, the text will be
+    /// rendered as a clickable hyperlink.
+    pub link: Option<Arc<str>>,
+}
+
+impl CanvasTextStyle {
+    // Returns whether the style is visible even on whitespace.
+    fn is_visible_on_whitespace(&self) -> bool {
+        self.underline || self.strikethrough || self.overline || self.reverse
+    }
+
+    // Returns the value of the CSS `text-decoration` property for the style, if any, using the
+    // given function to format the underline color.
+    fn css_text_decoration(
+        &self,
+        format_color: impl Fn(Color) -> Option<String>,
+    ) -> Option<String> {
+        let mut values = Vec::new();
+        if self.underline {
+            values.push("underline");
+        }
+        if self.strikethrough {
+            values.push("line-through");
+        }
+        if self.overline {
+            values.push("overline");
+        }
+        if values.is_empty() {
+            return None;
+        }
+        if self.underline {
+            match self.underline_style {
+                UnderlineStyle::Single => {}
+                UnderlineStyle::Double => values.push("double"),
+                UnderlineStyle::Curly => values.push("wavy"),
+                UnderlineStyle::Dotted => values.push("dotted"),
+                UnderlineStyle::Dashed => values.push("dashed"),
+            }
+        }
+        let mut ret = values.join(" ");
+        if let Some(color) = self.underline_color.and_then(format_color) {
+            ret.push(' ');
+            ret.push_str(&color);
+        }
+        Some(ret)
+    }
 }
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Debug, Default)]
 struct Cell {
     background_color: Option<Color>,
     character: Option<Character>,
@@ -81,6 +152,370 @@ impl Cell {
     fn is_empty(&self) -> bool {
         self.background_color.is_none() && self.character.is_none()
     }
+
+    // Returns the character, unless it's a space with no visible styling, in which case the cell
+    // looks the same as an empty one.
+    fn visible_character(&self) -> Option<&Character> {
+        self.character
+            .as_ref()
+            .filter(|c| c.value != " " || c.style.is_visible_on_whitespace())
+    }
+}
+
+impl PartialEq for Cell {
+    fn eq(&self, other: &Self) -> bool {
+        self.background_color == other.background_color
+            && self.visible_character() == other.visible_character()
+    }
+}
+
+/// Tracks the styles that are currently active in the terminal while writing ANSI output.
+struct AnsiState {
+    color_depth: ColorDepth,
+    // Colors are tracked after being downgraded to the color depth.
+    background_color: Option<Color>,
+    text_style: CanvasTextStyle,
+    // Hyperlinks are tracked separately since they aren't affected by SGR resets.
+    link: Option<Arc<str>>,
+}
+
+impl AnsiState {
+    fn new(color_depth: ColorDepth) -> Self {
+        Self {
+            color_depth,
+            background_color: None,
+            text_style: CanvasTextStyle::default(),
+            link: None,
+        }
+    }
+
+    fn downgrade(&self, color: Option<Color>) -> Option<Color> {
+        color.and_then(|c| self.color_depth.downgrade(c))
+    }
+
+    /// Writes the escape codes necessary to transition from the current styles to the cell's.
+    fn apply<W: Write>(&mut self, w: &mut W, cell: &Cell) -> io::Result<()> {
+        self.set_link(
+            w,
+            cell.character.as_ref().and_then(|c| c.style.link.as_ref()),
+        )?;
+
+        // For certain changes, we need to reset all attributes.
+        let mut needs_reset = false;
+        if let Some(c) = &cell.character {
+            let (new, current) = (&c.style, &self.text_style);
+            if new.weight != current.weight && new.weight == Weight::Normal {
+                needs_reset = true;
+            }
+            for (new, current) in [
+                (new.underline, current.underline),
+                (new.italic, current.italic),
+                (new.strikethrough, current.strikethrough),
+                (new.overline, current.overline),
+                (new.reverse, current.reverse),
+                (new.blink, current.blink),
+                (new.hidden, current.hidden),
+            ] {
+                if !new && current {
+                    needs_reset = true;
+                }
+            }
+        } else if self.text_style.is_visible_on_whitespace() {
+            needs_reset = true;
+        }
+        if needs_reset {
+            write!(w, csi!("0m"))?;
+            self.background_color = None;
+            self.text_style = CanvasTextStyle::default();
+        }
+
+        let background_color = self.downgrade(cell.background_color);
+        if background_color != self.background_color {
+            write!(
+                w,
+                csi!("{}m"),
+                Colored::BackgroundColor(background_color.unwrap_or(Color::Reset))
+            )?;
+            self.background_color = background_color;
+        }
+
+        if let Some(c) = &cell.character {
+            let (new, current) = (&c.style, &self.text_style);
+            let color = self.downgrade(new.color);
+            let underline_color = self.downgrade(new.underline_color);
+
+            if color != current.color {
+                write!(
+                    w,
+                    csi!("{}m"),
+                    Colored::ForegroundColor(color.unwrap_or(Color::Reset))
+                )?;
+            }
+
+            if new.weight != current.weight {
+                match new.weight {
+                    Weight::Bold => write!(w, csi!("{}m"), Attribute::Bold.sgr())?,
+                    Weight::Normal => {}
+                    Weight::Light => write!(w, csi!("{}m"), Attribute::Dim.sgr())?,
+                }
+            }
+
+            if new.underline
+                && (!current.underline || new.underline_style != current.underline_style)
+            {
+                let attribute = match new.underline_style {
+                    UnderlineStyle::Single => Attribute::Underlined,
+                    UnderlineStyle::Double => Attribute::DoubleUnderlined,
+                    UnderlineStyle::Curly => Attribute::Undercurled,
+                    UnderlineStyle::Dotted => Attribute::Underdotted,
+                    UnderlineStyle::Dashed => Attribute::Underdashed,
+                };
+                write!(w, csi!("{}m"), attribute.sgr())?;
+            }
+
+            if underline_color != current.underline_color {
+                write!(
+                    w,
+                    csi!("{}m"),
+                    Colored::UnderlineColor(underline_color.unwrap_or(Color::Reset))
+                )?;
+            }
+
+            for (new, current, attribute) in [
+                (new.italic, current.italic, Attribute::Italic),
+                (
+                    new.strikethrough,
+                    current.strikethrough,
+                    Attribute::CrossedOut,
+                ),
+                (new.overline, current.overline, Attribute::OverLined),
+                (new.reverse, current.reverse, Attribute::Reverse),
+                (new.blink, current.blink, Attribute::SlowBlink),
+                (new.hidden, current.hidden, Attribute::Hidden),
+            ] {
+                if new && !current {
+                    write!(w, csi!("{}m"), attribute.sgr())?;
+                }
+            }
+
+            self.text_style = CanvasTextStyle {
+                color,
+                underline_color,
+                ..c.style.clone()
+            };
+        }
+        Ok(())
+    }
+
+    /// Writes the OSC 8 sequence necessary to start, change, or end a hyperlink.
+    fn set_link<W: Write>(&mut self, w: &mut W, link: Option<&Arc<str>>) -> io::Result<()> {
+        if self.link.as_ref() == link {
+            return Ok(());
+        }
+        match link {
+            Some(url) => {
+                // Control characters could be used to escape the sequence, so they're stripped.
+                let url: String = url.chars().filter(|c| !c.is_control()).collect();
+                // The id tells the terminal that links broken up by wrapping or by other
+                // output are the same link.
+                write!(w, "\x1b]8;id={:x};{}\x1b\\", link_id(&url), url)?;
+            }
+            None => w.write_all(b"\x1b]8;;\x1b\\")?,
+        }
+        self.link = link.cloned();
+        Ok(())
+    }
+}
+
+// Derives a stable identifier for a hyperlink from its URL using FNV-1a.
+fn link_id(url: &str) -> u64 {
+    url.bytes().fold(0xcbf29ce484222325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// Removes the character which covers the given column, if it begins before it. This prevents wide
+// characters from being partially drawn over content written on top of them.
+fn clear_overlapping_character(row: &mut [Cell], x: usize) {
+    let start = char_start(row, x);
+    if start < x {
+        row[start].character = None;
+    }
+}
+
+// Writes a character to the given column, removing any characters that it covers, and returns its
+// width.
+fn set_cell_character(row: &mut [Cell], x: usize, value: String, style: CanvasTextStyle) -> usize {
+    let width = value.width().max(1);
+    clear_overlapping_character(row, x);
+    for cell in row.iter_mut().take(x + width).skip(x + 1) {
+        cell.character = None;
+    }
+    row[x].character = Some(Character { value, style });
+    width
+}
+
+static EMPTY_CELL: Cell = Cell {
+    background_color: None,
+    character: None,
+};
+
+fn cell_at(row: &[Cell], x: usize) -> &Cell {
+    row.get(x).unwrap_or(&EMPTY_CELL)
+}
+
+// Returns the column at which the character covering the given column begins.
+fn char_start(row: &[Cell], x: usize) -> usize {
+    (x.saturating_sub(2)..x)
+        .find(|&i| {
+            cell_at(row, i)
+                .character
+                .as_ref()
+                .is_some_and(|c| i + c.value.width() > x)
+        })
+        .unwrap_or(x)
+}
+
+// Returns the column just past the end of the character beginning at the given column.
+fn char_end(row: &[Cell], x: usize) -> usize {
+    x + cell_at(row, x)
+        .character
+        .as_ref()
+        .map_or(1, |c| c.value.width().max(1))
+}
+
+// The xterm palette for the 16 named ANSI colors.
+pub(crate) const XTERM_PALETTE: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0x80, 0x00, 0x00),
+    (0x00, 0x80, 0x00),
+    (0x80, 0x80, 0x00),
+    (0x00, 0x00, 0x80),
+    (0x80, 0x00, 0x80),
+    (0x00, 0x80, 0x80),
+    (0xc0, 0xc0, 0xc0),
+    (0x80, 0x80, 0x80),
+    (0xff, 0x00, 0x00),
+    (0x00, 0xff, 0x00),
+    (0xff, 0xff, 0x00),
+    (0x00, 0x00, 0xff),
+    (0xff, 0x00, 0xff),
+    (0x00, 0xff, 0xff),
+    (0xff, 0xff, 0xff),
+];
+
+// Returns the RGB equivalent of the given color, using the given palette for the 16 named ANSI
+// colors and the xterm palette for the remaining indexed colors.
+pub(crate) fn color_rgb(color: Color, palette: &[(u8, u8, u8); 16]) -> Option<(u8, u8, u8)> {
+    Some(match color {
+        Color::Reset => return None,
+        Color::Black => palette[0],
+        Color::DarkRed => palette[1],
+        Color::DarkGreen => palette[2],
+        Color::DarkYellow => palette[3],
+        Color::DarkBlue => palette[4],
+        Color::DarkMagenta => palette[5],
+        Color::DarkCyan => palette[6],
+        Color::Grey => palette[7],
+        Color::DarkGrey => palette[8],
+        Color::Red => palette[9],
+        Color::Green => palette[10],
+        Color::Yellow => palette[11],
+        Color::Blue => palette[12],
+        Color::Magenta => palette[13],
+        Color::Cyan => palette[14],
+        Color::White => palette[15],
+        Color::Rgb { r, g, b } => (r, g, b),
+        Color::AnsiValue(i @ 0..=15) => palette[i as usize],
+        Color::AnsiValue(i @ 16..=231) => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        Color::AnsiValue(i) => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    })
+}
+
+fn hex_color((r, g, b): (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+// Returns the CSS equivalent of the given color, using the xterm palette for named and indexed
+// colors.
+fn css_color(color: Color) -> Option<String> {
+    color_rgb(color, &XTERM_PALETTE).map(hex_color)
+}
+
+// Returns the inline CSS for the given cell, which may be empty if the cell is unstyled.
+fn html_style(cell: &Cell) -> String {
+    let mut props = Vec::new();
+    let background_color = cell.background_color.and_then(css_color);
+    match &cell.character {
+        Some(c) if c.style.reverse => {
+            // Without concrete default colors, we fall back to the browser's.
+            let color = c.style.color.and_then(css_color);
+            props.push(format!(
+                "background-color: {}",
+                color.as_deref().unwrap_or("CanvasText")
+            ));
+            props.push(format!(
+                "color: {}",
+                background_color.as_deref().unwrap_or("Canvas")
+            ));
+        }
+        _ => {
+            if let Some(color) = background_color {
+                props.push(format!("background-color: {}", color));
+            }
+            if let Some(color) = cell
+                .character
+                .as_ref()
+                .and_then(|c| c.style.color)
+                .and_then(css_color)
+            {
+                props.push(format!("color: {}", color));
+            }
+        }
+    }
+    if let Some(c) = &cell.character {
+        match c.style.weight {
+            Weight::Bold => props.push("font-weight: bold".to_string()),
+            Weight::Normal => {}
+            Weight::Light => props.push("opacity: 0.5".to_string()),
+        }
+        if c.style.italic {
+            props.push("font-style: italic".to_string());
+        }
+        if let Some(decoration) = c.style.css_text_decoration(css_color) {
+            props.push(format!("text-decoration: {}", decoration));
+        }
+        if c.style.hidden {
+            props.push("visibility: hidden".to_string());
+        }
+        // Blinking isn't supported by browsers, so it's omitted.
+    }
+    props.join("; ")
+}
+
+// Formats a number for use in SVG output, avoiding excessive precision.
+fn svg_number(v: f32) -> String {
+    format!("{}", (v * 100.0).round() / 100.0)
+}
+
+fn write_xml_escaped<W: Write>(w: &mut W, s: &str) -> io::Result<()> {
+    for c in s.chars() {
+        match c {
+            '&' => w.write_all(b"&amp;")?,
+            '<' => w.write_all(b"&lt;")?,
+            '>' => w.write_all(b"&gt;")?,
+            '"' => w.write_all(b"&quot;")?,
+            _ => write!(w, "{}", c)?,
+        }
+    }
+    Ok(())
 }
 
 /// `Canvas` is the medium that output is drawn to before being rendered to the terminal or other
@@ -90,7 +525,7 @@ impl Cell {
 ///
 /// - When implementing low-level components, you'll need to utilize the `Canvas` drawing methods.
 /// - When implementing unit tests for components, you may want to render to a `Canvas` for inspection.
-#[derive(Clone, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Canvas {
     width: usize,
     cells: Vec<Vec<Cell>>,
@@ -105,6 +540,22 @@ impl Canvas {
         }
     }
 
+    /// Constructs a canvas from text containing ANSI escape codes, such as the output of
+    /// [`Canvas::write_ansi`], by interpreting it the way a terminal of the given width would.
+    ///
+    /// SGR sequences for colors and text attributes are interpreted, as are cursor movement and
+    /// erase sequences. Newlines are treated as moving to the start of the next line. Other escape
+    /// sequences are ignored.
+    ///
+    /// The height of the canvas is determined by the final cursor position and the content
+    /// written. As on a terminal screen, cursor movement sequences can't move the cursor below the
+    /// lowest line reached by text or newlines.
+    pub fn from_ansi(s: &str, width: usize) -> Self {
+        let mut reader = AnsiReader::new(width);
+        reader.read(s);
+        reader.into_canvas()
+    }
+
     /// Returns the width of the canvas.
     pub fn width(&self) -> usize {
         self.width
@@ -115,13 +566,56 @@ impl Canvas {
         self.cells.len()
     }
 
+    /// Truncates the canvas so that it is no taller than the given height. If any rows need to be
+    /// removed, the last remaining row is replaced with an indicator of how many rows are hidden,
+    /// and the canvas is widened to fit the indicator if possible without exceeding `max_width`.
+    pub(crate) fn truncate_with_overflow_indicator(
+        &mut self,
+        max_width: Option<usize>,
+        max_height: usize,
+    ) {
+        if self.height() <= max_height {
+            return;
+        }
+        if max_height == 0 {
+            self.cells.clear();
+            return;
+        }
+        let hidden_rows = self.height() - (max_height - 1);
+        let indicator = format!(
+            "… {} more line{}",
+            hidden_rows,
+            if hidden_rows == 1 { "" } else { "s" }
+        );
+        let width = self
+            .width
+            .max(indicator.width().min(max_width.unwrap_or(usize::MAX)));
+        self.cells.truncate(max_height - 1);
+        self.cells.push(Vec::new());
+        for row in &mut self.cells {
+            row.resize(width, Cell::default());
+        }
+        self.width = width;
+        self.set_text_row_graphemes(
+            0,
+            max_height - 1,
+            indicator.graphemes(true),
+            CanvasTextStyle {
+                weight: Weight::Light,
+                ..Default::default()
+            },
+        );
+    }
+
     fn clear_text(&mut self, x: usize, y: usize, w: usize, h: usize) {
+        if w == 0 {
+            return;
+        }
         for y in y..y + h {
             if let Some(row) = self.cells.get_mut(y) {
-                for x in x..x + w {
-                    if x < row.len() {
-                        row[x].character = None;
-                    }
+                clear_overlapping_character(row, x);
+                for x in x..(x + w).min(row.len()) {
+                    row[x].character = None;
                 }
             }
         }
@@ -139,30 +633,31 @@ impl Canvas {
         }
     }
 
-    fn set_text_row_chars<I>(&mut self, mut x: usize, y: usize, chars: I, style: CanvasTextStyle)
-    where
-        I: IntoIterator<Item = char>,
+    fn set_text_row_graphemes<'a, I>(
+        &mut self,
+        mut x: usize,
+        y: usize,
+        graphemes: I,
+        style: CanvasTextStyle,
+    ) where
+        I: IntoIterator<Item = &'a str>,
     {
-        // Divide the string up into characters, which may consist of multiple Unicode code points.
+        // Each extended grapheme cluster occupies a single cell. Zero-width clusters, such as a
+        // combining mark at the start of the text, are attached to the preceding character.
         let row = &mut self.cells[y];
         let mut buf = String::new();
-        for c in chars.into_iter() {
+        for g in graphemes.into_iter() {
             if x >= row.len() {
                 break;
             }
-            let width = c.width().unwrap_or(0);
-            if width > 0 && !buf.is_empty() {
-                row[x].character = Some(Character {
-                    value: buf.clone(),
-                    style,
-                });
-                x += buf.width().max(1);
+            if g.width() > 0 && !buf.is_empty() {
+                x += set_cell_character(row, x, buf.clone(), style.clone());
                 buf.clear();
             }
-            buf.push(c);
+            buf.push_str(g);
         }
         if !buf.is_empty() && x < row.len() {
-            row[x].character = Some(Character { value: buf, style });
+            set_cell_character(row, x, buf, style);
         }
     }
 
@@ -175,7 +670,7 @@ impl Canvas {
         clip_y: isize,
         clip_width: usize,
         clip_height: usize,
-    ) -> CanvasSubviewMut {
+    ) -> CanvasSubviewMut<'_> {
         CanvasSubviewMut {
             y,
             x,
@@ -187,121 +682,99 @@ impl Canvas {
         }
     }
 
+    fn row(&self, y: usize) -> &[Cell] {
+        self.cells.get(y).map_or(&[], |row| row.as_slice())
+    }
+
+    /// Returns the number of columns in the row which need to be written, i.e. the columns up to
+    /// and including the last non-empty cell.
+    fn row_len(&self, y: usize) -> usize {
+        self.row(y)
+            .iter()
+            .rposition(|cell| !cell.is_empty())
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Writes the cells of a row in the given range. The range is expected to begin at a character
+    /// boundary.
+    ///
+    /// If `clear_line` is true, the remainder of the terminal line will be cleared after the cells
+    /// are written.
+    fn write_cells<W: Write>(
+        &self,
+        w: &mut W,
+        y: usize,
+        range: Range<usize>,
+        ansi: bool,
+        state: &mut AnsiState,
+        clear_line: bool,
+    ) -> io::Result<()> {
+        let row = self.row(y);
+        let end = range.end.min(row.len());
+        let mut col = range.start;
+        let mut did_clear_line = false;
+        while col < end {
+            let cell = &row[col];
+
+            if ansi {
+                state.apply(w, cell)?;
+            }
+
+            if let Some(c) = &cell.character {
+                col += c.value.width().max(1);
+            } else {
+                col += 1;
+            }
+
+            if ansi && clear_line && col >= self.width {
+                // go ahead and clear until end of line. we need to do this before writing
+                // the last character, because if we're at the end of the terminal row, the
+                // cursor won't change position and the last character would be erased
+                // if we did it later
+                // see: https://github.com/ccbrown/iocraft/issues/83
+                write!(w, csi!("K"))?;
+                did_clear_line = true;
+            }
+
+            if let Some(c) = &cell.character {
+                write!(w, "{}{}", c.value, " ".repeat(c.required_padding()))?;
+            } else {
+                w.write_all(b" ")?;
+            }
+        }
+        if ansi {
+            // hyperlinks shouldn't extend past the cells we've written
+            state.set_link(w, None)?;
+        }
+        if ansi && clear_line {
+            // if the background color is set, we need to reset it
+            if state.background_color.is_some() {
+                write!(w, csi!("{}m"), Colored::BackgroundColor(Color::Reset))?;
+                state.background_color = None;
+            }
+            if !did_clear_line {
+                // clear until end of line
+                write!(w, csi!("K"))?;
+            }
+        }
+        Ok(())
+    }
+
     fn write_impl<W: Write>(
         &self,
         mut w: W,
         ansi: bool,
+        color_depth: ColorDepth,
         omit_final_newline: bool,
     ) -> io::Result<()> {
         if ansi {
             write!(w, csi!("0m"))?;
         }
 
-        let mut background_color = None;
-        let mut text_style = CanvasTextStyle::default();
+        let mut state = AnsiState::new(color_depth);
 
         for y in 0..self.cells.len() {
-            let row = &self.cells[y];
-            let last_non_empty = row.iter().rposition(|cell| !cell.is_empty());
-            let row = &row[..last_non_empty.map_or(0, |i| i + 1)];
-            let mut col = 0;
-            let mut did_clear_line = false;
-            while col < row.len() {
-                let cell = &row[col];
-
-                if ansi {
-                    // For certain changes, we need to reset all attributes.
-                    let mut needs_reset = false;
-                    if let Some(c) = &cell.character {
-                        if c.style.weight != text_style.weight && c.style.weight == Weight::Normal {
-                            needs_reset = true;
-                        }
-                        if !c.style.underline && text_style.underline {
-                            needs_reset = true;
-                        }
-                        if !c.style.italic && text_style.italic {
-                            needs_reset = true;
-                        }
-                    } else if text_style.underline {
-                        needs_reset = true;
-                    }
-                    if needs_reset {
-                        write!(w, csi!("0m"))?;
-                        background_color = None;
-                        text_style = CanvasTextStyle::default();
-                    }
-
-                    if cell.background_color != background_color {
-                        write!(
-                            w,
-                            csi!("{}m"),
-                            Colored::BackgroundColor(cell.background_color.unwrap_or(Color::Reset))
-                        )?;
-                        background_color = cell.background_color;
-                    }
-
-                    if let Some(c) = &cell.character {
-                        if c.style.color != text_style.color {
-                            write!(
-                                w,
-                                csi!("{}m"),
-                                Colored::ForegroundColor(c.style.color.unwrap_or(Color::Reset))
-                            )?;
-                        }
-
-                        if c.style.weight != text_style.weight {
-                            match c.style.weight {
-                                Weight::Bold => write!(w, csi!("{}m"), Attribute::Bold.sgr())?,
-                                Weight::Normal => {}
-                                Weight::Light => write!(w, csi!("{}m"), Attribute::Dim.sgr())?,
-                            }
-                        }
-
-                        if c.style.underline && !text_style.underline {
-                            write!(w, csi!("{}m"), Attribute::Underlined.sgr())?;
-                        }
-
-                        if c.style.italic && !text_style.italic {
-                            write!(w, csi!("{}m"), Attribute::Italic.sgr())?;
-                        }
-
-                        text_style = c.style;
-                    }
-                }
-
-                if let Some(c) = &cell.character {
-                    col += c.value.width().max(1);
-                } else {
-                    col += 1;
-                }
-
-                if ansi && col >= self.width {
-                    // go ahead and clear until end of line. we need to do this before writing
-                    // the last character, because if we're at the end of the terminal row, the
-                    // cursor won't change position and the last character would be erased
-                    // if we did it later
-                    // see: https://github.com/ccbrown/iocraft/issues/83
-                    write!(w, csi!("K"))?;
-                    did_clear_line = true;
-                }
-
-                if let Some(c) = &cell.character {
-                    write!(w, "{}{}", c.value, " ".repeat(c.required_padding()))?;
-                } else {
-                    w.write_all(b" ")?;
-                }
-            }
-            if ansi {
-                // if the background color is set, we need to reset it
-                if background_color.is_some() {
-                    write!(w, csi!("{}m"), Colored::BackgroundColor(Color::Reset))?;
-                    background_color = None;
-                }
-                if !did_clear_line {
-                    // clear until end of line
-                    write!(w, csi!("K"))?;
-                }
-            }
+            self.write_cells(&mut w, y, 0..self.row_len(y), ansi, &mut state, true)?;
             let is_final_line = y == self.cells.len() - 1;
             if !omit_final_newline || !is_final_line {
                 if ansi {
@@ -322,41 +795,820 @@ impl Canvas {
         Ok(())
     }
 
-    /// Writes the canvas to the given writer with ANSI escape codes.
+    /// Returns the ranges of columns in the given row which differ from the same row of `prev`.
+    ///
+    /// Ranges are widened so that they always begin and end on character boundaries in both
+    /// canvases, and ranges separated by only a few unchanged cells are merged, as rewriting those
+    /// cells is cheaper than moving the cursor.
+    fn changed_row_ranges(&self, prev: &Canvas, y: usize) -> Vec<Range<usize>> {
+        const MIN_GAP: usize = 4;
+
+        let (a, b) = (self.row(y), prev.row(y));
+        let len = a.len().max(b.len());
+        let differs = |x: usize| cell_at(a, x) != cell_at(b, x);
+
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+        let mut x = 0;
+        while x < len {
+            if !differs(x) {
+                x += 1;
+                continue;
+            }
+            let start = char_start(a, x).min(char_start(b, x));
+            let mut end = x + 1;
+            let mut i = start;
+            while i < end {
+                end = end.max(char_end(a, i)).max(char_end(b, i));
+                i += 1;
+                if i == end && end < len && differs(end) {
+                    end += 1;
+                }
+            }
+            match ranges.last_mut() {
+                Some(last) if start <= last.end + MIN_GAP => last.end = last.end.max(end),
+                _ => ranges.push(start..end),
+            }
+            x = end;
+        }
+        ranges
+    }
+
+    /// Writes the canvas to the given writer with ANSI escape codes, assuming that `prev` was the
+    /// last canvas written and that the cursor is where that write left it. Only the regions that
+    /// differ from `prev` are written.
+    pub(crate) fn write_ansi_diff<W: Write>(
+        &self,
+        mut w: W,
+        prev: &Canvas,
+        omit_final_newline: bool,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        let final_row = |height: usize| {
+            if omit_final_newline {
+                height.saturating_sub(1)
+            } else {
+                height
+            }
+        };
+        let mut cursor_row = final_row(prev.height());
+        let mut move_to_row = |w: &mut W, y: usize| -> io::Result<()> {
+            if y < cursor_row {
+                write!(w, "{}", cursor::MoveToPreviousLine((cursor_row - y) as _))?;
+            } else {
+                // unlike other cursor movements, newlines will scroll the terminal if needed
+                for _ in cursor_row..y {
+                    w.write_all(b"\r\n")?;
+                }
+            }
+            cursor_row = y;
+            Ok(())
+        };
+
+        for y in 0..self.height() {
+            let ranges = if y < prev.height() {
+                self.changed_row_ranges(prev, y)
+            } else {
+                std::iter::once(0..self.width.max(1)).collect()
+            };
+            if ranges.is_empty() {
+                continue;
+            }
+            move_to_row(&mut w, y)?;
+            let row_len = self.row_len(y);
+            for range in ranges {
+                write!(w, "{}", cursor::MoveToColumn(range.start as _))?;
+                write!(w, csi!("0m"))?;
+                let mut state = AnsiState::new(color_depth);
+                if range.end >= row_len.max(prev.row_len(y)) {
+                    // The rest of the line is changing, so we can write the remaining content and
+                    // clear whatever is left.
+                    self.write_cells(&mut w, y, range.start..row_len, true, &mut state, true)?;
+                    break;
+                }
+                self.write_cells(&mut w, y, range, true, &mut state, false)?;
+            }
+            write!(w, csi!("0m"))?;
+        }
+
+        if self.height() < prev.height() {
+            move_to_row(&mut w, self.height())?;
+            write!(w, "{}", cursor::MoveToColumn(0))?;
+            write!(w, csi!("J"))?;
+        }
+        move_to_row(&mut w, final_row(self.height()))?;
+        w.flush()
+    }
+
+    /// Writes the canvas to the given writer with ANSI escape codes. Colors are written exactly
+    /// as specified.
     pub fn write_ansi<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, true, false)
+        self.write_ansi_with_color_depth(w, ColorDepth::TrueColor)
     }
 
-    pub(crate) fn write_ansi_without_final_newline<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, true, true)
+    /// Writes the canvas to the given writer with ANSI escape codes, converting colors to the
+    /// nearest ones which can be displayed at the given color depth.
+    pub fn write_ansi_with_color_depth<W: Write>(
+        &self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        self.write_impl(w, true, color_depth, false)
     }
 
-    /// Writes the canvas to the given writer as unstyled text, without ANSI escape codes.
-    pub fn write<W: Write>(&self, w: W) -> io::Result<()> {
-        self.write_impl(w, false, false)
+    pub(crate) fn write_ansi_without_final_newline<W: Write>(
+        &self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
+        self.write_impl(w, true, color_depth, true)
     }
-}
 
-impl Display for Canvas {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut buf = Vec::with_capacity(self.width * self.cells.len());
-        self.write(&mut buf).unwrap();
-        f.write_str(&String::from_utf8_lossy(&buf))?;
-        Ok(())
+    /// Writes the canvas to the given writer as unstyled text, without ANSI escape codes.
+    pub fn write<W: Write>(&self, w: W) -> io::Result<()> {
+        self.write_impl(w, false, ColorDepth::NoColor, false)
     }
-}
 
-/// Represents a writeable region of a [`Canvas`]. All coordinates provided to functions of this
-/// type are relative to the region's top-left corner.
-pub struct CanvasSubviewMut<'a> {
-    x: isize,
-    y: isize,
-    clip_x: isize,
-    clip_y: isize,
-    clip_width: usize,
-    clip_height: usize,
-    canvas: &'a mut Canvas,
-}
+    /// Writes the canvas to the given writer as an HTML `<pre>` element, with styles applied via
+    /// inline CSS.
+    pub fn write_html<W: Write>(&self, mut w: W) -> io::Result<()> {
+        let link = |cell: &Cell| cell.character.as_ref().and_then(|c| c.style.link.clone());
+        w.write_all(b"<pre>")?;
+        let mut current_link = None;
+        for y in 0..self.height() {
+            let row = self.row(y);
+            let end = self.row_len(y);
+            let mut current_style = String::new();
+            let mut col = 0;
+            while col < end {
+                let cell = &row[col];
+
+                let cell_link = link(cell);
+                if cell_link != current_link {
+                    if !current_style.is_empty() {
+                        w.write_all(b"</span>")?;
+                        current_style.clear();
+                    }
+                    if current_link.is_some() {
+                        w.write_all(b"</a>")?;
+                    }
+                    if let Some(url) = &cell_link {
+                        w.write_all(b"<a href=\"")?;
+                        write_xml_escaped(&mut w, url)?;
+                        w.write_all(b"\">")?;
+                    }
+                    current_link = cell_link;
+                }
+
+                let style = html_style(cell);
+                if style != current_style {
+                    if !current_style.is_empty() {
+                        w.write_all(b"</span>")?;
+                    }
+                    if !style.is_empty() {
+                        write!(w, "<span style=\"{}\">", style)?;
+                    }
+                    current_style = style;
+                }
+
+                match &cell.character {
+                    Some(c) => {
+                        // Browsers don't reliably render wide characters at exactly twice the
+                        // width of other characters, so we enforce it to keep columns aligned.
+                        // The padding for VS16 is a terminal workaround, so it's not needed here.
+                        let width = c.value.width();
+                        if width > 1 {
+                            write!(
+                                w,
+                                "<span style=\"display: inline-block; width: {}ch\">",
+                                width
+                            )?;
+                            write_xml_escaped(&mut w, &c.value)?;
+                            w.write_all(b"</span>")?;
+                        } else {
+                            write_xml_escaped(&mut w, &c.value)?;
+                        }
+                        col += width.max(1);
+                    }
+                    None => {
+                        w.write_all(b" ")?;
+                        col += 1;
+                    }
+                }
+            }
+            if !current_style.is_empty() {
+                w.write_all(b"</span>")?;
+            }
+            // If a link is wrapped onto the next line, keep it as a single element.
+            let next_row_link = (y + 1 < self.height())
+                .then(|| link(cell_at(self.row(y + 1), 0)))
+                .flatten();
+            if current_link.is_some() && current_link != next_row_link {
+                w.write_all(b"</a>")?;
+                current_link = None;
+            }
+            w.write_all(b"\n")?;
+        }
+        w.write_all(b"</pre>\n")?;
+        w.flush()?;
+        Ok(())
+    }
+
+    /// Writes the canvas to the given writer as a self-contained SVG image which looks like a
+    /// screenshot of a terminal.
+    ///
+    /// Characters are positioned using the cell size given in the options, so for best results the
+    /// font should be monospaced and its advance width should match the cell width.
+    pub fn write_svg<W: Write>(&self, mut w: W, options: &SvgOptions) -> io::Result<()> {
+        let theme = &options.theme;
+        let rgb = |color: Option<Color>, default: (u8, u8, u8)| {
+            hex_color(
+                color
+                    .and_then(|c| color_rgb(c, &theme.ansi_colors))
+                    .unwrap_or(default),
+            )
+        };
+        let title_bar_height = if options.window_chrome { 32.0 } else { 0.0 };
+        let content_x = options.padding;
+        let content_y = options.padding + title_bar_height;
+        let width = self.width as f32 * options.cell_width + options.padding * 2.0;
+        let height =
+            self.height() as f32 * options.cell_height + options.padding * 2.0 + title_bar_height;
+
+        writeln!(
+            w,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\" xml:space=\"preserve\">",
+            w = svg_number(width),
+            h = svg_number(height),
+        )?;
+        write!(w, "<style>text {{ font-family: ")?;
+        write_xml_escaped(&mut w, &options.font_family)?;
+        writeln!(
+            w,
+            "; font-size: {}px; white-space: pre; }}</style>",
+            svg_number(options.font_size)
+        )?;
+        writeln!(
+            w,
+            "<rect width=\"100%\" height=\"100%\" rx=\"{}\" fill=\"{}\"/>",
+            if options.window_chrome { 8 } else { 0 },
+            hex_color(theme.background)
+        )?;
+        if options.window_chrome {
+            for (i, color) in ["#ff5f56", "#ffbd2e", "#27c93f"].iter().enumerate() {
+                writeln!(
+                    w,
+                    "<circle cx=\"{}\" cy=\"16\" r=\"6\" fill=\"{}\"/>",
+                    20 + i * 20,
+                    color
+                )?;
+            }
+        }
+
+        // Reversed characters swap their foreground and background colors.
+        let background = |cell: &Cell| match &cell.character {
+            Some(c) if c.style.reverse => Some(rgb(c.style.color, theme.foreground)),
+            _ => cell
+                .background_color
+                .map(|color| rgb(Some(color), theme.background)),
+        };
+        let foreground = |cell: &Cell| {
+            let style = cell
+                .character
+                .as_ref()
+                .map(|c| c.style.clone())
+                .unwrap_or_default();
+            if style.reverse {
+                rgb(cell.background_color, theme.background)
+            } else {
+                rgb(style.color, theme.foreground)
+            }
+        };
+
+        // Draw the backgrounds, merging adjacent cells with the same color.
+        for y in 0..self.height() {
+            let row = self.row(y);
+            let mut col = 0;
+            while col < row.len() {
+                let Some(color) = background(&row[col]) else {
+                    col += 1;
+                    continue;
+                };
+                let start = col;
+                while col < row.len() && background(&row[col]).as_ref() == Some(&color) {
+                    col += 1;
+                }
+                writeln!(
+                    w,
+                    "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+                    svg_number(content_x + start as f32 * options.cell_width),
+                    svg_number(content_y + y as f32 * options.cell_height),
+                    svg_number((col - start) as f32 * options.cell_width),
+                    svg_number(options.cell_height),
+                    color,
+                )?;
+            }
+        }
+
+        // Draw the text, merging adjacent characters with the same style. Each run of text is
+        // stretched to its exact width so that columns stay aligned regardless of the font.
+        let baseline = (options.cell_height + options.font_size * 0.7) / 2.0;
+        for y in 0..self.height() {
+            let row = self.row(y);
+            let mut col = 0;
+            while col < row.len() {
+                let Some(style) = row[col].character.as_ref().map(|c| c.style.clone()) else {
+                    col += 1;
+                    continue;
+                };
+                let fill = foreground(&row[col]);
+                let start = col;
+                let mut text = String::new();
+                while let Some(cell) = row.get(col) {
+                    match &cell.character {
+                        Some(c) if c.style == style && foreground(cell) == fill => {
+                            text.push_str(&c.value);
+                            col += c.value.width().max(1);
+                        }
+                        _ => break,
+                    }
+                }
+                let decoration =
+                    style.css_text_decoration(|color| Some(rgb(Some(color), theme.foreground)));
+                if style.hidden || (text.trim().is_empty() && decoration.is_none()) {
+                    continue;
+                }
+                if let Some(url) = &style.link {
+                    w.write_all(b"<a href=\"")?;
+                    write_xml_escaped(&mut w, url)?;
+                    w.write_all(b"\">")?;
+                }
+                write!(
+                    w,
+                    "<text x=\"{}\" y=\"{}\" textLength=\"{}\" fill=\"{}\"",
+                    svg_number(content_x + start as f32 * options.cell_width),
+                    svg_number(content_y + y as f32 * options.cell_height + baseline),
+                    svg_number((col - start) as f32 * options.cell_width),
+                    fill,
+                )?;
+                match style.weight {
+                    Weight::Bold => write!(w, " font-weight=\"bold\"")?,
+                    Weight::Normal => {}
+                    Weight::Light => write!(w, " opacity=\"0.5\"")?,
+                }
+                if style.italic {
+                    write!(w, " font-style=\"italic\"")?;
+                }
+                if let Some(decoration) = decoration {
+                    write!(w, " text-decoration=\"{}\"", decoration)?;
+                }
+                w.write_all(b">")?;
+                write_xml_escaped(&mut w, &text)?;
+                if style.blink {
+                    w.write_all(b"<animate attributeName=\"opacity\" values=\"1;0\" dur=\"1s\" calcMode=\"discrete\" repeatCount=\"indefinite\"/>")?;
+                }
+                w.write_all(b"</text>")?;
+                if style.link.is_some() {
+                    w.write_all(b"</a>")?;
+                }
+                w.write_all(b"\n")?;
+            }
+        }
+
+        w.write_all(b"</svg>\n")?;
+        w.flush()?;
+        Ok(())
+    }
+}
+
+/// Interprets text containing ANSI escape codes, writing the result to a canvas.
+struct AnsiReader {
+    canvas: Canvas,
+    x: usize,
+    y: usize,
+    // The lowest row reached by text or newlines. Cursor movement sequences can't go below it, so
+    // that a sequence with a huge parameter can't force an equally huge canvas to be allocated.
+    bottom: usize,
+    // Set when a character has been written to the last column. As in most terminals, the cursor
+    // doesn't wrap to the next line until another character is written.
+    pending_wrap: bool,
+    background_color: Option<Color>,
+    text_style: CanvasTextStyle,
+    last_character_position: Option<(usize, usize)>,
+}
+
+impl AnsiReader {
+    fn new(width: usize) -> Self {
+        Self {
+            canvas: Canvas::new(width, 0),
+            x: 0,
+            y: 0,
+            bottom: 0,
+            pending_wrap: false,
+            background_color: None,
+            text_style: CanvasTextStyle::default(),
+            last_character_position: None,
+        }
+    }
+
+    fn read(&mut self, s: &str) {
+        // Printable text is buffered so that it can be split into grapheme clusters.
+        let mut text = String::new();
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            if !c.is_control() {
+                text.push(c);
+                continue;
+            }
+            self.write_text(&text);
+            text.clear();
+            match c {
+                '\x1b' => match chars.next() {
+                    Some('[') => {
+                        let mut params = String::new();
+                        for c in chars.by_ref() {
+                            if ('\x40'..='\x7e').contains(&c) {
+                                self.handle_csi(&params, c);
+                                break;
+                            }
+                            params.push(c);
+                        }
+                    }
+                    Some(']') => {
+                        // Operating system commands are terminated by BEL or ST.
+                        let mut command = String::new();
+                        while let Some(c) = chars.next() {
+                            if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                                break;
+                            }
+                            command.push(c);
+                        }
+                        self.handle_osc(&command);
+                    }
+                    _ => {}
+                },
+                '\n' => self.move_to(0, self.y + 1),
+                '\r' => self.move_to(0, self.y),
+                '\t' => self.move_to((self.x / 8 + 1) * 8, self.y),
+                '\x08' => self.move_to(self.x.saturating_sub(1), self.y),
+                _ => {}
+            }
+        }
+        self.write_text(&text);
+    }
+
+    fn into_canvas(mut self) -> Canvas {
+        let content_height = (0..self.canvas.height())
+            .rev()
+            .find(|&y| self.canvas.row_len(y) > 0)
+            .map_or(0, |y| y + 1);
+        let cursor_height = if self.x > 0 || self.pending_wrap {
+            self.y + 1
+        } else {
+            self.y
+        };
+        self.ensure_rows(content_height.max(cursor_height));
+        self.canvas
+            .cells
+            .truncate(content_height.max(cursor_height));
+        self.canvas
+    }
+
+    fn ensure_rows(&mut self, height: usize) {
+        let width = self.canvas.width;
+        if self.canvas.cells.len() < height {
+            self.canvas
+                .cells
+                .resize(height, vec![Cell::default(); width]);
+        }
+    }
+
+    fn move_to(&mut self, x: usize, y: usize) {
+        self.x = x.min(self.canvas.width.saturating_sub(1));
+        self.y = y;
+        self.pending_wrap = false;
+    }
+
+    // Clears the cells in the given range of the current row, including any wide characters which
+    // partially overlap it.
+    fn clear_cells(&mut self, y: usize, range: Range<usize>) {
+        if range.is_empty() {
+            return;
+        }
+        self.ensure_rows(y + 1);
+        let row = &mut self.canvas.cells[y];
+        let start = char_start(row, range.start);
+        let end = char_end(row, range.end - 1).min(row.len());
+        for (x, cell) in row.iter_mut().enumerate().take(end).skip(start) {
+            cell.character = None;
+            if range.contains(&x) {
+                cell.background_color = self.background_color;
+            }
+        }
+    }
+
+    fn write_text(&mut self, text: &str) {
+        for g in text.graphemes(true) {
+            self.write_grapheme(g);
+        }
+    }
+
+    fn write_grapheme(&mut self, g: &str) {
+        let width = g.width();
+        if width == 0 {
+            // Zero-width clusters such as stray combining marks belong to the previous character.
+            if let Some((x, y)) = self.last_character_position {
+                if let Some(character) = &mut self.canvas.cells[y][x].character {
+                    character.value.push_str(g);
+                }
+            }
+            return;
+        }
+        if width > self.canvas.width {
+            return;
+        }
+        if self.pending_wrap || self.x + width > self.canvas.width {
+            self.x = 0;
+            self.y += 1;
+            self.pending_wrap = false;
+        }
+        self.clear_cells(self.y, self.x..self.x + width);
+        self.canvas.cells[self.y][self.x].character = Some(Character {
+            value: g.to_string(),
+            style: self.text_style.clone(),
+        });
+        self.last_character_position = Some((self.x, self.y));
+        self.x += width;
+        if self.x >= self.canvas.width {
+            self.x = self.canvas.width - 1;
+            self.pending_wrap = true;
+        }
+    }
+
+    fn handle_csi(&mut self, params: &str, command: char) {
+        if params.starts_with(|c: char| !c.is_ascii_digit() && c != ';' && c != ':') {
+            // Private sequences such as those for hiding the cursor don't affect the output.
+            return;
+        }
+        // Each parameter may have sub-parameters, separated by colons.
+        let params: Vec<Vec<usize>> = params
+            .split(';')
+            .map(|p| p.split(':').map(|p| p.parse().unwrap_or(0)).collect())
+            .collect();
+        let param = |i: usize| params.get(i).map_or(0, |p| p[0]);
+        let count = param(0).max(1);
+        self.bottom = self.bottom.max(self.y);
+        let bottom = self.bottom;
+        match command {
+            'A' => self.move_to(self.x, self.y.saturating_sub(count)),
+            'B' => self.move_to(self.x, self.y.saturating_add(count).min(bottom)),
+            'C' => self.move_to(self.x.saturating_add(count), self.y),
+            'D' => self.move_to(self.x.saturating_sub(count), self.y),
+            'E' => self.move_to(0, self.y.saturating_add(count).min(bottom)),
+            'F' => self.move_to(0, self.y.saturating_sub(count)),
+            'G' => self.move_to(count - 1, self.y),
+            'H' | 'f' => self.move_to(param(1).max(1) - 1, (count - 1).min(bottom)),
+            'K' => {
+                let range = match param(0) {
+                    0 => self.x..self.canvas.width,
+                    1 => 0..self.x + 1,
+                    _ => 0..self.canvas.width,
+                };
+                self.clear_cells(self.y, range);
+            }
+            'J' => {
+                let (rows, range) = match param(0) {
+                    0 => (self.y + 1..self.canvas.height(), self.x..self.canvas.width),
+                    1 => (0..self.y, 0..self.x + 1),
+                    _ => (0..self.canvas.height(), 0..self.canvas.width),
+                };
+                for y in rows {
+                    self.clear_cells(y, 0..self.canvas.width);
+                }
+                if param(0) <= 1 {
+                    self.clear_cells(self.y, range);
+                }
+            }
+            'm' => self.handle_sgr(&params),
+            _ => {}
+        }
+    }
+
+    fn handle_osc(&mut self, command: &str) {
+        // The only command we care about is OSC 8, which starts or ends a hyperlink.
+        let mut parts = command.splitn(3, ';');
+        if parts.next() == Some("8") {
+            if let Some(url) = parts.nth(1) {
+                self.text_style.link = (!url.is_empty()).then(|| url.into());
+            }
+        }
+    }
+
+    fn handle_sgr(&mut self, params: &[Vec<usize>]) {
+        let style = &mut self.text_style;
+        let mut params = params.iter();
+        while let Some(param) = params.next() {
+            let sub_params = &param[1..];
+            match param[0] {
+                0 => {
+                    // Hyperlinks aren't affected by SGR sequences.
+                    *style = CanvasTextStyle {
+                        link: style.link.take(),
+                        ..Default::default()
+                    };
+                    self.background_color = None;
+                }
+                1 => style.weight = Weight::Bold,
+                2 => style.weight = Weight::Light,
+                22 => style.weight = Weight::Normal,
+                3 => style.italic = true,
+                23 => style.italic = false,
+                4 => {
+                    style.underline_style = match sub_params.first() {
+                        Some(2) => UnderlineStyle::Double,
+                        Some(3) => UnderlineStyle::Curly,
+                        Some(4) => UnderlineStyle::Dotted,
+                        Some(5) => UnderlineStyle::Dashed,
+                        _ => UnderlineStyle::Single,
+                    };
+                    style.underline = sub_params.first() != Some(&0);
+                }
+                21 => {
+                    style.underline = true;
+                    style.underline_style = UnderlineStyle::Double;
+                }
+                24 => {
+                    style.underline = false;
+                    style.underline_style = UnderlineStyle::Single;
+                }
+                5 | 6 => style.blink = true,
+                25 => style.blink = false,
+                7 => style.reverse = true,
+                27 => style.reverse = false,
+                8 => style.hidden = true,
+                28 => style.hidden = false,
+                9 => style.strikethrough = true,
+                29 => style.strikethrough = false,
+                53 => style.overline = true,
+                55 => style.overline = false,
+                30..=37 => style.color = Some(ansi_named_color(param[0] as u8 - 30)),
+                90..=97 => style.color = Some(ansi_named_color(param[0] as u8 - 90 + 8)),
+                38 => style.color = read_sgr_color(sub_params, &mut params),
+                39 => style.color = None,
+                40..=47 => self.background_color = Some(ansi_named_color(param[0] as u8 - 40)),
+                100..=107 => {
+                    self.background_color = Some(ansi_named_color(param[0] as u8 - 100 + 8))
+                }
+                48 => self.background_color = read_sgr_color(sub_params, &mut params),
+                49 => self.background_color = None,
+                58 => style.underline_color = read_sgr_color(sub_params, &mut params),
+                59 => style.underline_color = None,
+                _ => {}
+            }
+        }
+    }
+}
+
+// Returns the named color for the given index in the 16-color ANSI palette.
+pub(crate) fn ansi_named_color(i: u8) -> Color {
+    match i {
+        0 => Color::Black,
+        1 => Color::DarkRed,
+        2 => Color::DarkGreen,
+        3 => Color::DarkYellow,
+        4 => Color::DarkBlue,
+        5 => Color::DarkMagenta,
+        6 => Color::DarkCyan,
+        7 => Color::Grey,
+        8 => Color::DarkGrey,
+        9 => Color::Red,
+        10 => Color::Green,
+        11 => Color::Yellow,
+        12 => Color::Blue,
+        13 => Color::Magenta,
+        14 => Color::Cyan,
+        15 => Color::White,
+        i => Color::AnsiValue(i),
+    }
+}
+
+// Reads the parameters of an extended SGR color, i.e. the parameters following 38, 48, or 58.
+// These may either be given as sub-parameters or as subsequent parameters.
+fn read_sgr_color<'a, I: Iterator<Item = &'a Vec<usize>>>(
+    sub_params: &[usize],
+    params: &mut I,
+) -> Option<Color> {
+    let values: Vec<usize> = if sub_params.is_empty() {
+        let mut values = vec![params.next()?[0]];
+        let count = if values[0] == 2 { 3 } else { 1 };
+        for _ in 0..count {
+            values.push(params.next()?[0]);
+        }
+        values
+    } else if sub_params[0] == 2 && sub_params.len() > 4 {
+        // The color space ID may be included before the components.
+        [&sub_params[..1], &sub_params[sub_params.len() - 3..]].concat()
+    } else {
+        sub_params.to_vec()
+    };
+    let value = |i: usize| values.get(i).map(|&v| v.min(255) as u8);
+    match values[0] {
+        5 => Some(ansi_named_color(value(1)?)),
+        2 => Some(Color::Rgb {
+            r: value(1)?,
+            g: value(2)?,
+            b: value(3)?,
+        }),
+        _ => None,
+    }
+}
+
+impl Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buf = Vec::with_capacity(self.width * self.cells.len());
+        self.write(&mut buf).unwrap();
+        f.write_str(&String::from_utf8_lossy(&buf))?;
+        Ok(())
+    }
+}
+
+/// The colors used when rendering a [`Canvas`] as an SVG image via [`Canvas::write_svg`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgTheme {
+    /// The default text color, as an RGB tuple.
+    pub foreground: (u8, u8, u8),
+
+    /// The default background color, as an RGB tuple.
+    pub background: (u8, u8, u8),
+
+    /// The colors used for the 16 named ANSI colors, in standard order: black, dark red, dark
+    /// green, dark yellow, dark blue, dark magenta, dark cyan, grey, dark grey, red, green, yellow,
+    /// blue, magenta, cyan, and white.
+    pub ansi_colors: [(u8, u8, u8); 16],
+}
+
+impl Default for SvgTheme {
+    fn default() -> Self {
+        Self {
+            foreground: (0xe5, 0xe5, 0xe5),
+            background: (0x1e, 0x1e, 0x1e),
+            ansi_colors: XTERM_PALETTE,
+        }
+    }
+}
+
+/// Options for rendering a [`Canvas`] as an SVG image via [`Canvas::write_svg`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SvgOptions {
+    /// The font family used for text, as a CSS `font-family` value.
+    pub font_family: String,
+
+    /// The font size, in pixels.
+    pub font_size: f32,
+
+    /// The width of each cell, in pixels.
+    pub cell_width: f32,
+
+    /// The height of each cell, in pixels.
+    pub cell_height: f32,
+
+    /// The amount of space around the content, in pixels.
+    pub padding: f32,
+
+    /// The colors to use.
+    pub theme: SvgTheme,
+
+    /// Whether to draw a title bar with window buttons above the content, making the image look
+    /// like a terminal window.
+    pub window_chrome: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            font_family: "Menlo, Monaco, Consolas, 'DejaVu Sans Mono', monospace".to_string(),
+            font_size: 14.0,
+            cell_width: 8.4,
+            cell_height: 17.0,
+            padding: 16.0,
+            theme: SvgTheme::default(),
+            window_chrome: false,
+        }
+    }
+}
+
+/// Represents a writeable region of a [`Canvas`]. All coordinates provided to functions of this
+/// type are relative to the region's top-left corner.
+pub struct CanvasSubviewMut<'a> {
+    x: isize,
+    y: isize,
+    clip_x: isize,
+    clip_y: isize,
+    clip_width: usize,
+    clip_height: usize,
+    canvas: &'a mut Canvas,
+}
 
 impl CanvasSubviewMut<'_> {
     /// Fills the region with the given color.
@@ -413,35 +1665,33 @@ impl CanvasSubviewMut<'_> {
         let horizontal_space = max_x - x + 1;
         let min_y = self.clip_y.max(0);
         let max_y = (self.clip_y + self.clip_height as isize).min(self.canvas.height() as _) - 1;
-        let mut y = self.y + y;
-        for line in text.lines() {
+        for (y, line) in (self.y + y..).zip(text.lines()) {
             if y >= min_y && y <= max_y {
                 let mut skipped_width = 0;
                 let mut taken_width = 0;
-                self.canvas.set_text_row_chars(
+                self.canvas.set_text_row_graphemes(
                     x as usize,
                     y as usize,
-                    line.chars()
-                        .skip_while(|c| {
+                    line.graphemes(true)
+                        .skip_while(|g| {
                             if skipped_width < to_skip {
-                                skipped_width += c.width().unwrap_or(0) as isize;
+                                skipped_width += g.width() as isize;
                                 true
                             } else {
                                 false
                             }
                         })
-                        .take_while(|c| {
+                        .take_while(|g| {
                             if taken_width < horizontal_space {
-                                taken_width += c.width().unwrap_or(0) as isize;
+                                taken_width += g.width() as isize;
                                 true
                             } else {
                                 false
                             }
                         }),
-                    style,
+                    style.clone(),
                 );
             }
-            y += 1;
         }
     }
 }
@@ -610,6 +1860,211 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_canvas_extended_text_styles() {
+        let mut canvas = Canvas::new(5, 1);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 5, 1);
+        subview.set_text(
+            0,
+            0,
+            "a",
+            CanvasTextStyle {
+                underline: true,
+                underline_style: UnderlineStyle::Curly,
+                underline_color: Some(Color::Red),
+                strikethrough: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            1,
+            0,
+            "b",
+            CanvasTextStyle {
+                underline: true,
+                underline_style: UnderlineStyle::Double,
+                strikethrough: true,
+                overline: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            2,
+            0,
+            "c",
+            CanvasTextStyle {
+                reverse: true,
+                blink: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            3,
+            0,
+            "d",
+            CanvasTextStyle {
+                reverse: true,
+                blink: true,
+                hidden: true,
+                ..Default::default()
+            },
+        );
+        // Whitespace with a visible style must not be skipped or merged with trailing space.
+        subview.set_text(
+            4,
+            0,
+            " ",
+            CanvasTextStyle {
+                overline: true,
+                ..Default::default()
+            },
+        );
+
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Attribute::Undercurled.sgr()).unwrap();
+        write!(expected, csi!("{}m"), Colored::UnderlineColor(Color::Red)).unwrap();
+        write!(expected, csi!("{}m"), Attribute::CrossedOut.sgr()).unwrap();
+        write!(expected, "a").unwrap();
+
+        write!(expected, csi!("{}m"), Attribute::DoubleUnderlined.sgr()).unwrap();
+        write!(expected, csi!("{}m"), Colored::UnderlineColor(Color::Reset)).unwrap();
+        write!(expected, csi!("{}m"), Attribute::OverLined.sgr()).unwrap();
+        write!(expected, "b").unwrap();
+
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Attribute::Reverse.sgr()).unwrap();
+        write!(expected, csi!("{}m"), Attribute::SlowBlink.sgr()).unwrap();
+        write!(expected, "c").unwrap();
+
+        write!(expected, csi!("{}m"), Attribute::Hidden.sgr()).unwrap();
+        write!(expected, "d").unwrap();
+
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Attribute::OverLined.sgr()).unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, " ").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            Canvas::from_ansi(&String::from_utf8(actual).unwrap(), 5),
+            canvas
+        );
+    }
+
+    #[test]
+    fn test_write_ansi_with_color_depth() {
+        let mut canvas = Canvas::new(2, 1);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 2, 1);
+        subview.set_background_color(0, 0, 2, 1, Color::Rgb { r: 0, g: 0, b: 250 });
+        subview.set_text(
+            0,
+            0,
+            "ab",
+            CanvasTextStyle {
+                color: Some(Color::AnsiValue(196)),
+                weight: Weight::Bold,
+                ..Default::default()
+            },
+        );
+
+        let write = |color_depth| {
+            let mut actual = Vec::new();
+            canvas
+                .write_ansi_with_color_depth(&mut actual, color_depth)
+                .unwrap();
+            actual
+        };
+
+        let mut expected = Vec::new();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Colored::BackgroundColor(Color::Blue)).unwrap();
+        write!(expected, csi!("{}m"), Colored::ForegroundColor(Color::Red)).unwrap();
+        write!(expected, csi!("{}m"), Attribute::Bold.sgr()).unwrap();
+        write!(expected, "a").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, "b").unwrap();
+        write!(
+            expected,
+            csi!("{}m"),
+            Colored::BackgroundColor(Color::Reset)
+        )
+        .unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(write(ColorDepth::Ansi16), expected);
+
+        let mut expected = Vec::new();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Attribute::Bold.sgr()).unwrap();
+        write!(expected, "a").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, "b").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(write(ColorDepth::NoColor), expected);
+
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+        assert_eq!(write(ColorDepth::TrueColor), actual);
+    }
+
+    #[test]
+    fn test_canvas_wide_character_occlusion() {
+        let mut canvas = Canvas::new(6, 2);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 6, 2);
+        subview.set_text(0, 0, "一二三", CanvasTextStyle::default());
+        // Writing over the right half of a wide character removes the whole character.
+        subview.set_text(1, 0, "x", CanvasTextStyle::default());
+        // Writing over the left half of a wide character removes the whole character.
+        subview.set_text(2, 0, "y", CanvasTextStyle::default());
+        // Clearing the right half of a wide character removes the whole character.
+        subview.clear_text(5, 0, 1, 1);
+        // Writing a wide character removes the characters it covers.
+        subview.set_text(0, 1, "abc", CanvasTextStyle::default());
+        subview.set_text(1, 1, "一", CanvasTextStyle::default());
+        assert_eq!(canvas.to_string(), " xy\na一\n");
+    }
+
+    #[test]
+    fn test_canvas_grapheme_clusters() {
+        let text = "👨\u{200d}👩\u{200d}👧🇺🇸e\u{301}x";
+        let mut canvas = Canvas::new(7, 2);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 7, 2);
+        subview.set_text(0, 0, text, CanvasTextStyle::default());
+        // Clipping never splits a cluster.
+        subview.set_text(-2, 1, text, CanvasTextStyle::default());
+        let values = canvas.cells[0]
+            .iter()
+            .map(|c| c.character.as_ref().map(|c| c.value.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            values,
+            vec![
+                Some("👨\u{200d}👩\u{200d}👧"),
+                None,
+                Some("🇺🇸"),
+                None,
+                Some("e\u{301}"),
+                Some("x"),
+                None,
+            ]
+        );
+        assert_eq!(canvas.to_string(), format!("{}\n🇺🇸e\u{301}x\n", text));
+
+        // Reading ANSI output groups clusters the same way.
+        let mut ansi = Vec::new();
+        canvas.write_ansi(&mut ansi).unwrap();
+        let ansi = String::from_utf8(ansi).unwrap();
+        assert_eq!(Canvas::from_ansi(&ansi, 7), canvas);
+        assert_eq!(Canvas::from_ansi(text, 7).cells[0], canvas.cells[0]);
+    }
+
     #[test]
     fn test_canvas_text_clipping() {
         let mut canvas = Canvas::new(10, 5);
@@ -649,7 +2104,7 @@ mod tests {
 
         let mut actual = Vec::new();
         canvas
-            .write_ansi_without_final_newline(&mut actual)
+            .write_ansi_without_final_newline(&mut actual, ColorDepth::TrueColor)
             .unwrap();
 
         let mut expected = Vec::new();
@@ -665,6 +2120,555 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn test_write_html() {
+        let mut canvas = Canvas::new(10, 3);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 10, 3);
+        subview.set_text(0, 0, "<a&b>", CanvasTextStyle::default());
+        subview.set_text(
+            1,
+            1,
+            "hi",
+            CanvasTextStyle {
+                color: Some(Color::Red),
+                weight: Weight::Bold,
+                ..Default::default()
+            },
+        );
+        subview.set_background_color(3, 1, 2, 1, Color::Rgb { r: 1, g: 2, b: 3 });
+        subview.set_text(0, 2, "一x", CanvasTextStyle::default());
+
+        let mut actual = Vec::new();
+        canvas.write_html(&mut actual).unwrap();
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            concat!(
+                "<pre>&lt;a&amp;b&gt;\n",
+                " <span style=\"color: #ff0000; font-weight: bold\">hi</span>",
+                "<span style=\"background-color: #010203\">  </span>\n",
+                "<span style=\"display: inline-block; width: 2ch\">一</span>x\n",
+                "</pre>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_write_svg() {
+        let mut canvas = Canvas::new(4, 2);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 4, 2);
+        subview.set_text(0, 0, "a<b", CanvasTextStyle::default());
+        subview.set_background_color(1, 1, 2, 1, Color::DarkBlue);
+        subview.set_text(
+            1,
+            1,
+            "一",
+            CanvasTextStyle {
+                color: Some(Color::Red),
+                weight: Weight::Bold,
+                ..Default::default()
+            },
+        );
+
+        let mut theme = SvgTheme::default();
+        theme.ansi_colors[9] = (1, 2, 3);
+        let options = SvgOptions {
+            font_family: "Fira Code".to_string(),
+            font_size: 10.0,
+            cell_width: 10.0,
+            cell_height: 20.0,
+            padding: 5.0,
+            theme,
+            window_chrome: true,
+        };
+
+        let mut actual = Vec::new();
+        canvas.write_svg(&mut actual, &options).unwrap();
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            concat!(
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"50\" height=\"82\" viewBox=\"0 0 50 82\" xml:space=\"preserve\">\n",
+                "<style>text { font-family: Fira Code; font-size: 10px; white-space: pre; }</style>\n",
+                "<rect width=\"100%\" height=\"100%\" rx=\"8\" fill=\"#1e1e1e\"/>\n",
+                "<circle cx=\"20\" cy=\"16\" r=\"6\" fill=\"#ff5f56\"/>\n",
+                "<circle cx=\"40\" cy=\"16\" r=\"6\" fill=\"#ffbd2e\"/>\n",
+                "<circle cx=\"60\" cy=\"16\" r=\"6\" fill=\"#27c93f\"/>\n",
+                "<rect x=\"15\" y=\"57\" width=\"20\" height=\"20\" fill=\"#000080\"/>\n",
+                "<text x=\"5\" y=\"50.5\" textLength=\"30\" fill=\"#e5e5e5\">a&lt;b</text>\n",
+                "<text x=\"15\" y=\"70.5\" textLength=\"20\" fill=\"#010203\" font-weight=\"bold\">一</text>\n",
+                "</svg>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_from_ansi() {
+        let mut canvas = Canvas::new(6, 3);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 6, 3);
+        subview.set_text(
+            0,
+            0,
+            "hello!",
+            CanvasTextStyle {
+                color: Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+                italic: true,
+                ..Default::default()
+            },
+        );
+        subview.set_background_color(0, 1, 3, 2, Color::DarkGreen);
+        subview.set_text(
+            1,
+            1,
+            "一x",
+            CanvasTextStyle {
+                color: Some(Color::AnsiValue(100)),
+                weight: Weight::Bold,
+                underline: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            0,
+            2,
+            "e\u{301}",
+            CanvasTextStyle {
+                weight: Weight::Light,
+                ..Default::default()
+            },
+        );
+
+        let mut ansi = Vec::new();
+        canvas.write_ansi(&mut ansi).unwrap();
+        let ansi = String::from_utf8(ansi).unwrap();
+        assert_eq!(Canvas::from_ansi(&ansi, 6), canvas);
+
+        let mut ansi = Vec::new();
+        canvas
+            .write_ansi_without_final_newline(&mut ansi, ColorDepth::TrueColor)
+            .unwrap();
+        let ansi = String::from_utf8(ansi).unwrap();
+        assert_eq!(Canvas::from_ansi(&ansi, 6), canvas);
+
+        // Rendering a diff on top of the previous output should produce the new canvas.
+        let mut prev = Canvas::new(6, 4);
+        prev.subview_mut(0, 0, 0, 0, 6, 4).set_text(
+            0,
+            0,
+            "一二三\nfoo\nbar\nbaz",
+            CanvasTextStyle::default(),
+        );
+        let mut ansi = Vec::new();
+        prev.write_ansi(&mut ansi).unwrap();
+        canvas
+            .write_ansi_diff(&mut ansi, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+        let ansi = String::from_utf8(ansi).unwrap();
+        assert_eq!(Canvas::from_ansi(&ansi, 6), canvas);
+    }
+
+    #[test]
+    fn test_from_ansi_sequences() {
+        let canvas = Canvas::from_ansi("\x1b[31mab\x1b[0m\x1b]0;title\x07\r\n\x1b[?25lcd", 3);
+        let mut expected = Canvas::new(3, 2);
+        let mut subview = expected.subview_mut(0, 0, 0, 0, 3, 2);
+        subview.set_text(
+            0,
+            0,
+            "ab",
+            CanvasTextStyle {
+                color: Some(Color::DarkRed),
+                ..Default::default()
+            },
+        );
+        subview.set_text(0, 1, "cd", CanvasTextStyle::default());
+        assert_eq!(canvas, expected);
+
+        // Text wraps at the given width, and can be overwritten via cursor movement.
+        let canvas = Canvas::from_ansi("abcdef\x1b[1;2Hx\x1b[2;3H\x1b[1Ky", 4);
+        assert_eq!(canvas.to_string(), "axcd\n  y\n");
+
+        // Overwriting half of a wide character clears the whole character.
+        let canvas = Canvas::from_ansi("一二\x1b[1Gx\x1b[4Gy", 4);
+        assert_eq!(canvas.to_string(), "x  y\n");
+
+        let canvas = Canvas::from_ansi("\x1b[48;5;9m\x1b[Kfoo\x1b[38;2;1;2;3mbar\x1b[49m\n", 8);
+        let mut expected = Canvas::new(8, 1);
+        let mut subview = expected.subview_mut(0, 0, 0, 0, 8, 1);
+        subview.set_background_color(0, 0, 8, 1, Color::Red);
+        subview.set_text(0, 0, "foo", CanvasTextStyle::default());
+        subview.set_text(
+            3,
+            0,
+            "bar",
+            CanvasTextStyle {
+                color: Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+                ..Default::default()
+            },
+        );
+        assert_eq!(canvas, expected);
+    }
+
+    #[test]
+    fn test_from_ansi_huge_parameters() {
+        let params = [
+            "0",
+            "1",
+            "65535",
+            "999999999",
+            "18446744073709551615",
+            "18446744073709551616",
+            "1;18446744073709551615",
+            "18446744073709551615;18446744073709551615",
+            "4:18446744073709551615",
+        ];
+        for command in "ABCDEFGHJKfm".chars() {
+            for param in params {
+                for prefix in ["", "foo\n\n", "\x1b[5A"] {
+                    let ansi = format!(
+                        "{}\x1b[{}{}bar\x1b[{}{}baz",
+                        prefix, param, command, param, command
+                    );
+                    let canvas = Canvas::from_ansi(&ansi, 10);
+                    assert!(canvas.height() <= 8, "{:?}: {}", ansi, canvas.height());
+                }
+            }
+        }
+
+        // Cursor movement can't go below the lowest line reached by text or newlines.
+        let canvas = Canvas::from_ansi("a\n\nb\x1b[3A\x1b[999999999Bc", 3);
+        assert_eq!(canvas.to_string(), "a\n\nbc\n");
+    }
+
+    #[test]
+    fn test_from_ansi_extended_sgr() {
+        let canvas = Canvas::from_ansi(
+            "\x1b[4:3;58:2::1:2:3;9;53ma\x1b[24;29;55;59m\x1b[7;5;8mb\x1b[27;25;28mc",
+            3,
+        );
+        let mut expected = Canvas::new(3, 1);
+        let mut subview = expected.subview_mut(0, 0, 0, 0, 3, 1);
+        subview.set_text(
+            0,
+            0,
+            "a",
+            CanvasTextStyle {
+                underline: true,
+                underline_style: UnderlineStyle::Curly,
+                underline_color: Some(Color::Rgb { r: 1, g: 2, b: 3 }),
+                strikethrough: true,
+                overline: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            1,
+            0,
+            "b",
+            CanvasTextStyle {
+                reverse: true,
+                blink: true,
+                hidden: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(2, 0, "c", CanvasTextStyle::default());
+        assert_eq!(canvas, expected);
+    }
+
+    #[test]
+    fn test_write_html_extended_text_styles() {
+        let mut canvas = Canvas::new(3, 1);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 3, 1);
+        subview.set_text(
+            0,
+            0,
+            "a",
+            CanvasTextStyle {
+                underline: true,
+                underline_style: UnderlineStyle::Dotted,
+                underline_color: Some(Color::Blue),
+                strikethrough: true,
+                ..Default::default()
+            },
+        );
+        subview.set_background_color(1, 0, 1, 1, Color::Red);
+        subview.set_text(
+            1,
+            0,
+            "b",
+            CanvasTextStyle {
+                reverse: true,
+                ..Default::default()
+            },
+        );
+        subview.set_text(
+            2,
+            0,
+            "c",
+            CanvasTextStyle {
+                hidden: true,
+                ..Default::default()
+            },
+        );
+
+        let mut actual = Vec::new();
+        canvas.write_html(&mut actual).unwrap();
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            concat!(
+                "<pre><span style=\"text-decoration: underline line-through dotted #0000ff\">a</span>",
+                "<span style=\"background-color: CanvasText; color: #ff0000\">b</span>",
+                "<span style=\"visibility: hidden\">c</span>\n",
+                "</pre>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_links() {
+        let link = CanvasTextStyle {
+            link: Some("https://example.com/?a&b".into()),
+            ..Default::default()
+        };
+        let mut canvas = Canvas::new(4, 3);
+        let mut subview = canvas.subview_mut(0, 0, 0, 0, 4, 3);
+        subview.set_text(0, 0, "see:", CanvasTextStyle::default());
+        subview.set_text(0, 1, "link", link.clone());
+        subview.set_text(0, 2, "ed", link.clone());
+        subview.set_text(
+            2,
+            2,
+            "!",
+            CanvasTextStyle {
+                weight: Weight::Bold,
+                ..Default::default()
+            },
+        );
+
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        let osc = format!(
+            "\x1b]8;id={:x};https://example.com/?a&b\x1b\\",
+            link_id("https://example.com/?a&b")
+        );
+        assert_eq!(
+            actual,
+            format!(
+                concat!(
+                    "\x1b[0msee\x1b[K:\r\n",
+                    "{osc}lin\x1b[Kk\x1b]8;;\x1b\\\r\n",
+                    "{osc}ed\x1b]8;;\x1b\\\x1b[1m!\x1b[K\x1b[0m\r\n",
+                ),
+                osc = osc
+            )
+        );
+        assert_eq!(Canvas::from_ansi(&actual, 4), canvas);
+
+        // Links don't appear in plain text output.
+        assert_eq!(canvas.to_string(), "see:\nlink\ned!\n");
+
+        // Links continued on the next row are written as a single element.
+        let mut actual = Vec::new();
+        canvas.write_html(&mut actual).unwrap();
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            concat!(
+                "<pre>see:\n",
+                "<a href=\"https://example.com/?a&amp;b\">link\n",
+                "ed</a><span style=\"font-weight: bold\">!</span>\n",
+                "</pre>\n",
+            )
+        );
+    }
+
+    #[test]
+    fn test_link_control_characters() {
+        let mut canvas = Canvas::new(1, 1);
+        canvas.subview_mut(0, 0, 0, 0, 1, 1).set_text(
+            0,
+            0,
+            "x",
+            CanvasTextStyle {
+                link: Some("a\x1b\\\x07b".into()),
+                ..Default::default()
+            },
+        );
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        assert!(actual.contains(&format!("\x1b]8;id={:x};a\\b\x1b\\", link_id("a\\b"))));
+    }
+
+    #[test]
+    fn test_css_color() {
+        assert_eq!(css_color(Color::Reset), None);
+        assert_eq!(css_color(Color::DarkCyan).unwrap(), "#008080");
+        assert_eq!(css_color(Color::AnsiValue(9)).unwrap(), "#ff0000");
+        assert_eq!(css_color(Color::AnsiValue(16)).unwrap(), "#000000");
+        assert_eq!(css_color(Color::AnsiValue(67)).unwrap(), "#5f87af");
+        assert_eq!(css_color(Color::AnsiValue(231)).unwrap(), "#ffffff");
+        assert_eq!(css_color(Color::AnsiValue(244)).unwrap(), "#808080");
+    }
+
+    #[test]
+    fn test_truncate_with_overflow_indicator() {
+        let mut canvas = Canvas::new(4, 3);
+        canvas
+            .subview_mut(0, 0, 0, 0, 4, 3)
+            .set_text(0, 0, "a\nb\nc", CanvasTextStyle::default());
+
+        let mut truncated = canvas.clone();
+        truncated.truncate_with_overflow_indicator(None, 3);
+        assert_eq!(truncated, canvas);
+
+        let mut truncated = canvas.clone();
+        truncated.truncate_with_overflow_indicator(None, 2);
+        assert_eq!(truncated.to_string(), "a\n… 2 more lines\n");
+
+        let mut truncated = canvas.clone();
+        truncated.truncate_with_overflow_indicator(Some(8), 2);
+        assert_eq!(truncated.to_string(), "a\n… 2 more\n");
+
+        let mut truncated = canvas.clone();
+        truncated.truncate_with_overflow_indicator(None, 0);
+        assert_eq!(truncated.height(), 0);
+    }
+
+    #[test]
+    fn test_write_ansi_diff() {
+        let mut prev = Canvas::new(10, 2);
+        prev.subview_mut(0, 0, 0, 0, 10, 2).set_text(
+            0,
+            0,
+            "hello!\nworld",
+            CanvasTextStyle::default(),
+        );
+
+        // Nothing changed, so nothing should be written.
+        let mut actual = Vec::new();
+        prev.clone()
+            .write_ansi_diff(&mut actual, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+        assert!(actual.is_empty());
+
+        // Only the changed cell should be written.
+        let mut canvas = prev.clone();
+        canvas.subview_mut(0, 0, 0, 0, 10, 2).set_text(
+            4,
+            0,
+            "O",
+            CanvasTextStyle {
+                color: Some(Color::Red),
+                ..Default::default()
+            },
+        );
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_diff(&mut actual, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "{}", cursor::MoveToPreviousLine(2)).unwrap();
+        write!(expected, "{}", cursor::MoveToColumn(4)).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("{}m"), Colored::ForegroundColor(Color::Red)).unwrap();
+        write!(expected, "O").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n\r\n").unwrap();
+        assert_eq!(actual, expected);
+
+        // Shortening a line should clear the rest of it.
+        let mut canvas = Canvas::new(10, 2);
+        canvas.subview_mut(0, 0, 0, 0, 10, 2).set_text(
+            0,
+            0,
+            "hello!\nwor",
+            CanvasTextStyle::default(),
+        );
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_diff(&mut actual, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "{}", cursor::MoveToPreviousLine(1)).unwrap();
+        write!(expected, "{}", cursor::MoveToColumn(3)).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_write_ansi_diff_wide_characters() {
+        let mut prev = Canvas::new(10, 1);
+        prev.subview_mut(0, 0, 0, 0, 10, 1)
+            .set_text(0, 0, "一二三", CanvasTextStyle::default());
+
+        // Replacing a wide character with narrow ones only rewrites its cells.
+        let mut canvas = prev.clone();
+        canvas
+            .subview_mut(0, 0, 0, 0, 10, 1)
+            .set_text(2, 0, "xy", CanvasTextStyle::default());
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_diff(&mut actual, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "{}", cursor::MoveToPreviousLine(1)).unwrap();
+        write!(expected, "{}", cursor::MoveToColumn(2)).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "xy").unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_write_ansi_diff_height_change() {
+        let mut prev = Canvas::new(10, 3);
+        prev.subview_mut(0, 0, 0, 0, 10, 3)
+            .set_text(0, 0, "a\nb\nc", CanvasTextStyle::default());
+
+        // Removed rows should be cleared.
+        let mut canvas = Canvas::new(10, 1);
+        canvas
+            .subview_mut(0, 0, 0, 0, 10, 1)
+            .set_text(0, 0, "a", CanvasTextStyle::default());
+        let mut actual = Vec::new();
+        canvas
+            .write_ansi_diff(&mut actual, &prev, false, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "{}", cursor::MoveToPreviousLine(2)).unwrap();
+        write!(expected, "{}", cursor::MoveToColumn(0)).unwrap();
+        write!(expected, csi!("J")).unwrap();
+        assert_eq!(actual, expected);
+
+        // Added rows should be written in full.
+        let mut actual = Vec::new();
+        prev.write_ansi_diff(&mut actual, &canvas, false, ColorDepth::TrueColor)
+            .unwrap();
+
+        let mut expected = Vec::new();
+        write!(expected, "{}", cursor::MoveToColumn(0)).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "b").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        write!(expected, "{}", cursor::MoveToColumn(0)).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "c").unwrap();
+        write!(expected, csi!("K")).unwrap();
+        write!(expected, csi!("0m")).unwrap();
+        write!(expected, "\r\n").unwrap();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_ansi_erase_for_full_rows() {
         let mut canvas = Canvas::new(10, 1);
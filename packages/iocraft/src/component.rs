@@ -13,6 +13,7 @@ use core::{
     task::{Context, Poll},
 };
 use futures::future::poll_fn;
+use std::collections::BTreeSet;
 use taffy::NodeId;
 
 pub(crate) struct ComponentHelper<C: Component> {
@@ -136,6 +137,7 @@ pub(crate) struct InstantiatedComponent {
     hooks: Vec<Box<dyn AnyHook>>,
     first_update: bool,
     has_transparent_layout: bool,
+    z_index: Option<i32>,
 }
 
 impl InstantiatedComponent {
@@ -148,6 +150,7 @@ impl InstantiatedComponent {
             hooks: Default::default(),
             first_update: true,
             has_transparent_layout: false,
+            z_index: None,
         }
     }
 
@@ -183,9 +186,33 @@ impl InstantiatedComponent {
         self.hooks.post_component_update(&mut updater);
         self.first_update = false;
         self.has_transparent_layout = updater.has_transparent_layout();
+        self.z_index = updater.z_index();
+    }
+
+    /// Adds the layers that the component and its descendants belong to, given the layer of the
+    /// component's parent.
+    pub fn collect_layers(&self, parent_layer: i32, layers: &mut BTreeSet<i32>) {
+        let layer = self.z_index.unwrap_or(parent_layer);
+        layers.insert(layer);
+        for child in self.children.components.iter() {
+            child.collect_layers(layer, layers);
+        }
     }
 
     pub fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        drawer.with_z_index(self.z_index, |drawer| {
+            if drawer.is_drawing_node_layer() {
+                self.draw_self_and_children(drawer);
+            } else {
+                // Descendants may still belong to the layer being drawn.
+                drawer.with_clip_rect_for_children(|drawer| {
+                    self.children.draw(drawer);
+                });
+            }
+        });
+    }
+
+    fn draw_self_and_children(&mut self, drawer: &mut ComponentDrawer<'_>) {
         if self.has_transparent_layout {
             // If the component has a transparent layout, provide the first child's layout to the
             // hooks and component.
@@ -0,0 +1,211 @@
+use crate::{
+    component,
+    components::{Button, Text},
+    element, AnyElement, Color, Handler, Props,
+};
+
+/// The glyphs used to display the state of a [`Checkbox`], [`Switch`](crate::components::Switch),
+/// or [`RadioGroup`](crate::components::RadioGroup) option.
+///
+/// Each component has Unicode glyphs by default. For terminals or fonts which can't display them,
+/// ASCII alternatives are also available.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ToggleGlyphs {
+    /// The glyph displayed when checked or on.
+    pub on: String,
+    /// The glyph displayed when unchecked or off.
+    pub off: String,
+}
+
+impl ToggleGlyphs {
+    /// Creates glyphs from the given strings.
+    pub fn new<S: Into<String>, T: Into<String>>(on: S, off: T) -> Self {
+        Self {
+            on: on.into(),
+            off: off.into(),
+        }
+    }
+
+    /// The default glyphs for a [`Checkbox`]: `☑` and `☐`.
+    pub fn checkbox() -> Self {
+        Self::new("☑", "☐")
+    }
+
+    /// ASCII glyphs for a [`Checkbox`]: `[x]` and `[ ]`.
+    pub fn checkbox_ascii() -> Self {
+        Self::new("[x]", "[ ]")
+    }
+
+    /// The default glyphs for a [`RadioGroup`](crate::components::RadioGroup): `◉` and `◯`.
+    pub fn radio() -> Self {
+        Self::new("◉", "◯")
+    }
+
+    /// ASCII glyphs for a [`RadioGroup`](crate::components::RadioGroup): `(*)` and `( )`.
+    pub fn radio_ascii() -> Self {
+        Self::new("(*)", "( )")
+    }
+
+    /// The default glyphs for a [`Switch`](crate::components::Switch): `━━●` and `●━━`.
+    pub fn switch() -> Self {
+        Self::new("━━●", "●━━")
+    }
+
+    /// ASCII glyphs for a [`Switch`](crate::components::Switch): `[ on]` and `[off]`.
+    pub fn switch_ascii() -> Self {
+        Self::new("[ on]", "[off]")
+    }
+
+    /// Returns the text for a toggle in the given state, with an optional label after the glyph.
+    pub(crate) fn text(&self, on: bool, label: &str) -> String {
+        let glyph = if on { &self.on } else { &self.off };
+        if label.is_empty() {
+            glyph.clone()
+        } else {
+            format!("{} {}", glyph, label)
+        }
+    }
+}
+
+/// The props which can be passed to the [`Checkbox`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct CheckboxProps {
+    /// The text to display next to the checkbox.
+    pub label: String,
+
+    /// True if the checkbox is checked.
+    pub checked: bool,
+
+    /// The handler to invoke when the user toggles the checkbox. It's given the new state, which
+    /// the parent should pass back via [`checked`](Self::checked).
+    ///
+    /// The checkbox can be toggled two ways:
+    ///
+    /// - By clicking on it with the mouse while in fullscreen mode.
+    /// - By pressing the Enter or Space key while [`has_focus`](Self::has_focus) is `true`.
+    pub on_change: Handler<'static, bool>,
+
+    /// True if the checkbox has focus and should process keyboard input.
+    pub has_focus: bool,
+
+    /// The glyphs to display. Defaults to [`ToggleGlyphs::checkbox`].
+    pub glyphs: Option<ToggleGlyphs>,
+
+    /// The color of the checkbox and label.
+    pub color: Option<Color>,
+
+    /// The color of the checkbox and label while focused. Defaults to blue.
+    pub focus_color: Option<Color>,
+}
+
+/// `Checkbox` is a component which displays a labeled box that the user can check or uncheck.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Settings(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut subscribe = hooks.use_state(|| false);
+///
+///     element! {
+///         Checkbox(
+///             label: "Subscribe to the newsletter",
+///             checked: subscribe.get(),
+///             on_change: move |checked| subscribe.set(checked),
+///             has_focus: true,
+///         )
+///     }
+/// }
+/// ```
+#[component]
+pub fn Checkbox(props: &mut CheckboxProps) -> impl Into<AnyElement<'static>> {
+    let checked = props.checked;
+    let mut on_change = props.on_change.take();
+    let glyphs = props.glyphs.clone().unwrap_or_else(ToggleGlyphs::checkbox);
+
+    element! {
+        Button(
+            has_focus: props.has_focus,
+            handler: {
+                let mut checked = checked;
+                move |_| {
+                    checked = !checked;
+                    on_change(checked);
+                }
+            },
+        ) {
+            Text(
+                content: glyphs.text(checked, &props.label),
+                color: if props.has_focus {
+                    Some(props.focus_color.unwrap_or(Color::Blue))
+                } else {
+                    props.color
+                },
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_events},
+    };
+    use crossterm::event::MouseButton;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[test]
+    fn test_checkbox() {
+        assert_eq!(element!(Checkbox(label: "foo")).to_string(), "☐ foo\n");
+        assert_eq!(
+            element!(Checkbox(label: "foo", checked: true, glyphs: ToggleGlyphs::checkbox_ascii()))
+                .to_string(),
+            "[x] foo\n"
+        );
+        assert_eq!(element!(Checkbox(checked: true)).to_string(), "☑\n");
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut checked = hooks.use_state(|| false);
+
+        element! {
+            Checkbox(
+                label: "foo",
+                checked: checked.get(),
+                on_change: move |value| checked.set(value),
+                has_focus: true,
+            )
+        }
+    }
+
+    async fn render_last(events: Vec<TerminalEvent>) -> String {
+        render_with_events(|| element!(MyComponent).into_any(), events)
+            .await
+            .pop()
+            .unwrap()
+            .to_string()
+    }
+
+    #[apply(test!)]
+    async fn test_checkbox_toggle() {
+        let click = TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+            MouseEventKind::Down(MouseButton::Left),
+            2,
+            0,
+        ));
+        assert_eq!(render_last(vec![key(KeyCode::Char(' '))]).await, "☑ foo\n");
+        assert_eq!(
+            render_last(vec![key(KeyCode::Char(' ')), click.clone()]).await,
+            "☐ foo\n"
+        );
+        assert_eq!(
+            render_last(vec![key(KeyCode::Char(' ')), click, key(KeyCode::Enter)]).await,
+            "☑ foo\n"
+        );
+    }
+}
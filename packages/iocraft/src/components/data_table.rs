@@ -0,0 +1,1089 @@
+use crate::{
+    components::{
+        BorderStyle, ColumnWidth, Table, TableColumn, TableRowStyle, Text, TextAlign, TextWrap,
+        View,
+    },
+    element,
+    hooks::{UseState, UseTerminalEvents},
+    Color, Component, ComponentUpdater, FlexDirection, Handler, Hooks, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, Props, TerminalEvent, Weight,
+};
+use core::cmp::Ordering;
+use iocraft_macros::with_layout_style_props;
+use serde::{
+    ser::{
+        self, SerializeMap, SerializeSeq, SerializeStruct, SerializeStructVariant, SerializeTuple,
+        SerializeTupleStruct, SerializeTupleVariant,
+    },
+    Serialize,
+};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Rows of data to be displayed by a [`DataTable`].
+///
+/// Rows can be created from any type implementing [`serde::Serialize`]. Structs and maps become
+/// one column per field or key, in the order they're serialized, and anything else becomes a
+/// single column named "value". Rows don't need to have the same fields. Missing fields are left
+/// empty.
+///
+/// The same rows can be rendered interactively or exported as CSV or JSON, making it easy to
+/// support both human and machine readable output.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[derive(serde::Serialize)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let rows = DataRows::from(vec![User { id: 1, name: "Alice" }, User { id: 2, name: "Bob" }]);
+/// assert_eq!(rows.columns(), ["id", "name"]);
+/// assert_eq!(rows.to_csv(), "id,name\n1,Alice\n2,Bob\n");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DataRows {
+    columns: Vec<String>,
+    // The rows are shared so that cloning them, e.g. for a table's event handler, is cheap.
+    rows: Arc<[Vec<Value>]>,
+}
+
+impl DataRows {
+    /// Serializes the given rows, returning an error if any of them fail to serialize.
+    pub fn try_new<T: Serialize, I: IntoIterator<Item = T>>(
+        rows: I,
+    ) -> Result<Self, serde_json::Error> {
+        let mut columns = Vec::new();
+        let mut ret_rows = Vec::new();
+        for row in rows {
+            let cells = row.serialize(RowSerializer)?;
+            let mut values = vec![Value::Null; columns.len()];
+            for (name, value) in cells {
+                let i = match columns.iter().position(|column| *column == name) {
+                    Some(i) => i,
+                    None => {
+                        columns.push(name);
+                        values.push(Value::Null);
+                        columns.len() - 1
+                    }
+                };
+                values[i] = value;
+            }
+            ret_rows.push(values);
+        }
+        for row in &mut ret_rows {
+            row.resize(columns.len(), Value::Null);
+        }
+        Ok(Self {
+            columns,
+            rows: ret_rows.into(),
+        })
+    }
+
+    /// Returns the names of the columns.
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// Returns the number of rows.
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if there are no rows.
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Returns the text displayed for the given cell, or `None` if it's out of bounds.
+    pub fn cell_text(&self, row: usize, column: usize) -> Option<String> {
+        self.rows
+            .get(row)
+            .and_then(|row| row.get(column))
+            .map(value_text)
+    }
+
+    /// Returns only the rows which contain the given text in any of their cells, ignoring case.
+    pub fn filtered(&self, query: &str) -> Self {
+        self.select(&self.visible_indices(query, None))
+    }
+
+    /// Returns the rows sorted by the given column. The sort is stable, so rows with equal values
+    /// keep their relative order.
+    pub fn sorted(&self, sort: DataSort) -> Self {
+        self.select(&self.visible_indices("", Some(sort)))
+    }
+
+    /// Formats the rows as CSV, with a header row containing the column names.
+    pub fn to_csv(&self) -> String {
+        let mut ret = String::new();
+        for row in std::iter::once(self.columns.clone()).chain(
+            self.rows
+                .iter()
+                .map(|row| row.iter().map(value_text).collect()),
+        ) {
+            let fields: Vec<_> = row.iter().map(|field| csv_field(field)).collect();
+            ret.push_str(&fields.join(","));
+            ret.push('\n');
+        }
+        ret
+    }
+
+    /// Formats the rows as a JSON array of objects, with fields in column order. Empty cells are
+    /// omitted.
+    pub fn to_json(&self) -> String {
+        let rows: Vec<_> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let fields: Vec<_> = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .filter(|(_, value)| !value.is_null())
+                    .map(|(column, value)| format!("{}:{}", Value::from(column.as_str()), value))
+                    .collect();
+                format!("{{{}}}", fields.join(","))
+            })
+            .collect();
+        format!("[{}]", rows.join(","))
+    }
+
+    fn select(&self, indices: &[usize]) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            rows: indices.iter().map(|&i| self.rows[i].clone()).collect(),
+        }
+    }
+
+    /// Returns the indices of the rows matching the query, in the given order.
+    fn visible_indices(&self, query: &str, sort: Option<DataSort>) -> Vec<usize> {
+        let query = query.to_lowercase();
+        let mut indices: Vec<usize> = (0..self.rows.len())
+            .filter(|&i| {
+                query.is_empty()
+                    || self.rows[i]
+                        .iter()
+                        .any(|value| value_text(value).to_lowercase().contains(&query))
+            })
+            .collect();
+        if let Some(sort) = sort.filter(|sort| sort.column < self.columns.len()) {
+            indices.sort_by(|&a, &b| {
+                let ordering =
+                    compare_values(&self.rows[a][sort.column], &self.rows[b][sort.column]);
+                match sort.direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+        indices
+    }
+
+    fn is_numeric_column(&self, column: usize) -> bool {
+        let mut values = self
+            .rows
+            .iter()
+            .map(|row| &row[column])
+            .filter(|value| !value.is_null())
+            .peekable();
+        values.peek().is_some() && values.all(Value::is_number)
+    }
+}
+
+impl<T: Serialize> From<Vec<T>> for DataRows {
+    /// Serializes the given rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the rows fail to serialize. See [`DataRows::try_new`] for a fallible
+    /// alternative.
+    fn from(rows: Vec<T>) -> Self {
+        Self::from(rows.as_slice())
+    }
+}
+
+impl<T: Serialize> From<&Vec<T>> for DataRows {
+    /// Serializes the given rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the rows fail to serialize. See [`DataRows::try_new`] for a fallible
+    /// alternative.
+    fn from(rows: &Vec<T>) -> Self {
+        Self::from(rows.as_slice())
+    }
+}
+
+impl<T: Serialize> From<&[T]> for DataRows {
+    /// Serializes the given rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the rows fail to serialize. See [`DataRows::try_new`] for a fallible
+    /// alternative.
+    fn from(rows: &[T]) -> Self {
+        Self::try_new(rows).expect("rows should be serializable")
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        _ => value.to_string(),
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Compares values such that empty cells come first, numbers are ordered numerically, and
+/// everything else is ordered by its text.
+fn compare_values(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Null, Value::Null) => Ordering::Equal,
+        (Value::Null, _) => Ordering::Less,
+        (_, Value::Null) => Ordering::Greater,
+        (Value::Number(a), Value::Number(b)) => a
+            .as_f64()
+            .partial_cmp(&b.as_f64())
+            .unwrap_or(Ordering::Equal),
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+        _ => value_text(a).cmp(&value_text(b)),
+    }
+}
+
+type Cells = Vec<(String, Value)>;
+
+/// Serializes a single row into named cells. Unlike [`serde_json::to_value`], this preserves the
+/// order of struct fields.
+struct RowSerializer;
+
+impl RowSerializer {
+    fn value<T: Serialize + ?Sized>(v: &T) -> Result<Cells, serde_json::Error> {
+        Ok(vec![("value".to_string(), serde_json::to_value(v)?)])
+    }
+}
+
+macro_rules! serialize_values {
+    ($($method:ident: $ty:ty),*) => {$(
+        fn $method(self, v: $ty) -> Result<Cells, serde_json::Error> {
+            Self::value(&v)
+        }
+    )*};
+}
+
+impl ser::Serializer for RowSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+    type SerializeSeq = CellsSerializer;
+    type SerializeTuple = CellsSerializer;
+    type SerializeTupleStruct = CellsSerializer;
+    type SerializeTupleVariant = CellsSerializer;
+    type SerializeMap = CellsSerializer;
+    type SerializeStruct = CellsSerializer;
+    type SerializeStructVariant = CellsSerializer;
+
+    serialize_values!(
+        serialize_bool: bool, serialize_i8: i8, serialize_i16: i16, serialize_i32: i32,
+        serialize_i64: i64, serialize_i128: i128, serialize_u8: u8, serialize_u16: u16,
+        serialize_u32: u32, serialize_u64: u64, serialize_u128: u128, serialize_f32: f32,
+        serialize_f64: f64, serialize_char: char, serialize_str: &str, serialize_bytes: &[u8]
+    );
+
+    fn serialize_none(self) -> Result<Cells, serde_json::Error> {
+        Self::value(&())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Cells, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Cells, serde_json::Error> {
+        Self::value(&())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Cells, serde_json::Error> {
+        Self::value(&())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Cells, serde_json::Error> {
+        Self::value(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Cells, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        value: &T,
+    ) -> Result<Cells, serde_json::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_tuple(self, _len: usize) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<CellsSerializer, serde_json::Error> {
+        Ok(CellsSerializer::default())
+    }
+}
+
+/// Collects the cells of a compound row. Sequences and tuples are given columns named after their
+/// indices.
+#[derive(Default)]
+struct CellsSerializer {
+    cells: Cells,
+    key: Option<String>,
+}
+
+impl CellsSerializer {
+    fn push<T: Serialize + ?Sized>(
+        &mut self,
+        name: String,
+        value: &T,
+    ) -> Result<(), serde_json::Error> {
+        self.cells.push((name, serde_json::to_value(value)?));
+        Ok(())
+    }
+
+    fn push_indexed<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), serde_json::Error> {
+        self.push(self.cells.len().to_string(), value)
+    }
+}
+
+impl SerializeSeq for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_indexed(value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeTuple for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_indexed(value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeTupleStruct for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_indexed(value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeTupleVariant for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push_indexed(value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeMap for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = Some(value_text(&serde_json::to_value(key)?));
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self.key.take().unwrap_or_default();
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeStruct for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+impl SerializeStructVariant for CellsSerializer {
+    type Ok = Cells;
+    type Error = serde_json::Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<Cells, Self::Error> {
+        Ok(self.cells)
+    }
+}
+
+/// The direction of a [`DataSort`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    /// Smallest values first.
+    #[default]
+    Ascending,
+    /// Largest values first.
+    Descending,
+}
+
+/// The order of the rows of a [`DataTable`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DataSort {
+    /// The index of the column to sort by.
+    pub column: usize,
+    /// The direction to sort in.
+    pub direction: SortDirection,
+}
+
+/// The format of a [`DataExport`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataExportFormat {
+    /// Comma-separated values, as produced by [`DataRows::to_csv`].
+    Csv,
+    /// A JSON array of objects, as produced by [`DataRows::to_json`].
+    Json,
+}
+
+/// The rows visible in a [`DataTable`], exported at the user's request.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataExport {
+    /// The format of the content.
+    pub format: DataExportFormat,
+    /// The exported rows.
+    pub content: String,
+}
+
+/// The props which can be passed to the [`DataTable`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct DataTableProps {
+    /// The rows to display.
+    pub rows: DataRows,
+
+    /// If true, the filter box above the table isn't displayed.
+    pub hide_filter: bool,
+
+    /// The style of the border around the table.
+    pub border_style: BorderStyle,
+
+    /// The color of the border and grid lines.
+    pub border_color: Option<Color>,
+
+    /// If true, lines are drawn between columns.
+    pub column_dividers: bool,
+
+    /// The style of the header row. Defaults to bold text.
+    pub header_style: Option<TableRowStyle>,
+
+    /// If given, every other row uses this style.
+    pub stripe_style: Option<TableRowStyle>,
+
+    /// The style of the selected row. Defaults to reversed colors.
+    pub selected_row_style: Option<TableRowStyle>,
+
+    /// The maximum width of each column. Longer values are truncated.
+    pub max_column_width: Option<u16>,
+
+    /// True if the data table has focus and should process keyboard input.
+    ///
+    /// The Up, Down, Home and End keys change the selected row. Left and Right sort by the
+    /// previous or next column, and `r` reverses the sort. `/` or Tab moves focus to the filter
+    /// box, where typed characters are appended to the filter and Backspace removes the last one,
+    /// and Enter, Escape or Tab moves it back. `c` and `j` export the visible rows as CSV or
+    /// JSON via [`on_export`](Self::on_export).
+    pub has_focus: bool,
+
+    /// The handler to invoke when the user changes the selection. It's given the index of the
+    /// selected row within [`rows`](Self::rows), regardless of sorting and filtering.
+    pub on_select: Handler<'static, usize>,
+
+    /// The handler to invoke when the user exports the visible rows.
+    pub on_export: Handler<'static, DataExport>,
+}
+
+/// `DataTable` is a component which displays [`DataRows`] in a [`Table`] that the user can sort,
+/// filter, and export.
+///
+/// Columns are derived from the rows' field names, and columns containing only numbers are right
+/// aligned.
+///
+/// This component is only available with the `serde` feature enabled.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[derive(serde::Serialize)]
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// #[component]
+/// fn Users(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let users = hooks.use_state(|| vec![
+///         User { id: 1, name: "Alice".to_string() },
+///         User { id: 2, name: "Bob".to_string() },
+///     ]);
+///
+///     element! {
+///         DataTable(
+///             rows: &*users.read(),
+///             has_focus: true,
+///             border_style: BorderStyle::Round,
+///             on_export: |export: DataExport| println!("{}", export.content),
+///         )
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct DataTable;
+
+impl Component for DataTable {
+    type Props<'a> = DataTableProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let mut filter = hooks.use_state(String::new);
+        let mut is_filter_focused = hooks.use_state(|| false);
+        let mut sort = hooks.use_state(|| None::<DataSort>);
+        // The selection is tracked by its index within the rows so that it follows the row when
+        // the rows are sorted or filtered.
+        let mut selected_row = hooks.use_state(|| 0usize);
+
+        let column_count = props.rows.columns.len();
+        let visible_indices = props.rows.visible_indices(&filter.read(), sort.get());
+        let has_focus = props.has_focus;
+
+        let mut on_select = props.on_select.take();
+        let selected_position = match visible_indices
+            .iter()
+            .position(|&i| i == selected_row.get())
+        {
+            Some(position) => position,
+            None => {
+                // The selected row was filtered out, so the first visible row is selected instead.
+                if let Some(&index) = visible_indices.first() {
+                    selected_row.set(index);
+                    on_select(index);
+                }
+                0
+            }
+        };
+
+        hooks.use_local_terminal_events({
+            let mut on_export = props.on_export.take();
+            let hide_filter = props.hide_filter;
+            let rows = props.rows.clone();
+            move |event| match event {
+                TerminalEvent::Key(KeyEvent {
+                    code,
+                    kind,
+                    modifiers,
+                    ..
+                }) if has_focus && kind != KeyEventKind::Release => {
+                    if modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) {
+                        return;
+                    }
+                    // The filter is edited here rather than by a focused text input so that keys
+                    // which arrive after the filter is closed, but before the next render, aren't
+                    // added to it.
+                    if is_filter_focused.get() {
+                        match code {
+                            KeyCode::Enter | KeyCode::Esc | KeyCode::Tab => {
+                                is_filter_focused.set(false);
+                            }
+                            KeyCode::Char(c) => filter.write().push(c),
+                            KeyCode::Backspace => {
+                                filter.write().pop();
+                            }
+                            _ => {}
+                        }
+                        return;
+                    }
+                    match code {
+                        KeyCode::Char('/') | KeyCode::Tab if !hide_filter => {
+                            is_filter_focused.set(true);
+                        }
+                        KeyCode::Left | KeyCode::Right if column_count > 0 => {
+                            let column = match (sort.get(), code) {
+                                (None, KeyCode::Left) => column_count - 1,
+                                (None, _) => 0,
+                                (Some(sort), KeyCode::Left) => {
+                                    (sort.column + column_count - 1) % column_count
+                                }
+                                (Some(sort), _) => (sort.column + 1) % column_count,
+                            };
+                            sort.set(Some(DataSort {
+                                column,
+                                direction: SortDirection::Ascending,
+                            }));
+                        }
+                        KeyCode::Char('r') => {
+                            if let Some(current) = sort.get() {
+                                sort.set(Some(DataSort {
+                                    direction: match current.direction {
+                                        SortDirection::Ascending => SortDirection::Descending,
+                                        SortDirection::Descending => SortDirection::Ascending,
+                                    },
+                                    ..current
+                                }));
+                            }
+                        }
+                        KeyCode::Char('c') | KeyCode::Char('j') => {
+                            // The filter and sort may have changed since the last render.
+                            let visible_rows =
+                                rows.select(&rows.visible_indices(&filter.read(), sort.get()));
+                            on_export(match code {
+                                KeyCode::Char('c') => DataExport {
+                                    format: DataExportFormat::Csv,
+                                    content: visible_rows.to_csv(),
+                                },
+                                _ => DataExport {
+                                    format: DataExportFormat::Json,
+                                    content: visible_rows.to_json(),
+                                },
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        });
+
+        let current_sort = sort.get();
+        let columns = (0..column_count)
+            .map(|i| {
+                let name = &props.rows.columns[i];
+                TableColumn {
+                    header: match current_sort {
+                        Some(sort) if sort.column == i => match sort.direction {
+                            SortDirection::Ascending => format!("{} ▲", name),
+                            SortDirection::Descending => format!("{} ▼", name),
+                        },
+                        _ => name.clone(),
+                    },
+                    width: ColumnWidth::Auto,
+                    max_width: props.max_column_width,
+                    align: if props.rows.is_numeric_column(i) {
+                        TextAlign::Right
+                    } else {
+                        TextAlign::Left
+                    },
+                    ..Default::default()
+                }
+            })
+            .collect::<Vec<_>>();
+        let rows = visible_indices
+            .iter()
+            .map(|&i| props.rows.rows[i].iter().map(value_text).collect())
+            .collect::<Vec<_>>();
+        let is_table_focused = has_focus && !is_filter_focused.get();
+
+        updater.set_layout_style_props_with(props.layout_style(), |style| {
+            style.flex_direction = FlexDirection::Column;
+        });
+
+        let mut content = element! {
+            View(flex_direction: FlexDirection::Column) {
+                #((!props.hide_filter).then(|| element! {
+                    View(height: 1) {
+                        Text(
+                            content: "Filter: ",
+                            weight: if has_focus && is_filter_focused.get() { Weight::Bold } else { Weight::Normal },
+                        )
+                        Text(content: filter.to_string(), wrap: TextWrap::NoWrap)
+                        #((has_focus && is_filter_focused.get()).then(|| element! {
+                            View(width: 1, height: 1, background_color: Color::Grey)
+                        }))
+                    }
+                }))
+                Table(
+                    columns,
+                    rows,
+                    border_style: props.border_style,
+                    border_color: props.border_color,
+                    column_dividers: props.column_dividers,
+                    header_style: props.header_style.clone().unwrap_or(TableRowStyle {
+                        weight: Weight::Bold,
+                        ..Default::default()
+                    }),
+                    stripe_style: props.stripe_style.clone(),
+                    selected_row_style: props.selected_row_style.clone(),
+                    has_focus: is_table_focused,
+                    selected_row: selected_position,
+                    on_select: move |i| {
+                        if let Some(&index) = visible_indices.get(i) {
+                            selected_row.set(index);
+                            on_select(index);
+                        }
+                    },
+                )
+            }
+        };
+        updater.update_children([&mut content], None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_event_batches},
+    };
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+    use std::{
+        collections::BTreeMap,
+        sync::{Arc, Mutex},
+    };
+
+    #[derive(Clone, Serialize)]
+    struct User {
+        name: &'static str,
+        age: Option<u32>,
+        email: &'static str,
+    }
+
+    fn users() -> Vec<User> {
+        vec![
+            User {
+                name: "Charlie",
+                age: Some(35),
+                email: "charlie@example.com",
+            },
+            User {
+                name: "Alice",
+                age: Some(7),
+                email: "alice@example.com",
+            },
+            User {
+                name: "Bob",
+                age: None,
+                email: "bob, the \"builder\"",
+            },
+        ]
+    }
+
+    #[test]
+    fn test_data_rows() {
+        let rows = DataRows::from(users());
+        assert_eq!(rows.columns(), ["name", "age", "email"]);
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows.cell_text(1, 1).as_deref(), Some("7"));
+        assert_eq!(rows.cell_text(2, 1).as_deref(), Some(""));
+        assert_eq!(rows.cell_text(3, 1), None);
+
+        let maps = vec![
+            BTreeMap::from([("a", 1)]),
+            BTreeMap::from([("a", 2), ("b", 3)]),
+        ];
+        let rows = DataRows::from(maps);
+        assert_eq!(rows.columns(), ["a", "b"]);
+        assert_eq!(rows.cell_text(0, 1).as_deref(), Some(""));
+
+        let rows = DataRows::from(vec!["foo", "bar"]);
+        assert_eq!(rows.columns(), ["value"]);
+        assert_eq!(rows.cell_text(1, 0).as_deref(), Some("bar"));
+
+        let rows = DataRows::from(vec![(1, "foo")]);
+        assert_eq!(rows.columns(), ["0", "1"]);
+
+        let rows = DataRows::from(vec![BTreeMap::from([((1, 2), 3)])]);
+        assert_eq!(rows.columns(), ["[1,2]"]);
+
+        // Nested maps can't have non-string keys.
+        assert!(
+            DataRows::try_new([BTreeMap::from([("a", BTreeMap::from([((1, 2), 3)]))])]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_data_rows_filter_and_sort() {
+        let rows = DataRows::from(users());
+        let names = |rows: &DataRows| {
+            (0..rows.len())
+                .map(|i| rows.cell_text(i, 0).unwrap())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(&rows.filtered("ALICE")), ["Alice"]);
+        assert_eq!(names(&rows.filtered("example")), ["Charlie", "Alice"]);
+        assert_eq!(names(&rows.filtered("3")), ["Charlie"]);
+
+        let sort = |column, direction| DataSort { column, direction };
+        assert_eq!(
+            names(&rows.sorted(sort(0, SortDirection::Ascending))),
+            ["Alice", "Bob", "Charlie"]
+        );
+        assert_eq!(
+            names(&rows.sorted(sort(1, SortDirection::Ascending))),
+            ["Bob", "Alice", "Charlie"]
+        );
+        assert_eq!(
+            names(&rows.sorted(sort(1, SortDirection::Descending))),
+            ["Charlie", "Alice", "Bob"]
+        );
+    }
+
+    #[test]
+    fn test_data_rows_export() {
+        let rows = DataRows::from(users());
+        assert_eq!(
+            rows.to_csv(),
+            indoc::indoc! {r#"
+                name,age,email
+                Charlie,35,charlie@example.com
+                Alice,7,alice@example.com
+                Bob,,"bob, the ""builder"""
+            "#}
+        );
+        assert_eq!(
+            rows.filtered("example").to_json(),
+            r#"[{"name":"Charlie","age":35,"email":"charlie@example.com"},{"name":"Alice","age":7,"email":"alice@example.com"}]"#
+        );
+        assert_eq!(
+            rows.filtered("bob").to_json(),
+            r#"[{"name":"Bob","email":"bob, the \"builder\""}]"#
+        );
+    }
+
+    #[test]
+    fn test_data_table() {
+        assert_eq!(
+            element!(DataTable(rows: users(), hide_filter: true)).to_string(),
+            indoc::indoc! {r#"
+                 name     age  email
+                ───────────────────────────────────
+                 Charlie   35  charlie@example.com
+                 Alice      7  alice@example.com
+                 Bob           bob, the "builder"
+            "#}
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_data_table_interaction() {
+        let batches = vec![
+            // Sort by age, descending, and start filtering.
+            vec![
+                key(KeyCode::Right),
+                key(KeyCode::Right),
+                key(KeyCode::Char('r')),
+                key(KeyCode::Char('/')),
+                key(KeyCode::Char('l')),
+            ],
+            // Filter by "li". Keys after Enter belong to the table, even before the next render.
+            vec![
+                key(KeyCode::Char('i')),
+                key(KeyCode::Enter),
+                key(KeyCode::Char('c')),
+            ],
+        ];
+        let exports = Arc::new(Mutex::new(Vec::new()));
+        let table = {
+            let exports = exports.clone();
+            move || {
+                let exports = exports.clone();
+                element! {
+                    DataTable(
+                        width: 40,
+                        rows: users(),
+                        has_focus: true,
+                        selected_row_style: TableRowStyle::default(),
+                        on_export: move |export| exports.lock().unwrap().push(export),
+                    )
+                }
+                .into_any()
+            }
+        };
+        let canvases: Vec<_> = render_with_event_batches(table, batches)
+            .await
+            .iter()
+            .map(|c| c.to_string())
+            .collect();
+        assert_eq!(
+            canvases.last().unwrap(),
+            indoc::indoc! {"
+                Filter: li
+                 name     age ▼  email
+                ─────────────────────────────────────
+                 Charlie     35  charlie@example.com
+                 Alice        7  alice@example.com
+            "}
+        );
+        assert_eq!(
+            *exports.lock().unwrap(),
+            vec![DataExport {
+                format: DataExportFormat::Csv,
+                content:
+                    "name,age,email\nCharlie,35,charlie@example.com\nAlice,7,alice@example.com\n"
+                        .to_string(),
+            }]
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_data_table_selection() {
+        let batches = vec![
+            // Select Alice, then sort by name, which moves her to the top, and select the next row.
+            vec![key(KeyCode::Down)],
+            vec![key(KeyCode::Right)],
+            vec![key(KeyCode::Down)],
+            // Filtering out the selected row selects the first visible row instead.
+            vec![
+                key(KeyCode::Char('/')),
+                key(KeyCode::Char('c')),
+                key(KeyCode::Enter),
+            ],
+        ];
+        let selections = Arc::new(Mutex::new(Vec::new()));
+        let table = {
+            let selections = selections.clone();
+            move || {
+                let selections = selections.clone();
+                element! {
+                    DataTable(
+                        width: 40,
+                        rows: users(),
+                        has_focus: true,
+                        selected_row_style: TableRowStyle::default(),
+                        on_select: move |i| selections.lock().unwrap().push(i),
+                    )
+                }
+                .into_any()
+            }
+        };
+        let canvas = render_with_event_batches(table, batches)
+            .await
+            .pop()
+            .unwrap();
+        assert_eq!(*selections.lock().unwrap(), vec![1, 2, 1]);
+        assert_eq!(
+            canvas.to_string(),
+            indoc::indoc! {"
+                Filter: c
+                 name ▲   age  email
+                ───────────────────────────────────
+                 Alice      7  alice@example.com
+                 Charlie   35  charlie@example.com
+            "}
+        );
+    }
+}
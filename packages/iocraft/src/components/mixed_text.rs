@@ -3,6 +3,7 @@ use crate::{
     segmented_string::SegmentedString,
     CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks, Props, Weight,
 };
+use std::sync::Arc;
 
 /// A section of text in a [`MixedText`] component.
 #[non_exhaustive]
@@ -20,8 +21,29 @@ pub struct MixedTextContent {
     /// The text decoration.
     pub decoration: TextDecoration,
 
+    /// The color of the underline, if the text is underlined. If `None`, the text color is used.
+    pub underline_color: Option<Color>,
+
     /// Whether to italicize the text.
     pub italic: bool,
+
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Whether to draw a line above the text.
+    pub overline: bool,
+
+    /// Whether to swap the foreground and background colors of the text.
+    pub reverse: bool,
+
+    /// Whether to make the text blink.
+    pub blink: bool,
+
+    /// Whether to hide the text.
+    pub hidden: bool,
+
+    /// The URL that the text links to, if any.
+    pub link: Option<Arc<str>>,
 }
 
 impl MixedTextContent {
@@ -51,11 +73,53 @@ impl MixedTextContent {
         self
     }
 
+    /// Returns a new [`MixedTextContent`] with the given underline color.
+    pub fn underline_color(mut self, color: Color) -> Self {
+        self.underline_color = Some(color);
+        self
+    }
+
     /// Returns a new [`MixedTextContent`] with italic text.
     pub fn italic(mut self) -> Self {
         self.italic = true;
         self
     }
+
+    /// Returns a new [`MixedTextContent`] with a line through the text.
+    pub fn strikethrough(mut self) -> Self {
+        self.strikethrough = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with a line above the text.
+    pub fn overline(mut self) -> Self {
+        self.overline = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with the foreground and background colors swapped.
+    pub fn reverse(mut self) -> Self {
+        self.reverse = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with blinking text.
+    pub fn blink(mut self) -> Self {
+        self.blink = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] with hidden text.
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self
+    }
+
+    /// Returns a new [`MixedTextContent`] which links to the given URL.
+    pub fn link<S: ToString>(mut self, url: S) -> Self {
+        self.link = Some(url.to_string().into());
+        self
+    }
 }
 
 /// The props which can be passed to the [`MixedText`] component.
@@ -153,12 +217,20 @@ impl Component for MixedText {
             let mut segments = line.segments.into_iter().peekable();
             while let Some(segment) = segments.next() {
                 let content = &self.contents[segment.index];
-                let style = CanvasTextStyle {
+                let mut style = CanvasTextStyle {
                     color: content.color,
                     weight: content.weight,
-                    underline: content.decoration == TextDecoration::Underline,
+                    underline_color: content.underline_color,
                     italic: content.italic,
+                    strikethrough: content.strikethrough,
+                    overline: content.overline,
+                    reverse: content.reverse,
+                    blink: content.blink,
+                    hidden: content.hidden,
+                    link: content.link.clone(),
+                    ..Default::default()
                 };
+                content.decoration.apply(&mut style);
                 if segments.peek().is_some() {
                     drawer.append_lines([segment.text], style);
                 } else {
@@ -190,4 +262,22 @@ mod tests {
             "this is a\nwrapping test\n"
         );
     }
+
+    #[test]
+    fn test_mixed_text_attributes() {
+        let mut actual = Vec::new();
+        element! {
+            MixedText(contents: vec![
+                MixedTextContent::new("a").decoration(TextDecoration::DoubleUnderline).strikethrough().overline(),
+                MixedTextContent::new("b").reverse().blink(),
+            ])
+        }
+        .render(None)
+        .write_ansi(&mut actual)
+        .unwrap();
+        assert_eq!(
+            String::from_utf8(actual).unwrap(),
+            "\x1b[0m\x1b[4:2m\x1b[9m\x1b[53ma\x1b[0m\x1b[7m\x1b[5m\x1b[Kb\x1b[0m\r\n"
+        );
+    }
 }
@@ -1,15 +1,47 @@
 mod button;
 pub use button::*;
 
+mod checkbox;
+pub use checkbox::*;
+
 mod context_provider;
 pub use context_provider::*;
 
+#[cfg(feature = "serde")]
+mod data_table;
+#[cfg(feature = "serde")]
+pub use data_table::*;
+
 mod fragment;
 pub use fragment::*;
 
 mod mixed_text;
 pub use mixed_text::*;
 
+mod progress_bar;
+pub use progress_bar::*;
+
+mod radio_group;
+pub use radio_group::*;
+
+mod scroll_view;
+pub use scroll_view::*;
+
+mod select_list;
+pub use select_list::*;
+
+mod spinner;
+pub use spinner::*;
+
+mod static_output;
+pub use static_output::*;
+
+mod switch;
+pub use switch::*;
+
+mod table;
+pub use table::*;
+
 mod text;
 pub use text::*;
 
@@ -18,3 +50,6 @@ pub use text_input::*;
 
 mod view;
 pub use view::*;
+
+mod virtual_list;
+pub use virtual_list::*;
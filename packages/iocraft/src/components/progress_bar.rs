@@ -0,0 +1,477 @@
+use crate::{
+    hooks::UseTicker, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks,
+    Props,
+};
+use iocraft_macros::with_layout_style_props;
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+use taffy::{AvailableSpace, Size};
+use unicode_width::UnicodeWidthStr;
+
+/// The partially filled blocks used to draw the leading edge of the bar, in eighths of a cell.
+const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// The width of the bar when it's told to fill the available space but there's no limit on it.
+const DEFAULT_BAR_WIDTH: usize = 40;
+
+/// The default period over which the rate is measured.
+const DEFAULT_RATE_WINDOW: Duration = Duration::from_secs(5);
+
+/// The props which can be passed to the [`ProgressBar`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct ProgressBarProps {
+    /// The amount of progress made, e.g. the number of items processed or bytes downloaded.
+    pub value: u64,
+
+    /// The total amount of work. If `None`, the bar is indeterminate and displays an animation
+    /// instead of the amount of progress made.
+    pub total: Option<u64>,
+
+    /// The layout of the bar and its labels. If empty, defaults to `"{bar} {percent}"`.
+    ///
+    /// The following placeholders are replaced, and all other text is displayed as is:
+    ///
+    /// - `{bar}`: The bar itself.
+    /// - `{percent}`: The percentage of progress made, e.g. ` 42%`.
+    /// - `{value}`: The value.
+    /// - `{total}`: The total.
+    /// - `{elapsed}`: The time since the bar was first rendered, e.g. `1:05`.
+    /// - `{eta}`: The estimated time remaining, based on the current rate.
+    /// - `{rate}`: The rate of progress per second, with an SI prefix and the [unit](Self::unit),
+    ///   e.g. `1.5 kB/s`.
+    ///
+    /// Labels which aren't known, such as the total of an indeterminate bar, are displayed as `?`.
+    pub template: String,
+
+    /// The width of the bar. If `None`, the bar fills the space not taken up by the labels.
+    pub bar_width: Option<u16>,
+
+    /// The color of the filled portion of the bar. Defaults to green.
+    pub color: Option<Color>,
+
+    /// The color of the unfilled portion of the bar. Defaults to dark grey.
+    pub track_color: Option<Color>,
+
+    /// The color of the labels.
+    pub label_color: Option<Color>,
+
+    /// The unit displayed in the rate, e.g. `"B"` for `"1.5 kB/s"`.
+    pub unit: String,
+
+    /// The period over which the rate and ETA are measured. Defaults to 5 seconds.
+    pub rate_window: Option<Duration>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum TemplateSegment {
+    Text(String),
+    Bar,
+}
+
+/// Tracks the value over time to estimate the rate of progress.
+#[derive(Default)]
+struct ProgressStats {
+    start: Option<Instant>,
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl ProgressStats {
+    fn record(&mut self, now: Instant, value: u64, window: Duration) {
+        self.start.get_or_insert(now);
+        if self.samples.back().map(|&(_, v)| v) != Some(value) {
+            // A value lower than before means the progress was reset.
+            if self.samples.back().is_some_and(|&(_, v)| v > value) {
+                self.samples.clear();
+            }
+            self.samples.push_back((now, value));
+        }
+        // Keep one sample from before the window so the rate covers the whole window.
+        while self.samples.len() > 2 && now.duration_since(self.samples[1].0) > window {
+            self.samples.pop_front();
+        }
+    }
+
+    fn elapsed(&self, now: Instant) -> Duration {
+        self.start
+            .map_or(Duration::ZERO, |start| now.duration_since(start))
+    }
+
+    /// Returns the rate of progress per second, if enough samples have been recorded.
+    fn rate(&self, now: Instant) -> Option<f64> {
+        let (first, last) = (self.samples.front()?, self.samples.back()?);
+        let seconds = now.duration_since(first.0).as_secs_f64();
+        (seconds > 0.0 && last.1 > first.1).then(|| (last.1 - first.1) as f64 / seconds)
+    }
+
+    fn eta(&self, now: Instant, total: u64) -> Option<Duration> {
+        let value = self.samples.back()?.1;
+        let remaining = total.saturating_sub(value);
+        if remaining == 0 {
+            return Some(Duration::ZERO);
+        }
+        self.rate(now)
+            .map(|rate| Duration::from_secs_f64(remaining as f64 / rate))
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    } else {
+        format!("{}:{:02}", seconds / 60, seconds % 60)
+    }
+}
+
+/// Formats a rate with an SI prefix, e.g. `"1.5 kB/s"`.
+fn format_rate(rate: f64, unit: &str) -> String {
+    let mut rate = rate;
+    let mut prefix = "";
+    for next in ["k", "M", "G", "T"] {
+        if rate < 1000.0 {
+            break;
+        }
+        rate /= 1000.0;
+        prefix = next;
+    }
+    match (prefix, unit) {
+        ("", "") => format!("{:.1}/s", rate),
+        _ => format!("{:.1} {}{}/s", rate, prefix, unit),
+    }
+}
+
+/// Returns the filled portion of a determinate bar. The rest of the bar is left to the track.
+///
+/// Partial cells are rounded down, so the bar is only full once the work is complete.
+fn determinate_bar(fraction: f64, width: usize) -> String {
+    let eighths = (fraction.clamp(0.0, 1.0) * (width * 8) as f64).floor() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if let partial @ 1.. = eighths % 8 {
+        bar.push(EIGHTHS[partial]);
+    }
+    bar
+}
+
+/// Returns the offset and width of the segment which bounces back and forth across an
+/// indeterminate bar.
+fn indeterminate_segment(tick: u64, width: usize) -> (usize, usize) {
+    let segment = (width / 5).max(1).min(width);
+    let range = (width - segment) as u64;
+    let offset = match range {
+        0 => 0,
+        _ => {
+            let phase = tick % (range * 2);
+            (if phase > range {
+                range * 2 - phase
+            } else {
+                phase
+            }) as usize
+        }
+    };
+    (offset, segment)
+}
+
+/// `ProgressBar` is a component which displays the progress of an operation.
+///
+/// The bar is drawn with sub-cell precision, and can be accompanied by labels such as the
+/// percentage complete, the elapsed time, and the estimated time remaining. See
+/// [`template`](ProgressBarProps::template) for how to arrange them.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo(downloaded: u64, size: u64) -> impl Into<AnyElement<'static>> {
+/// element! {
+///     ProgressBar(
+///         value: downloaded,
+///         total: size,
+///         template: "{bar} {percent} {rate} ETA {eta}",
+///         unit: "B",
+///         width: 60,
+///     )
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ProgressBar {
+    segments: Vec<TemplateSegment>,
+    fraction: Option<f64>,
+    tick: u64,
+    bar_width: Option<usize>,
+    bar_style: CanvasTextStyle,
+    track_color: Option<Color>,
+    label_style: CanvasTextStyle,
+    stats: ProgressStats,
+}
+
+impl ProgressBar {
+    fn labels_width(&self) -> usize {
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                TemplateSegment::Text(text) => text.width(),
+                TemplateSegment::Bar => 0,
+            })
+            .sum()
+    }
+}
+
+impl Component for ProgressBar {
+    type Props<'a> = ProgressBarProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Default::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let template = match props.template.as_str() {
+            "" => "{bar} {percent}",
+            template => template,
+        };
+        let is_timed = ["{elapsed}", "{eta}", "{rate}"]
+            .iter()
+            .any(|placeholder| template.contains(placeholder));
+
+        // Re-render periodically to animate an indeterminate bar and keep the timings current.
+        self.tick = hooks.use_ticker(match props.total {
+            None => Duration::from_millis(80),
+            Some(_) if is_timed => Duration::from_secs(1),
+            Some(_) => Duration::from_secs(3600),
+        });
+
+        let now = Instant::now();
+        let stats = &mut self.stats;
+        stats.record(
+            now,
+            props.value,
+            props.rate_window.unwrap_or(DEFAULT_RATE_WINDOW),
+        );
+
+        self.fraction = props.total.map(|total| match total {
+            0 => 1.0,
+            _ => props.value as f64 / total as f64,
+        });
+
+        let mut segments = Vec::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let Some(len) = rest[start..].find('}') else {
+                break;
+            };
+            let placeholder = &rest[start + 1..start + len];
+            let value = match placeholder {
+                "bar" => None,
+                "percent" => Some(match self.fraction {
+                    Some(fraction) => format!("{:>3.0}%", (fraction * 100.0).floor()),
+                    None => "?".to_string(),
+                }),
+                "value" => Some(props.value.to_string()),
+                "total" => Some(
+                    props
+                        .total
+                        .map_or("?".to_string(), |total| total.to_string()),
+                ),
+                "elapsed" => Some(format_duration(stats.elapsed(now))),
+                "eta" => Some(
+                    props
+                        .total
+                        .and_then(|total| stats.eta(now, total))
+                        .map_or("?".to_string(), format_duration),
+                ),
+                "rate" => Some(
+                    stats
+                        .rate(now)
+                        .map_or("?".to_string(), |rate| format_rate(rate, &props.unit)),
+                ),
+                _ => Some(rest[start..start + len + 1].to_string()),
+            };
+            segments.push(TemplateSegment::Text(rest[..start].to_string()));
+            segments.push(match value {
+                Some(value) => TemplateSegment::Text(value),
+                None => TemplateSegment::Bar,
+            });
+            rest = &rest[start + len + 1..];
+        }
+        segments.push(TemplateSegment::Text(rest.to_string()));
+        segments.retain(|segment| *segment != TemplateSegment::Text(String::new()));
+        self.segments = segments;
+
+        self.bar_width = props.bar_width.map(|width| width as _);
+        self.bar_style = CanvasTextStyle {
+            color: Some(props.color.unwrap_or(Color::Green)),
+            ..Default::default()
+        };
+        self.track_color = Some(props.track_color.unwrap_or(Color::DarkGrey));
+        self.label_style = CanvasTextStyle {
+            color: props.label_color,
+            ..Default::default()
+        };
+
+        updater.set_layout_style_props(props.layout_style());
+        updater.set_measure_func({
+            let labels_width = self.labels_width();
+            let has_bar = self.segments.contains(&TemplateSegment::Bar);
+            let bar_width = self.bar_width;
+            Box::new(move |known_size, available_space, _| {
+                let width = match (known_size.width, bar_width) {
+                    (Some(width), _) => width,
+                    _ if !has_bar => labels_width as f32,
+                    (None, Some(bar_width)) => (labels_width + bar_width) as f32,
+                    (None, None) => match available_space.width {
+                        AvailableSpace::Definite(width) => width,
+                        AvailableSpace::MinContent => (labels_width + 1) as f32,
+                        AvailableSpace::MaxContent => (labels_width + DEFAULT_BAR_WIDTH) as f32,
+                    },
+                };
+                Size { width, height: 1.0 }
+            })
+        });
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        let layout = drawer.layout();
+        let content_width = layout.content_box_width() as usize;
+        let bar_width = self
+            .bar_width
+            .unwrap_or_else(|| content_width.saturating_sub(self.labels_width()));
+        let y = (layout.border.top + layout.padding.top) as isize;
+        let mut x = (layout.border.left + layout.padding.left) as isize;
+        let mut canvas = drawer.canvas();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Text(text) => {
+                    canvas.set_text(x, y, text, self.label_style.clone());
+                    x += text.width() as isize;
+                }
+                TemplateSegment::Bar => {
+                    if let Some(track_color) = self.track_color {
+                        canvas.set_background_color(x, y, bar_width, 1, track_color);
+                    }
+                    let (offset, bar) = match self.fraction {
+                        Some(fraction) => (0, determinate_bar(fraction, bar_width)),
+                        None => {
+                            let (offset, len) = indeterminate_segment(self.tick, bar_width);
+                            (offset, "█".repeat(len))
+                        }
+                    };
+                    canvas.set_text(x + offset as isize, y, &bar, self.bar_style.clone());
+                    x += bar_width as isize;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_bars() {
+        assert_eq!(determinate_bar(0.0, 4), "");
+        assert_eq!(determinate_bar(0.5, 4), "██");
+        assert_eq!(determinate_bar(1.0 / 32.0, 4), "▏");
+        assert_eq!(determinate_bar(0.3, 4), "█▏");
+        assert_eq!(determinate_bar(0.99, 4), "███▉");
+        assert_eq!(determinate_bar(2.0, 4), "████");
+
+        assert_eq!(indeterminate_segment(0, 10), (0, 2));
+        assert_eq!(indeterminate_segment(3, 10), (3, 2));
+        assert_eq!(indeterminate_segment(8, 10), (8, 2));
+        assert_eq!(indeterminate_segment(10, 10), (6, 2));
+        assert_eq!(indeterminate_segment(16, 10), (0, 2));
+        assert_eq!(indeterminate_segment(5, 1), (0, 1));
+        assert_eq!(indeterminate_segment(5, 0), (0, 0));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_secs(0)), "0:00");
+        assert_eq!(format_duration(Duration::from_secs(65)), "1:05");
+        assert_eq!(format_duration(Duration::from_secs(3723)), "1:02:03");
+    }
+
+    #[test]
+    fn test_format_rate() {
+        assert_eq!(format_rate(12.34, ""), "12.3/s");
+        assert_eq!(format_rate(512.0, "B"), "512.0 B/s");
+        assert_eq!(format_rate(1500.0, ""), "1.5 k/s");
+        assert_eq!(format_rate(2_500_000.0, "B"), "2.5 MB/s");
+    }
+
+    #[test]
+    fn test_progress_stats() {
+        let window = Duration::from_secs(5);
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        let mut stats = ProgressStats::default();
+
+        stats.record(at(0), 0, window);
+        assert_eq!(stats.rate(at(0)), None);
+        assert_eq!(stats.eta(at(0), 100), None);
+
+        stats.record(at(2), 20, window);
+        assert_eq!(stats.rate(at(2)), Some(10.0));
+        assert_eq!(stats.eta(at(2), 100), Some(Duration::from_secs(8)));
+        assert_eq!(stats.elapsed(at(2)), Duration::from_secs(2));
+
+        // Old samples fall out of the window, so the rate reflects recent progress.
+        for secs in 3..=10 {
+            stats.record(at(secs), 20 + (secs - 2) * 2, window);
+        }
+        assert_eq!(stats.rate(at(10)), Some(2.0));
+        assert_eq!(stats.eta(at(10), 100), Some(Duration::from_secs(32)));
+
+        // Stalled progress lowers the rate.
+        assert_eq!(stats.rate(at(14)), Some(1.2));
+
+        stats.record(at(11), 100, window);
+        assert_eq!(stats.eta(at(11), 100), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(
+            element!(ProgressBar(value: 50u64, total: 100u64, bar_width: 10u16)).to_string(),
+            "█████       50%\n"
+        );
+        assert_eq!(
+            element! {
+                View(width: 30) {
+                    ProgressBar(
+                        value: 3u64,
+                        total: 8u64,
+                        template: "[{bar}] {value}/{total} {percent} {eta} {unknown}",
+                    )
+                }
+            }
+            .to_string(),
+            "[██▋    ] 3/8  37% ? {unknown}\n"
+        );
+        assert_eq!(
+            element!(ProgressBar(value: 3u64, template: "{value}/{total} {rate}", unit: "B"))
+                .to_string(),
+            "3/? ?\n"
+        );
+        assert_eq!(
+            element!(ProgressBar(bar_width: 10u16, template: "{bar} {percent}")).to_string(),
+            "██         ?\n"
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    component,
+    components::{Button, Text, ToggleGlyphs, View},
+    element,
+    hooks::{UseState, UseTerminalEvents},
+    AnyElement, Color, FlexDirection, Handler, Hooks, KeyCode, KeyEvent, KeyEventKind, Props,
+    TerminalEvent,
+};
+use std::sync::{Arc, Mutex};
+
+/// The props which can be passed to the [`RadioGroup`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct RadioGroupProps {
+    /// The options to choose from.
+    pub options: Vec<String>,
+
+    /// The index of the chosen option, if any.
+    pub value: Option<usize>,
+
+    /// The handler to invoke when the user chooses an option. It's given the option's index,
+    /// which the parent should pass back via [`value`](Self::value).
+    ///
+    /// An option can be chosen two ways:
+    ///
+    /// - By clicking on it with the mouse while in fullscreen mode.
+    /// - By pressing the Enter or Space key while it's focused and [`has_focus`](Self::has_focus)
+    ///   is `true`.
+    pub on_change: Handler<'static, usize>,
+
+    /// True if the group has focus and should process keyboard input.
+    ///
+    /// The Up and Down keys, or Left and Right keys if [`horizontal`](Self::horizontal) is `true`,
+    /// move focus between the options. Focus starts on the chosen option.
+    pub has_focus: bool,
+
+    /// If true, the options are laid out in a row instead of a column.
+    pub horizontal: bool,
+
+    /// The glyphs to display. Defaults to [`ToggleGlyphs::radio`].
+    pub glyphs: Option<ToggleGlyphs>,
+
+    /// The color of the options.
+    pub color: Option<Color>,
+
+    /// The color of the focused option while the group has focus. Defaults to blue.
+    pub focus_color: Option<Color>,
+}
+
+/// `RadioGroup` is a component which displays a set of options, of which the user can choose one.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Settings(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut size = hooks.use_state(|| Some(1));
+///
+///     element! {
+///         RadioGroup(
+///             options: vec!["Small".to_string(), "Medium".to_string(), "Large".to_string()],
+///             value: size.get(),
+///             on_change: move |i| size.set(Some(i)),
+///             has_focus: true,
+///         )
+///     }
+/// }
+/// ```
+#[component]
+pub fn RadioGroup(mut hooks: Hooks, props: &mut RadioGroupProps) -> impl Into<AnyElement<'static>> {
+    let option_count = props.options.len();
+    let mut focused = hooks.use_state(|| props.value.unwrap_or(0));
+    let focused_index = focused.get().min(option_count.saturating_sub(1));
+    let on_change = Arc::new(Mutex::new(props.on_change.take()));
+
+    hooks.use_local_terminal_events({
+        let on_change = on_change.clone();
+        let has_focus = props.has_focus;
+        let horizontal = props.horizontal;
+        let mut focused_index = focused_index;
+        move |event| match event {
+            TerminalEvent::Key(KeyEvent { code, kind, .. })
+                if has_focus && kind != KeyEventKind::Release && option_count > 0 =>
+            {
+                let (previous, next) = if horizontal {
+                    (KeyCode::Left, KeyCode::Right)
+                } else {
+                    (KeyCode::Up, KeyCode::Down)
+                };
+                match code {
+                    _ if code == previous => {
+                        focused_index = focused_index.saturating_sub(1);
+                        focused.set(focused_index);
+                    }
+                    _ if code == next => {
+                        focused_index = (focused_index + 1).min(option_count - 1);
+                        focused.set(focused_index);
+                    }
+                    KeyCode::Enter | KeyCode::Char(' ') => {
+                        (on_change.lock().unwrap())(focused_index);
+                    }
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+    });
+
+    let glyphs = props.glyphs.clone().unwrap_or_else(ToggleGlyphs::radio);
+    let focus_color = props.focus_color.unwrap_or(Color::Blue);
+
+    element! {
+        View(
+            flex_direction: if props.horizontal { FlexDirection::Row } else { FlexDirection::Column },
+            column_gap: if props.horizontal { 2 } else { 0 },
+        ) {
+            #(props.options.iter().enumerate().map(|(i, option)| {
+                let on_change = on_change.clone();
+                element! {
+                    Button(handler: move |_| {
+                        focused.set(i);
+                        (on_change.lock().unwrap())(i);
+                    }) {
+                        Text(
+                            content: glyphs.text(props.value == Some(i), option),
+                            color: if props.has_focus && i == focused_index {
+                                Some(focus_color)
+                            } else {
+                                props.color
+                            },
+                        )
+                    }
+                }
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_events},
+    };
+    use crossterm::event::MouseButton;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn options() -> Vec<String> {
+        vec![
+            "Small".to_string(),
+            "Medium".to_string(),
+            "Large".to_string(),
+        ]
+    }
+
+    #[test]
+    fn test_radio_group() {
+        assert_eq!(
+            element!(RadioGroup(options: options(), value: 1usize)).to_string(),
+            "◯ Small\n◉ Medium\n◯ Large\n"
+        );
+        assert_eq!(
+            element!(RadioGroup(
+                options: options(),
+                horizontal: true,
+                glyphs: ToggleGlyphs::radio_ascii(),
+            ))
+            .to_string(),
+            "( ) Small  ( ) Medium  ( ) Large\n"
+        );
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut value = hooks.use_state(|| None);
+
+        element! {
+            RadioGroup(
+                options: options(),
+                value: value.get(),
+                on_change: move |i| value.set(Some(i)),
+                has_focus: true,
+            )
+        }
+    }
+
+    async fn render_last(events: Vec<TerminalEvent>) -> String {
+        render_with_events(|| element!(MyComponent).into_any(), events)
+            .await
+            .pop()
+            .unwrap()
+            .to_string()
+    }
+
+    #[apply(test!)]
+    async fn test_radio_group_input() {
+        assert_eq!(
+            render_last(vec![
+                key(KeyCode::Down),
+                key(KeyCode::Down),
+                key(KeyCode::Down),
+                key(KeyCode::Char(' ')),
+            ])
+            .await,
+            "◯ Small\n◯ Medium\n◉ Large\n"
+        );
+        assert_eq!(
+            render_last(vec![TerminalEvent::FullscreenMouse(
+                FullscreenMouseEvent::new(MouseEventKind::Down(MouseButton::Left), 3, 1)
+            )])
+            .await,
+            "◯ Small\n◉ Medium\n◯ Large\n"
+        );
+    }
+}
@@ -0,0 +1,565 @@
+use crate::{
+    components::View,
+    element,
+    hooks::{UseState, UseTerminalEvents},
+    AlignSelf, AnyElement, CanvasSubviewMut, CanvasTextStyle, Color, Component, ComponentDrawer,
+    ComponentUpdater, FlexDirection, FullscreenMouseEvent, Handler, Hooks, KeyCode, KeyEvent,
+    KeyEventKind, MouseEventKind, Percent, Props, Size, TerminalEvent,
+};
+use iocraft_macros::with_layout_style_props;
+use std::sync::{Arc, Mutex};
+use taffy::{Overflow, Point};
+
+/// The position that the content of a [`ScrollView`] is scrolled to.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ScrollOffset {
+    /// The number of columns that the content is scrolled to the left by.
+    pub x: u16,
+    /// The number of rows that the content is scrolled up by.
+    pub y: u16,
+}
+
+/// The props which can be passed to the [`ScrollView`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct ScrollViewProps<'a> {
+    /// The children of the component. They're laid out in a column.
+    pub children: Vec<AnyElement<'a>>,
+
+    /// If true, the content can also be scrolled horizontally, and is laid out at its preferred
+    /// width instead of being fit to the width of the view.
+    pub horizontal: bool,
+
+    /// The scroll offset. If `Some`, the offset is controlled by the parent, which should update it
+    /// in response to [`on_scroll`](Self::on_scroll). If `None`, the view keeps track of the offset
+    /// itself.
+    ///
+    /// The offset is clamped to the size of the content, so `u16::MAX` can be used to scroll to
+    /// the end.
+    pub scroll_offset: Option<ScrollOffset>,
+
+    /// The handler to invoke when the user scrolls the view.
+    pub on_scroll: Handler<'static, ScrollOffset>,
+
+    /// If true, the view starts out scrolled to the bottom and stays there as content is added,
+    /// until the user scrolls up. Scrolling back to the bottom resumes following the content. This
+    /// is useful for things like log tails.
+    ///
+    /// While following the content, the vertical offset passed to
+    /// [`on_scroll`](Self::on_scroll) is `u16::MAX`.
+    pub stick_to_bottom: bool,
+
+    /// True if the view has focus and should process keyboard input.
+    ///
+    /// The arrow keys scroll by a single row or column, Page Up and Page Down scroll by a page, and
+    /// Home and End scroll to the top and bottom. Regardless of focus, the view can be scrolled via
+    /// the mouse wheel while in fullscreen mode.
+    pub has_focus: bool,
+
+    /// If true, scrollbars are drawn along the right edge, and the bottom edge if the view is
+    /// [`horizontal`](Self::horizontal).
+    pub show_scrollbars: bool,
+
+    /// The color of the scrollbar tracks. Defaults to dark gray.
+    pub scrollbar_color: Option<Color>,
+
+    /// The color of the scrollbar thumbs. If `None`, the default text color is used.
+    pub scrollbar_thumb_color: Option<Color>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct ScrollMetrics {
+    offset: ScrollOffset,
+    max_offset: ScrollOffset,
+    viewport_height: u16,
+}
+
+impl ScrollMetrics {
+    fn scrolled_by(&self, dx: i32, dy: i32) -> ScrollOffset {
+        ScrollOffset {
+            x: (self.offset.x as i32 + dx).clamp(0, self.max_offset.x as _) as _,
+            y: (self.offset.y as i32 + dy).clamp(0, self.max_offset.y as _) as _,
+        }
+    }
+}
+
+/// `ScrollView` is a component which displays its children in a region that can be scrolled
+/// vertically and optionally horizontally.
+///
+/// The view should be given a size, either directly or by its parent's layout. The content can be
+/// scrolled with the keyboard when the view has focus, and with the mouse wheel in fullscreen
+/// mode.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     View(border_style: BorderStyle::Round) {
+///         ScrollView(height: 10, has_focus: true, show_scrollbars: true) {
+///             #((0..100).map(|i| element! {
+///                 Text(content: format!("Line {}", i))
+///             }))
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ScrollView {
+    metrics: Arc<Mutex<ScrollMetrics>>,
+    show_scrollbars: bool,
+    scrollbar_style: ScrollbarStyle,
+}
+
+impl Component for ScrollView {
+    type Props<'a> = ScrollViewProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Default::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        self.show_scrollbars = props.show_scrollbars;
+        self.scrollbar_style =
+            ScrollbarStyle::new(props.scrollbar_color, props.scrollbar_thumb_color);
+
+        let stick_to_bottom = props.stick_to_bottom;
+        let mut offset = hooks.use_state(|| ScrollOffset {
+            x: 0,
+            y: if stick_to_bottom { u16::MAX } else { 0 },
+        });
+        let is_controlled = props.scroll_offset.is_some();
+
+        hooks.use_local_terminal_events({
+            let metrics = self.metrics.clone();
+            let mut on_scroll = props.on_scroll.take();
+            let has_focus = props.has_focus;
+            let horizontal = props.horizontal;
+            move |event| {
+                let mut metrics = metrics.lock().unwrap();
+                let page = metrics.viewport_height.max(1) as i32;
+                let mut new_offset = match event {
+                    TerminalEvent::Key(KeyEvent { code, kind, .. })
+                        if has_focus && kind != KeyEventKind::Release =>
+                    {
+                        match code {
+                            KeyCode::Up => metrics.scrolled_by(0, -1),
+                            KeyCode::Down => metrics.scrolled_by(0, 1),
+                            KeyCode::Left if horizontal => metrics.scrolled_by(-1, 0),
+                            KeyCode::Right if horizontal => metrics.scrolled_by(1, 0),
+                            KeyCode::PageUp => metrics.scrolled_by(0, -page),
+                            KeyCode::PageDown => metrics.scrolled_by(0, page),
+                            KeyCode::Home => ScrollOffset {
+                                y: 0,
+                                ..metrics.offset
+                            },
+                            KeyCode::End => ScrollOffset {
+                                y: metrics.max_offset.y,
+                                ..metrics.offset
+                            },
+                            _ => return,
+                        }
+                    }
+                    TerminalEvent::FullscreenMouse(FullscreenMouseEvent { kind, .. }) => match kind
+                    {
+                        MouseEventKind::ScrollUp => metrics.scrolled_by(0, -1),
+                        MouseEventKind::ScrollDown => metrics.scrolled_by(0, 1),
+                        MouseEventKind::ScrollLeft if horizontal => metrics.scrolled_by(-1, 0),
+                        MouseEventKind::ScrollRight if horizontal => metrics.scrolled_by(1, 0),
+                        _ => return,
+                    },
+                    _ => return,
+                };
+                if new_offset == metrics.offset {
+                    return;
+                }
+                // Subsequent events may be handled before the next draw, so they need to build on
+                // this one.
+                metrics.offset = new_offset;
+                if stick_to_bottom && new_offset.y >= metrics.max_offset.y {
+                    new_offset.y = u16::MAX;
+                }
+                if !is_controlled {
+                    offset.set(new_offset);
+                }
+                on_scroll(new_offset);
+            }
+        });
+
+        let offset = props.scroll_offset.unwrap_or_else(|| offset.get());
+        updater.set_scroll_offset(Point {
+            x: offset.x,
+            y: offset.y,
+        });
+
+        updater.set_layout_style_props_with(props.layout_style(), |style| {
+            style.flex_direction = FlexDirection::Column;
+            style.overflow = Point {
+                x: if props.horizontal {
+                    Overflow::Scroll
+                } else {
+                    Overflow::Hidden
+                },
+                y: Overflow::Scroll,
+            };
+            style.scrollbar_width = if props.show_scrollbars { 1.0 } else { 0.0 };
+        });
+
+        // The children are wrapped in a single view which is never shrunk, so the content keeps its
+        // natural height regardless of how much space the scroll view has.
+        let mut content = if props.horizontal {
+            element! {
+                View(
+                    flex_direction: FlexDirection::Column,
+                    flex_shrink: 0.0,
+                    align_self: AlignSelf::FlexStart,
+                    width: Size::MaxContent,
+                    min_width: Percent(100.0),
+                ) {
+                    #(props.children.iter_mut())
+                }
+            }
+        } else {
+            element! {
+                View(flex_direction: FlexDirection::Column, flex_shrink: 0.0) {
+                    #(props.children.iter_mut())
+                }
+            }
+        };
+        updater.update_children([&mut content], None);
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        let layout = drawer.layout();
+        let offset = drawer.scroll_offset();
+        let max_offset = drawer.max_scroll_offset();
+        let viewport_left = layout.border.left as u16;
+        let viewport_top = layout.border.top as u16;
+        let viewport_width = (layout.size.width
+            - layout.border.left
+            - layout.border.right
+            - layout.scrollbar_size.width) as u16;
+        let viewport_height = (layout.size.height
+            - layout.border.top
+            - layout.border.bottom
+            - layout.scrollbar_size.height) as u16;
+
+        *self.metrics.lock().unwrap() = ScrollMetrics {
+            offset: ScrollOffset {
+                x: offset.x,
+                y: offset.y,
+            },
+            max_offset: ScrollOffset {
+                x: max_offset.x,
+                y: max_offset.y,
+            },
+            viewport_height,
+        };
+
+        if !self.show_scrollbars {
+            return;
+        }
+
+        let mut canvas = drawer.canvas();
+
+        if layout.scrollbar_size.width > 0.0 {
+            self.scrollbar_style.draw_vertical(
+                &mut canvas,
+                (viewport_left + viewport_width) as _,
+                viewport_top as _,
+                viewport_height,
+                max_offset.y as _,
+                offset.y as _,
+            );
+        }
+
+        if layout.scrollbar_size.height > 0.0 {
+            self.scrollbar_style.draw_horizontal(
+                &mut canvas,
+                viewport_left as _,
+                (viewport_top + viewport_height) as _,
+                viewport_width,
+                max_offset.x as _,
+                offset.x as _,
+            );
+        }
+    }
+}
+
+/// The style of a scrollbar, shared by the scrollable components.
+#[derive(Default)]
+pub(crate) struct ScrollbarStyle {
+    track: CanvasTextStyle,
+    thumb: CanvasTextStyle,
+}
+
+impl ScrollbarStyle {
+    pub fn new(color: Option<Color>, thumb_color: Option<Color>) -> Self {
+        Self {
+            track: CanvasTextStyle {
+                color: Some(color.unwrap_or(Color::DarkGrey)),
+                ..Default::default()
+            },
+            thumb: CanvasTextStyle {
+                color: thumb_color,
+                ..Default::default()
+            },
+        }
+    }
+
+    /// Draws a vertical scrollbar of the given length, whose top is at the given position.
+    pub fn draw_vertical(
+        &self,
+        canvas: &mut CanvasSubviewMut,
+        x: isize,
+        y: isize,
+        len: u16,
+        max_offset: u32,
+        offset: u32,
+    ) {
+        let (thumb_start, thumb_len) = thumb(len, max_offset, offset);
+        for i in 0..len {
+            let (c, style) = if i >= thumb_start && i < thumb_start + thumb_len {
+                ("█", &self.thumb)
+            } else {
+                ("│", &self.track)
+            };
+            canvas.set_text(x, y + i as isize, c, style.clone());
+        }
+    }
+
+    /// Draws a horizontal scrollbar of the given length, whose left end is at the given position.
+    pub fn draw_horizontal(
+        &self,
+        canvas: &mut CanvasSubviewMut,
+        x: isize,
+        y: isize,
+        len: u16,
+        max_offset: u32,
+        offset: u32,
+    ) {
+        let (thumb_start, thumb_len) = thumb(len, max_offset, offset);
+        for i in 0..len {
+            let (c, style) = if i >= thumb_start && i < thumb_start + thumb_len {
+                ("█", &self.thumb)
+            } else {
+                ("─", &self.track)
+            };
+            canvas.set_text(x + i as isize, y, c, style.clone());
+        }
+    }
+}
+
+/// Returns the start and length of a scrollbar thumb, given the length of the track, which is also
+/// the length of the viewport.
+fn thumb(track_len: u16, max_offset: u32, offset: u32) -> (u16, u16) {
+    if track_len == 0 {
+        return (0, 0);
+    }
+    let track_len = track_len as u64;
+    let max_offset = max_offset as u64;
+    let offset = offset.min(max_offset as _) as u64;
+    let content_len = track_len + max_offset;
+    let len = ((track_len * track_len + content_len / 2) / content_len).clamp(1, track_len);
+    let start = ((track_len - len) * offset + max_offset / 2)
+        .checked_div(max_offset)
+        .unwrap_or(0);
+    (start as _, len as _)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_events},
+    };
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn lines(n: usize) -> Vec<AnyElement<'static>> {
+        (0..n)
+            .map(|i| element!(Text(content: format!("line {}", i))).into_any())
+            .collect()
+    }
+
+    #[test]
+    fn test_scroll_view() {
+        assert_eq!(
+            element! {
+                ScrollView(height: 3) {
+                    #(lines(5))
+                }
+            }
+            .to_string(),
+            "line 0\nline 1\nline 2\n"
+        );
+
+        assert_eq!(
+            element! {
+                ScrollView(height: 3, scroll_offset: ScrollOffset { x: 0, y: 1 }) {
+                    #(lines(5))
+                }
+            }
+            .to_string(),
+            "line 1\nline 2\nline 3\n"
+        );
+
+        // The offset is clamped to the content.
+        assert_eq!(
+            element! {
+                ScrollView(height: 3, scroll_offset: ScrollOffset { x: 0, y: 100 }) {
+                    #(lines(5))
+                }
+            }
+            .to_string(),
+            "line 2\nline 3\nline 4\n"
+        );
+
+        // Content that fits isn't scrolled.
+        assert_eq!(
+            element! {
+                ScrollView(height: 3, scroll_offset: ScrollOffset { x: 0, y: 100 }) {
+                    #(lines(2))
+                }
+            }
+            .to_string(),
+            "line 0\nline 1\n\n"
+        );
+
+        assert_eq!(
+            element! {
+                ScrollView(height: 3, stick_to_bottom: true) {
+                    #(lines(5))
+                }
+            }
+            .to_string(),
+            "line 2\nline 3\nline 4\n"
+        );
+
+        // Without horizontal scrolling, content is wrapped to the width of the view.
+        assert_eq!(
+            element! {
+                ScrollView(width: 5, height: 2) {
+                    Text(content: "foo bar")
+                }
+            }
+            .to_string(),
+            "foo\nbar\n"
+        );
+
+        assert_eq!(
+            element! {
+                ScrollView(
+                    width: 5,
+                    height: 2,
+                    horizontal: true,
+                    scroll_offset: ScrollOffset { x: 2, y: 0 },
+                ) {
+                    Text(content: "foo bar")
+                }
+            }
+            .to_string(),
+            "o bar\n\n"
+        );
+    }
+
+    #[test]
+    fn test_scroll_view_scrollbars() {
+        assert_eq!(
+            element! {
+                ScrollView(height: 4, show_scrollbars: true, scroll_offset: ScrollOffset { x: 0, y: 2 }) {
+                    #(lines(8))
+                }
+            }
+            .to_string(),
+            "line 2│\nline 3█\nline 4█\nline 5│\n"
+        );
+
+        assert_eq!(
+            element! {
+                ScrollView(width: 6, height: 3, horizontal: true, show_scrollbars: true) {
+                    Text(content: "abcdefghij")
+                    Text(content: "k")
+                    Text(content: "l")
+                }
+            }
+            .to_string(),
+            "abcde█\nk    │\n███──\n"
+        );
+    }
+
+    #[test]
+    fn test_thumb() {
+        assert_eq!(thumb(10, 0, 0), (0, 10));
+        assert_eq!(thumb(10, 10, 0), (0, 5));
+        assert_eq!(thumb(10, 10, 5), (3, 5));
+        assert_eq!(thumb(10, 10, 10), (5, 5));
+        assert_eq!(thumb(10, 1000, 1000), (9, 1));
+        assert_eq!(thumb(10, 1_000_000, 2_000_000), (9, 1));
+        assert_eq!(thumb(0, 10, 5), (0, 0));
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut count = hooks.use_state(|| 5);
+
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::Key(KeyEvent { code, .. }) = event {
+                if code == KeyCode::Char('a') {
+                    count += 1;
+                }
+            }
+        });
+
+        element! {
+            ScrollView(height: 3, has_focus: true, stick_to_bottom: true) {
+                #(lines(count.get()))
+            }
+        }
+    }
+
+    async fn render_with_key_presses(codes: Vec<KeyCode>) -> Vec<String> {
+        render_with_events(
+            || element!(MyComponent).into_any(),
+            codes.into_iter().map(key).collect(),
+        )
+        .await
+        .iter()
+        .map(|c| c.to_string())
+        .collect()
+    }
+
+    #[apply(test!)]
+    async fn test_scroll_view_keys() {
+        // New content is followed while scrolled to the bottom.
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Char('a')]).await,
+            vec!["line 2\nline 3\nline 4\n", "line 3\nline 4\nline 5\n"]
+        );
+
+        // Scrolling up stops following new content.
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Up, KeyCode::Char('a')]).await,
+            vec!["line 2\nline 3\nline 4\n", "line 1\nline 2\nline 3\n"]
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Home]).await,
+            vec!["line 2\nline 3\nline 4\n", "line 0\nline 1\nline 2\n"]
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::PageUp, KeyCode::Down]).await,
+            vec!["line 2\nline 3\nline 4\n", "line 1\nline 2\nline 3\n"]
+        );
+    }
+}
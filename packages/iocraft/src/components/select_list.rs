@@ -0,0 +1,640 @@
+use crate::{
+    hooks::{UseState, UseTerminalEvents},
+    CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, FullscreenMouseEvent,
+    Handler, Hooks, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEventKind, Props,
+    TerminalEvent, Weight,
+};
+use iocraft_macros::with_layout_style_props;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use taffy::Size;
+use unicode_width::UnicodeWidthStr;
+
+/// How long the user can pause between keystrokes while typing the start of an item's label.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// An item of a [`SelectList`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SelectItem {
+    /// The text to display for the item.
+    pub label: String,
+    /// If true, the item is displayed but can't be selected.
+    pub disabled: bool,
+    /// The group the item belongs to. A header with the group's name is displayed above each run
+    /// of consecutive items in the same group.
+    pub group: Option<String>,
+}
+
+impl SelectItem {
+    /// Creates a new item with the given label.
+    pub fn new<S: Into<String>>(label: S) -> Self {
+        Self {
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<&str> for SelectItem {
+    fn from(label: &str) -> Self {
+        Self::new(label)
+    }
+}
+
+impl From<String> for SelectItem {
+    fn from(label: String) -> Self {
+        Self::new(label)
+    }
+}
+
+/// The props which can be passed to the [`SelectList`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct SelectListProps {
+    /// The items to choose from.
+    pub items: Vec<SelectItem>,
+
+    /// The index of the highlighted item. If `Some`, the highlighted item is controlled by the
+    /// parent, which should update it in response to [`on_select`](Self::on_select). If `None`,
+    /// the list keeps track of it itself, starting with the first item that isn't disabled.
+    pub selected_index: Option<usize>,
+
+    /// The handler to invoke when the user highlights a different item.
+    pub on_select: Handler<'static, usize>,
+
+    /// The handler to invoke when the user presses Enter, or clicks an item in single-select mode.
+    /// It's given the index of the highlighted item.
+    pub on_submit: Handler<'static, usize>,
+
+    /// If true, each item has a checkbox which can be toggled with Space or by clicking on it.
+    pub multiple: bool,
+
+    /// The indices of the checked items in multi-select mode. If `Some`, the checked items are
+    /// controlled by the parent, which should update them in response to
+    /// [`on_check`](Self::on_check). If `None`, the list keeps track of them itself.
+    pub checked: Option<Vec<usize>>,
+
+    /// The handler to invoke when the user checks or unchecks an item in multi-select mode. It's
+    /// given the indices of all checked items, in ascending order.
+    pub on_check: Handler<'static, Vec<usize>>,
+
+    /// If true, moving past the last item highlights the first, and vice versa.
+    pub wrap_around: bool,
+
+    /// True if the list has focus and should process keyboard input.
+    ///
+    /// The Up and Down keys move the highlight, skipping disabled items, and Home and End jump to
+    /// the first and last items. Typing jumps to the next item whose label starts with the typed
+    /// text. Regardless of focus, items can be clicked with the mouse while in fullscreen mode.
+    pub has_focus: bool,
+
+    /// The color of the items.
+    pub color: Option<Color>,
+
+    /// The color of the highlighted item. Defaults to blue.
+    pub selected_color: Option<Color>,
+
+    /// The color of disabled items. Defaults to dark grey.
+    pub disabled_color: Option<Color>,
+
+    /// The color of group headers.
+    pub header_color: Option<Color>,
+}
+
+/// A line displayed by a [`SelectList`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SelectListLine {
+    Header(String),
+    Item(usize),
+}
+
+#[derive(Default)]
+struct TypeAhead {
+    query: String,
+    last_keystroke: Option<Instant>,
+}
+
+#[derive(Default)]
+struct SelectListMetrics {
+    content_top: u16,
+    type_ahead: TypeAhead,
+}
+
+/// Returns the index of the next item which can be selected, searching in the given direction
+/// from (but not including) `from`.
+fn next_enabled(items: &[SelectItem], from: usize, forward: bool, wrap: bool) -> Option<usize> {
+    let n = items.len();
+    (1..=n)
+        .map(|step| {
+            if forward {
+                from as isize + step as isize
+            } else {
+                from as isize - step as isize
+            }
+        })
+        .take_while(|&i| wrap || (0..n as isize).contains(&i))
+        .map(|i| i.rem_euclid(n as isize) as usize)
+        .find(|&i| !items[i].disabled)
+}
+
+/// Returns the first item which can be selected at or after `from`, wrapping around, whose label
+/// starts with the query, ignoring case.
+fn find_by_prefix(items: &[SelectItem], from: usize, query: &str) -> Option<usize> {
+    let query = query.to_lowercase();
+    (0..items.len())
+        .map(|step| (from + step) % items.len())
+        .find(|&i| !items[i].disabled && items[i].label.to_lowercase().starts_with(&query))
+}
+
+/// `SelectList` is a component which lets the user choose one or more of a list of items.
+///
+/// Items can be disabled, and grouped under headers. In multi-select mode, each item has a
+/// checkbox which is toggled via the Space key.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn FlavorPicker(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut system = hooks.use_context_mut::<SystemContext>();
+///     let mut flavor = hooks.use_state(|| None);
+///
+///     if flavor.get().is_some() {
+///         system.exit();
+///     }
+///
+///     element! {
+///         SelectList(
+///             items: vec![
+///                 SelectItem::new("Vanilla"),
+///                 SelectItem::new("Chocolate"),
+///                 SelectItem { disabled: true, ..SelectItem::new("Strawberry (sold out)") },
+///             ],
+///             has_focus: true,
+///             wrap_around: true,
+///             on_submit: move |i| flavor.set(Some(i)),
+///         )
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct SelectList {
+    lines: Vec<SelectListLine>,
+    items: Vec<SelectItem>,
+    selected_index: Option<usize>,
+    checked: Option<Vec<usize>>,
+    item_style: CanvasTextStyle,
+    selected_style: CanvasTextStyle,
+    disabled_style: CanvasTextStyle,
+    header_style: CanvasTextStyle,
+    metrics: Arc<Mutex<SelectListMetrics>>,
+}
+
+impl SelectList {
+    fn prefix(&self, index: usize) -> String {
+        let cursor = if self.selected_index == Some(index) {
+            '❯'
+        } else {
+            ' '
+        };
+        match &self.checked {
+            Some(checked) => format!(
+                "{} {} ",
+                cursor,
+                if checked.contains(&index) {
+                    '◉'
+                } else {
+                    '◯'
+                }
+            ),
+            None => format!("{} ", cursor),
+        }
+    }
+
+    fn line_text(&self, line: &SelectListLine) -> String {
+        match line {
+            SelectListLine::Header(header) => header.clone(),
+            SelectListLine::Item(index) => {
+                format!("{}{}", self.prefix(*index), self.items[*index].label)
+            }
+        }
+    }
+}
+
+impl Component for SelectList {
+    type Props<'a> = SelectListProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Default::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let items = props.items.clone();
+        let initial_index = items.iter().position(|item| !item.disabled).unwrap_or(0);
+        let mut selected_index_state = hooks.use_state(|| initial_index);
+        let mut checked_state = hooks.use_state(Vec::<usize>::new);
+        let is_selection_controlled = props.selected_index.is_some();
+        let is_checked_controlled = props.checked.is_some();
+        let unclamped_index = props
+            .selected_index
+            .unwrap_or_else(|| selected_index_state.get());
+        let selected_index = unclamped_index.min(items.len().saturating_sub(1));
+        let mut on_select = props.on_select.take();
+        if !items.is_empty() && selected_index != unclamped_index {
+            // The selected item was removed, so the last item is selected instead.
+            if !is_selection_controlled {
+                selected_index_state.set(selected_index);
+            }
+            on_select(selected_index);
+        }
+        let checked = props
+            .checked
+            .clone()
+            .unwrap_or_else(|| checked_state.read().clone());
+
+        self.lines.clear();
+        let mut group = None;
+        for (i, item) in items.iter().enumerate() {
+            if item.group.is_some() && item.group != group {
+                self.lines.push(SelectListLine::Header(
+                    item.group.clone().unwrap_or_default(),
+                ));
+            }
+            group = item.group.clone();
+            self.lines.push(SelectListLine::Item(i));
+        }
+
+        hooks.use_local_terminal_events({
+            let mut on_submit = props.on_submit.take();
+            let mut on_check = props.on_check.take();
+            let has_focus = props.has_focus;
+            let multiple = props.multiple;
+            let wrap = props.wrap_around;
+            let lines = self.lines.clone();
+            let metrics = self.metrics.clone();
+            let mut selected_index = selected_index;
+            let mut checked = checked.clone();
+            move |event| {
+                if items.is_empty() {
+                    return;
+                }
+                let mut metrics = metrics.lock().unwrap();
+                let mut toggle = false;
+                let mut submit = false;
+                let new_index = match event {
+                    TerminalEvent::Key(KeyEvent {
+                        code,
+                        kind,
+                        modifiers,
+                        ..
+                    }) if has_focus && kind != KeyEventKind::Release => match code {
+                        KeyCode::Up => next_enabled(&items, selected_index, false, wrap),
+                        KeyCode::Down => next_enabled(&items, selected_index, true, wrap),
+                        KeyCode::Home => next_enabled(&items, items.len() - 1, true, true),
+                        KeyCode::End => next_enabled(&items, 0, false, true),
+                        KeyCode::Enter => {
+                            submit = true;
+                            Some(selected_index)
+                        }
+                        KeyCode::Char(' ') if multiple => {
+                            toggle = true;
+                            Some(selected_index)
+                        }
+                        KeyCode::Char(c)
+                            if !modifiers.intersects(KeyModifiers::CONTROL | KeyModifiers::ALT) =>
+                        {
+                            let now = Instant::now();
+                            let type_ahead = &mut metrics.type_ahead;
+                            let is_continuation = type_ahead
+                                .last_keystroke
+                                .is_some_and(|t| now.duration_since(t) < TYPE_AHEAD_TIMEOUT);
+                            type_ahead.last_keystroke = Some(now);
+                            if is_continuation {
+                                type_ahead.query.push(c);
+                                find_by_prefix(&items, selected_index, &type_ahead.query)
+                            } else {
+                                type_ahead.query = c.to_string();
+                                find_by_prefix(&items, selected_index + 1, &type_ahead.query)
+                            }
+                        }
+                        _ => None,
+                    },
+                    TerminalEvent::FullscreenMouse(FullscreenMouseEvent {
+                        kind: MouseEventKind::Down(_),
+                        row,
+                        ..
+                    }) => match row
+                        .checked_sub(metrics.content_top)
+                        .and_then(|row| lines.get(row as usize))
+                    {
+                        Some(&SelectListLine::Item(i)) if !items[i].disabled => {
+                            toggle = multiple;
+                            submit = !multiple;
+                            Some(i)
+                        }
+                        _ => None,
+                    },
+                    _ => None,
+                };
+                let Some(new_index) = new_index else {
+                    return;
+                };
+                if new_index != selected_index {
+                    selected_index = new_index;
+                    if !is_selection_controlled {
+                        selected_index_state.set(new_index);
+                    }
+                    on_select(new_index);
+                }
+                if toggle && !items[new_index].disabled {
+                    match checked.binary_search(&new_index) {
+                        Ok(i) => {
+                            checked.remove(i);
+                        }
+                        Err(i) => checked.insert(i, new_index),
+                    }
+                    if !is_checked_controlled {
+                        checked_state.set(checked.clone());
+                    }
+                    on_check(checked.clone());
+                }
+                if submit && !items[new_index].disabled {
+                    on_submit(new_index);
+                }
+            }
+        });
+
+        self.items = props.items.clone();
+        self.selected_index = (!self.items.is_empty()).then_some(selected_index);
+        self.checked = props.multiple.then_some(checked);
+        self.item_style = CanvasTextStyle {
+            color: props.color,
+            ..Default::default()
+        };
+        self.selected_style = CanvasTextStyle {
+            color: Some(props.selected_color.unwrap_or(Color::Blue)),
+            ..Default::default()
+        };
+        self.disabled_style = CanvasTextStyle {
+            color: Some(props.disabled_color.unwrap_or(Color::DarkGrey)),
+            ..Default::default()
+        };
+        self.header_style = CanvasTextStyle {
+            color: props.header_color,
+            weight: Weight::Bold,
+            ..Default::default()
+        };
+
+        updater.set_layout_style_props(props.layout_style());
+        updater.set_measure_func({
+            let width = self
+                .lines
+                .iter()
+                .map(|line| self.line_text(line).width())
+                .max()
+                .unwrap_or(0);
+            let height = self.lines.len();
+            Box::new(move |_, _, _| Size {
+                width: width as _,
+                height: height as _,
+            })
+        });
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        let layout = drawer.layout();
+        let left = (layout.border.left + layout.padding.left) as isize;
+        let top = layout.border.top + layout.padding.top;
+        self.metrics.lock().unwrap().content_top = top as _;
+
+        let mut canvas = drawer.canvas();
+        for (y, line) in self.lines.iter().enumerate() {
+            let style = match line {
+                SelectListLine::Header(_) => &self.header_style,
+                SelectListLine::Item(i) if self.items[*i].disabled => &self.disabled_style,
+                SelectListLine::Item(i) if self.selected_index == Some(*i) => &self.selected_style,
+                SelectListLine::Item(_) => &self.item_style,
+            };
+            canvas.set_text(
+                left,
+                top as isize + y as isize,
+                &self.line_text(line),
+                style.clone(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_event_batches, render_with_events},
+    };
+    use crossterm::event::MouseButton;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn items() -> Vec<SelectItem> {
+        vec![
+            SelectItem {
+                group: Some("Fruits".to_string()),
+                ..SelectItem::new("Apple")
+            },
+            SelectItem {
+                group: Some("Fruits".to_string()),
+                disabled: true,
+                ..SelectItem::new("Banana")
+            },
+            SelectItem {
+                group: Some("Fruits".to_string()),
+                ..SelectItem::new("Blueberry")
+            },
+            SelectItem {
+                group: Some("Vegetables".to_string()),
+                ..SelectItem::new("Broccoli")
+            },
+            SelectItem {
+                group: Some("Vegetables".to_string()),
+                ..SelectItem::new("Carrot")
+            },
+        ]
+    }
+
+    #[test]
+    fn test_next_enabled() {
+        let items = items();
+        assert_eq!(next_enabled(&items, 0, true, false), Some(2));
+        assert_eq!(next_enabled(&items, 2, false, false), Some(0));
+        assert_eq!(next_enabled(&items, 0, false, false), None);
+        assert_eq!(next_enabled(&items, 0, false, true), Some(4));
+        assert_eq!(next_enabled(&items, 4, true, true), Some(0));
+        assert_eq!(find_by_prefix(&items, 1, "b"), Some(2));
+        assert_eq!(find_by_prefix(&items, 3, "b"), Some(3));
+        assert_eq!(find_by_prefix(&items, 4, "bl"), Some(2));
+        assert_eq!(find_by_prefix(&items, 0, "x"), None);
+    }
+
+    #[test]
+    fn test_select_list() {
+        assert_eq!(
+            element!(SelectList(items: items())).to_string(),
+            "Fruits\n❯ Apple\n  Banana\n  Blueberry\nVegetables\n  Broccoli\n  Carrot\n"
+        );
+        assert_eq!(
+            element!(SelectList(
+                items: vec!["foo".into(), "bar".into()],
+                multiple: true,
+                checked: vec![1],
+                selected_index: 1usize,
+            ))
+            .to_string(),
+            "  ◯ foo\n❯ ◉ bar\n"
+        );
+    }
+
+    #[derive(Default, Props)]
+    struct MyComponentProps {
+        multiple: bool,
+    }
+
+    #[component]
+    fn MyComponent(props: &MyComponentProps, mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut submitted = hooks.use_state(|| None);
+
+        element! {
+            View(flex_direction: FlexDirection::Column, padding_top: 1) {
+                SelectList(
+                    items: items(),
+                    has_focus: true,
+                    multiple: props.multiple,
+                    on_submit: move |i| submitted.set(Some(i)),
+                )
+                Text(content: format!("submitted: {:?}", submitted.get()))
+            }
+        }
+    }
+
+    async fn render_with_multiple(multiple: bool, events: Vec<TerminalEvent>) -> String {
+        render_with_events(move || element!(MyComponent(multiple)).into_any(), events)
+            .await
+            .pop()
+            .unwrap()
+            .to_string()
+    }
+
+    #[apply(test!)]
+    async fn test_select_list_keys() {
+        // Disabled items are skipped.
+        assert_eq!(
+            render_with_multiple(false, vec![key(KeyCode::Down), key(KeyCode::Enter)]).await,
+            "\nFruits\n  Apple\n  Banana\n❯ Blueberry\nVegetables\n  Broccoli\n  Carrot\nsubmitted: Some(2)\n"
+        );
+
+        // Without wrapping around, the highlight stops at the first item.
+        assert_eq!(
+            render_with_multiple(false, vec![key(KeyCode::Up), key(KeyCode::Enter)]).await,
+            "\nFruits\n❯ Apple\n  Banana\n  Blueberry\nVegetables\n  Broccoli\n  Carrot\nsubmitted: Some(0)\n"
+        );
+
+        // Typing jumps to matching items.
+        assert_eq!(
+            render_with_multiple(false, vec![key(KeyCode::Char('b')), key(KeyCode::Char('r'))])
+                .await,
+            "\nFruits\n  Apple\n  Banana\n  Blueberry\nVegetables\n❯ Broccoli\n  Carrot\nsubmitted: None\n"
+        );
+
+        assert_eq!(
+            render_with_multiple(
+                true,
+                vec![
+                    key(KeyCode::Char(' ')),
+                    key(KeyCode::End),
+                    key(KeyCode::Char(' ')),
+                ]
+            )
+            .await,
+            "\nFruits\n  ◉ Apple\n  ◯ Banana\n  ◯ Blueberry\nVegetables\n  ◯ Broccoli\n❯ ◉ Carrot\nsubmitted: None\n"
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_select_list_click() {
+        let click = |row| {
+            TerminalEvent::FullscreenMouse(FullscreenMouseEvent::new(
+                MouseEventKind::Down(MouseButton::Left),
+                2,
+                row,
+            ))
+        };
+
+        // Clicking on headers and disabled items does nothing.
+        assert_eq!(
+            render_with_multiple(false, vec![click(1), click(3), click(6)]).await,
+            "\nFruits\n  Apple\n  Banana\n  Blueberry\nVegetables\n❯ Broccoli\n  Carrot\nsubmitted: Some(3)\n"
+        );
+
+        assert_eq!(
+            render_with_multiple(true, vec![click(4), click(4), click(7)]).await,
+            "\nFruits\n  ◯ Apple\n  ◯ Banana\n  ◯ Blueberry\nVegetables\n  ◯ Broccoli\n❯ ◉ Carrot\nsubmitted: None\n"
+        );
+    }
+
+    #[component]
+    fn ShrinkingList(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut len = hooks.use_state(|| items().len());
+        let mut selected = hooks.use_state(|| None);
+        let mut submitted = hooks.use_state(|| None);
+
+        hooks.use_terminal_events(move |event| {
+            if let TerminalEvent::Key(KeyEvent {
+                code: KeyCode::Delete,
+                ..
+            }) = event
+            {
+                len -= 1;
+            }
+        });
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                SelectList(
+                    items: items().into_iter().take(len.get()).collect::<Vec<_>>(),
+                    has_focus: true,
+                    on_select: move |i| selected.set(Some(i)),
+                    on_submit: move |i| submitted.set(Some(i)),
+                )
+                Text(content: format!("selected: {:?}, submitted: {:?}", selected.get(), submitted.get()))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_select_list_shrinking_items() {
+        // Removing the selected item moves the selection to the new last item.
+        let canvas = render_with_event_batches(
+            || element!(ShrinkingList).into_any(),
+            vec![
+                vec![key(KeyCode::End), key(KeyCode::Delete)],
+                vec![key(KeyCode::Enter)],
+            ],
+        )
+        .await
+        .pop()
+        .unwrap();
+        assert_eq!(
+            canvas.to_string(),
+            "Fruits\n  Apple\n  Banana\n  Blueberry\nVegetables\n❯ Broccoli\nselected: Some(3), submitted: Some(3)\n"
+        );
+    }
+}
@@ -0,0 +1,249 @@
+use crate::{
+    component,
+    components::{Text, View},
+    element,
+    hooks::UseTicker,
+    AnyElement, Color, Hooks, Props,
+};
+use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
+
+/// The frames of a [`Spinner`] animation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpinnerFrames {
+    /// The frames, which are displayed in order and then repeated.
+    pub frames: Vec<String>,
+    /// How long each frame is displayed for.
+    pub interval: Duration,
+}
+
+impl SpinnerFrames {
+    /// Creates a new set of frames.
+    pub fn new<I, S>(frames: I, interval: Duration) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self {
+            frames: frames.into_iter().map(Into::into).collect(),
+            interval,
+        }
+    }
+
+    /// Braille dots circling a cell: `⠋ ⠙ ⠹ ⠸ ⠼ ⠴ ⠦ ⠧ ⠇ ⠏`. This is the default.
+    pub fn dots() -> Self {
+        Self::new(
+            ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+            Duration::from_millis(80),
+        )
+    }
+
+    /// A rotating line: `- \ | /`.
+    pub fn line() -> Self {
+        Self::new(["-", "\\", "|", "/"], Duration::from_millis(130))
+    }
+
+    /// A rotating arc: `◜ ◠ ◝ ◞ ◡ ◟`.
+    pub fn arc() -> Self {
+        Self::new(["◜", "◠", "◝", "◞", "◡", "◟"], Duration::from_millis(100))
+    }
+
+    /// A rotating quarter-filled circle: `◐ ◓ ◑ ◒`.
+    pub fn circle() -> Self {
+        Self::new(["◐", "◓", "◑", "◒"], Duration::from_millis(120))
+    }
+
+    /// A rotating arrow: `← ↖ ↑ ↗ → ↘ ↓ ↙`.
+    pub fn arrow() -> Self {
+        Self::new(
+            ["←", "↖", "↑", "↗", "→", "↘", "↓", "↙"],
+            Duration::from_millis(100),
+        )
+    }
+
+    /// A bar bouncing between brackets: `[=   ]`, `[==  ]`, and so on.
+    pub fn bouncing_bar() -> Self {
+        Self::new(
+            [
+                "[    ]", "[=   ]", "[==  ]", "[=== ]", "[ ===]", "[  ==]", "[   =]", "[    ]",
+                "[   =]", "[  ==]", "[ ===]", "[====]", "[=== ]", "[==  ]", "[=   ]",
+            ],
+            Duration::from_millis(80),
+        )
+    }
+
+    /// A ball bouncing between parentheses: `( ●    )`, `(  ●   )`, and so on.
+    pub fn bouncing_ball() -> Self {
+        Self::new(
+            [
+                "( ●    )",
+                "(  ●   )",
+                "(   ●  )",
+                "(    ● )",
+                "(     ●)",
+                "(    ● )",
+                "(   ●  )",
+                "(  ●   )",
+                "( ●    )",
+                "(●     )",
+            ],
+            Duration::from_millis(80),
+        )
+    }
+
+    /// Dots filling up and emptying, using only ASCII characters: `.  `, `.. `, `...`, and so on.
+    /// This is useful for terminals or fonts which can't display the other sets.
+    pub fn ascii() -> Self {
+        Self::new(
+            [".  ", ".. ", "...", " ..", "  .", "   "],
+            Duration::from_millis(200),
+        )
+    }
+}
+
+impl Default for SpinnerFrames {
+    fn default() -> Self {
+        Self::dots()
+    }
+}
+
+/// The props which can be passed to the [`Spinner`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct SpinnerProps {
+    /// The frames to animate. Defaults to [`SpinnerFrames::dots`].
+    pub frames: Option<SpinnerFrames>,
+
+    /// How long each frame is displayed for, overriding the interval of the frames.
+    pub interval: Option<Duration>,
+
+    /// The color of the spinner.
+    pub color: Option<Color>,
+
+    /// The text to display after the spinner.
+    pub label: String,
+
+    /// The color of the label.
+    pub label_color: Option<Color>,
+}
+
+/// `Spinner` is a component which displays an animation to indicate that something is in progress.
+///
+/// Spinners are animated by a shared [ticker](crate::hooks::UseTicker), so any number of them can
+/// be displayed at once without each running their own timer.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     Spinner(frames: SpinnerFrames::arc(), color: Color::Cyan, label: "Downloading...")
+/// }
+/// # }
+/// ```
+#[component]
+pub fn Spinner(mut hooks: Hooks, props: &SpinnerProps) -> impl Into<AnyElement<'static>> {
+    let frames = props.frames.clone().unwrap_or_default();
+    let tick = hooks.use_ticker(props.interval.unwrap_or(frames.interval));
+
+    // Frames are padded to the same width so that the label doesn't shift around.
+    let width = frames.frames.iter().map(|f| f.width()).max().unwrap_or(0);
+    let frame = match frames.frames.len() {
+        0 => "",
+        n => frames.frames[(tick % n as u64) as usize].as_str(),
+    };
+
+    element! {
+        View {
+            Text(
+                content: format!("{}{}", frame, " ".repeat(width - frame.width())),
+                color: props.color,
+            )
+            #((!props.label.is_empty()).then(|| element! {
+                Text(content: format!(" {}", props.label), color: props.label_color)
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[test]
+    fn test_spinner() {
+        assert_eq!(
+            element!(Spinner(label: "Loading...")).to_string(),
+            "⠋ Loading...\n"
+        );
+        assert_eq!(
+            element!(Spinner(frames: SpinnerFrames::ascii())).to_string(),
+            ".\n"
+        );
+        assert_eq!(
+            element!(Spinner(frames: SpinnerFrames::new(["", "."], Duration::from_secs(1)), label: "foo"))
+                .to_string(),
+            "  foo\n"
+        );
+    }
+
+    #[test]
+    fn test_spinner_frame_widths() {
+        for frames in [
+            SpinnerFrames::dots(),
+            SpinnerFrames::line(),
+            SpinnerFrames::arc(),
+            SpinnerFrames::circle(),
+            SpinnerFrames::arrow(),
+            SpinnerFrames::bouncing_bar(),
+            SpinnerFrames::bouncing_ball(),
+            SpinnerFrames::ascii(),
+        ] {
+            let width = frames.frames[0].width();
+            assert!(frames.frames.iter().all(|f| f.width() == width));
+        }
+        assert!(SpinnerFrames::ascii()
+            .frames
+            .iter()
+            .all(|frame| frame.is_ascii()));
+    }
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let tick = hooks.use_ticker(Duration::from_millis(50));
+
+        if tick >= 1 {
+            system.exit();
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                #((0..10).map(|_| element! {
+                    Spinner(frames: SpinnerFrames::line(), interval: Duration::from_millis(5))
+                }))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_spinner_animation() {
+        let canvases = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(canvases[0], "-\n".repeat(10));
+        assert!(canvases.len() > 1);
+        for canvas in canvases {
+            assert!(canvas
+                .lines()
+                .all(|line| ["-", "\\", "|", "/"].contains(&line)));
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{AnyElement, Component, ComponentUpdater, Hooks, Props};
+use taffy::{Display, FlexDirection, Style};
+
+/// The props which can be passed to the [`Static`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct StaticProps<'a> {
+    /// The children of the component.
+    pub children: Vec<AnyElement<'a>>,
+}
+
+/// `Static` is a component which renders its children once and writes them permanently above the
+/// rest of the output, where they will remain even as the rest of the output is redrawn.
+///
+/// Only children that have not previously been written are rendered, so new output can be emitted
+/// by appending children. Each child is rendered independently and only once, so it will not be
+/// updated if its props change, and it will not have access to the context provided by its
+/// ancestors.
+///
+/// This is useful for things like logs or summaries of completed tasks, which can be printed above
+/// a live progress display.
+///
+/// When the element is not being rendered to a terminal, e.g. via
+/// [`ElementExt::to_string`](crate::ElementExt::to_string), the children are simply laid out in a
+/// column.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Tasks(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut completed = hooks.use_state(|| 0);
+///
+///     hooks.use_future(async move {
+///         loop {
+///             smol::Timer::after(std::time::Duration::from_secs(1)).await;
+///             completed += 1;
+///         }
+///     });
+///
+///     element! {
+///         View(flex_direction: FlexDirection::Column) {
+///             Static {
+///                 #((0..completed.get()).map(|i| element! {
+///                     View(border_style: BorderStyle::Round, border_color: Color::Green) {
+///                         Text(content: format!("Task {} complete!", i + 1))
+///                     }
+///                 }))
+///             }
+///             Text(content: format!("Working on task {}...", completed + 1))
+///         }
+///     }
+/// }
+/// ```
+#[derive(Default)]
+pub struct Static {
+    written_children: usize,
+}
+
+impl Component for Static {
+    type Props<'a> = StaticProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Self::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        _hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        if !updater.is_rendering_to_terminal() {
+            updater.set_layout_style(Style {
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            });
+            updater.update_children(props.children.iter_mut(), None);
+            return;
+        }
+
+        updater.set_layout_style(Style {
+            display: Display::None,
+            ..Default::default()
+        });
+        for child in props.children.iter_mut().skip(self.written_children) {
+            updater.write_static_output(child);
+        }
+        self.written_children = props.children.len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let mut tick = hooks.use_state(|| 0);
+
+        if tick == 2 {
+            system.exit();
+        } else {
+            tick += 1;
+        }
+
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                Static {
+                    #((0..tick.get()).map(|i| element! {
+                        Text(content: format!("done: {}", i))
+                    }))
+                }
+                Text(content: format!("tick: {}", tick))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_static() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        let expected = vec!["done: 0\n", "tick: 1\n", "done: 1\n", "tick: 2\n"];
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_static_without_terminal() {
+        let actual = element! {
+            View(flex_direction: FlexDirection::Column) {
+                Static {
+                    Text(content: "foo")
+                    Text(content: "bar")
+                }
+                Text(content: "baz")
+            }
+        }
+        .to_string();
+        assert_eq!(actual, "foo\nbar\nbaz\n");
+    }
+}
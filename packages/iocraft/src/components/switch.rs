@@ -0,0 +1,122 @@
+use crate::{
+    component,
+    components::{Button, Text, ToggleGlyphs, View},
+    element, AnyElement, Color, Handler, Props,
+};
+
+/// The props which can be passed to the [`Switch`] component.
+#[non_exhaustive]
+#[derive(Default, Props)]
+pub struct SwitchProps {
+    /// The text to display next to the switch.
+    pub label: String,
+
+    /// True if the switch is on.
+    pub on: bool,
+
+    /// The handler to invoke when the user flips the switch. It's given the new state, which the
+    /// parent should pass back via [`on`](Self::on).
+    ///
+    /// The switch can be flipped two ways:
+    ///
+    /// - By clicking on it with the mouse while in fullscreen mode.
+    /// - By pressing the Enter or Space key while [`has_focus`](Self::has_focus) is `true`.
+    pub on_change: Handler<'static, bool>,
+
+    /// True if the switch has focus and should process keyboard input.
+    pub has_focus: bool,
+
+    /// The glyphs to display. Defaults to [`ToggleGlyphs::switch`].
+    pub glyphs: Option<ToggleGlyphs>,
+
+    /// The color of the switch and label while off.
+    pub color: Option<Color>,
+
+    /// The color of the switch while on. Defaults to green.
+    pub on_color: Option<Color>,
+
+    /// The color of the label while focused. Defaults to blue.
+    pub focus_color: Option<Color>,
+}
+
+/// `Switch` is a component which displays a labeled toggle that the user can turn on or off.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Settings(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let mut dark_mode = hooks.use_state(|| true);
+///
+///     element! {
+///         Switch(
+///             label: "Dark mode",
+///             on: dark_mode.get(),
+///             on_change: move |on| dark_mode.set(on),
+///             has_focus: true,
+///         )
+///     }
+/// }
+/// ```
+#[component]
+pub fn Switch(props: &mut SwitchProps) -> impl Into<AnyElement<'static>> {
+    let on = props.on;
+    let mut on_change = props.on_change.take();
+    let glyphs = props.glyphs.clone().unwrap_or_else(ToggleGlyphs::switch);
+
+    element! {
+        Button(
+            has_focus: props.has_focus,
+            handler: {
+                let mut on = on;
+                move |_| {
+                    on = !on;
+                    on_change(on);
+                }
+            },
+        ) {
+            View {
+                Text(
+                    content: glyphs.text(on, ""),
+                    color: if on {
+                        Some(props.on_color.unwrap_or(Color::Green))
+                    } else {
+                        props.color
+                    },
+                )
+                #((!props.label.is_empty()).then(|| element! {
+                    Text(
+                        content: format!(" {}", props.label),
+                        color: if props.has_focus {
+                            Some(props.focus_color.unwrap_or(Color::Blue))
+                        } else {
+                            props.color
+                        },
+                    )
+                }))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_switch() {
+        assert_eq!(
+            element!(Switch(label: "Dark mode")).to_string(),
+            "●━━ Dark mode\n"
+        );
+        assert_eq!(
+            element!(Switch(label: "Dark mode", on: true)).to_string(),
+            "━━● Dark mode\n"
+        );
+        assert_eq!(
+            element!(Switch(on: true, glyphs: ToggleGlyphs::switch_ascii())).to_string(),
+            "[ on]\n"
+        );
+    }
+}
@@ -0,0 +1,1013 @@
+use crate::{
+    components::{BorderCharacters, BorderStyle, Text, TextAlign},
+    hooks::{UseState, UseTerminalEvents},
+    segmented_string::SegmentedString,
+    CanvasSubviewMut, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater,
+    Handler, Hooks, KeyCode, KeyEvent, KeyEventKind, Props, TerminalEvent, Weight,
+};
+use iocraft_macros::with_layout_style_props;
+use std::sync::Arc;
+use taffy::{AvailableSpace, Size};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The width of a [`TableColumn`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ColumnWidth {
+    /// The column is as wide as its widest cell. This is the default.
+    #[default]
+    Auto,
+    /// The column is exactly this many cells wide.
+    Fixed(u16),
+    /// The column takes up a percentage of the table's width.
+    Percent(f32),
+    /// The column takes up a share of the table's width that's left over after sizing the other
+    /// columns, in proportion to its factor relative to the other flexible columns.
+    Flex(f32),
+}
+
+/// The behavior of a [`TableColumn`] when a cell's content is too wide to fit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CellOverflow {
+    /// The content is truncated and ends with an ellipsis. This is the default.
+    #[default]
+    Ellipsis,
+    /// The content is truncated.
+    Clip,
+    /// The content is wrapped onto multiple lines, making the row taller.
+    Wrap,
+}
+
+/// The definition of a column of a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableColumn {
+    /// The content of the column's header.
+    pub header: String,
+    /// The width of the column, not including the cell padding.
+    pub width: ColumnWidth,
+    /// The minimum width of the column.
+    pub min_width: Option<u16>,
+    /// The maximum width of the column.
+    pub max_width: Option<u16>,
+    /// The alignment of the column's content.
+    pub align: TextAlign,
+    /// The behavior of the column when a cell's content is too wide to fit.
+    pub overflow: CellOverflow,
+}
+
+impl TableColumn {
+    /// Creates a new column with the given header.
+    pub fn new<S: Into<String>>(header: S) -> Self {
+        Self {
+            header: header.into(),
+            ..Default::default()
+        }
+    }
+
+    fn min_width(&self) -> usize {
+        match self.width {
+            ColumnWidth::Fixed(width) => width as _,
+            _ => self.min_width.unwrap_or(1) as _,
+        }
+    }
+
+    fn clamp_width(&self, width: usize) -> usize {
+        let width = self.max_width.map_or(width, |max| width.min(max as _));
+        self.min_width.map_or(width, |min| width.max(min as _))
+    }
+
+    /// Fits the content of a cell to the given width, returning its lines.
+    fn fit(&self, content: &str, width: usize) -> Vec<String> {
+        let lines = match self.overflow {
+            CellOverflow::Wrap => content
+                .lines()
+                .flat_map(|line| {
+                    let s = SegmentedString::from(line);
+                    s.wrap(width.max(1))
+                        .into_iter()
+                        .map(|mut line| {
+                            line.trim_end();
+                            truncate(&line.to_string(), width, false)
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            CellOverflow::Ellipsis | CellOverflow::Clip => vec![truncate(
+                &content.lines().collect::<Vec<_>>().join(" "),
+                width,
+                self.overflow == CellOverflow::Ellipsis,
+            )],
+        };
+        lines
+            .into_iter()
+            .map(|line| {
+                let line_width = line.width();
+                let padding = Text::alignment_padding(line_width, self.align, width);
+                format!(
+                    "{}{}{}",
+                    " ".repeat(padding),
+                    line,
+                    " ".repeat(width - line_width - padding)
+                )
+            })
+            .collect()
+    }
+}
+
+/// Truncates the given single line of text to fit within the given width.
+fn truncate(s: &str, width: usize, ellipsis: bool) -> String {
+    if s.width() <= width {
+        return s.to_string();
+    } else if width == 0 {
+        return String::new();
+    }
+    let width = if ellipsis {
+        width.saturating_sub(1)
+    } else {
+        width
+    };
+    let mut ret = String::new();
+    let mut ret_width = 0;
+    for g in s.graphemes(true) {
+        let w = g.width();
+        if ret_width + w > width {
+            break;
+        }
+        ret.push_str(g);
+        ret_width += w;
+    }
+    if ellipsis {
+        ret.push('…');
+    }
+    ret
+}
+
+/// The style of a row of a [`Table`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TableRowStyle {
+    /// The color of the text.
+    pub color: Option<Color>,
+    /// The color of the background.
+    pub background_color: Option<Color>,
+    /// The weight of the text.
+    pub weight: Weight,
+    /// Whether to italicize the text.
+    pub italic: bool,
+    /// Whether to swap the foreground and background colors.
+    pub reverse: bool,
+}
+
+impl TableRowStyle {
+    fn text_style(&self) -> CanvasTextStyle {
+        CanvasTextStyle {
+            color: self.color,
+            weight: self.weight,
+            italic: self.italic,
+            reverse: self.reverse,
+            ..Default::default()
+        }
+    }
+}
+
+/// The props which can be passed to the [`Table`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct TableProps {
+    /// The columns of the table.
+    pub columns: Vec<TableColumn>,
+
+    /// The rows of the table, each of which contains the content of a cell for each column.
+    pub rows: Vec<Vec<String>>,
+
+    /// The footer row of the table, if any.
+    pub footer: Option<Vec<String>>,
+
+    /// If true, the header row isn't displayed.
+    pub hide_header: bool,
+
+    /// The style of the header row.
+    pub header_style: TableRowStyle,
+
+    /// The style of the footer row.
+    pub footer_style: TableRowStyle,
+
+    /// The style of the rows.
+    pub row_style: TableRowStyle,
+
+    /// If given, every other row uses this style instead of [`row_style`](Self::row_style),
+    /// starting with the second.
+    pub stripe_style: Option<TableRowStyle>,
+
+    /// The style of the selected row. Defaults to reversed colors.
+    pub selected_row_style: Option<TableRowStyle>,
+
+    /// The style of the border around the table. Grid lines use the same characters, and are joined
+    /// to the border and each other with the appropriate box drawing characters.
+    pub border_style: BorderStyle,
+
+    /// The color of the border and grid lines.
+    pub border_color: Option<Color>,
+
+    /// If true, lines are drawn between columns.
+    pub column_dividers: bool,
+
+    /// If true, lines are drawn between rows. Lines are always drawn beneath the header and above
+    /// the footer.
+    pub row_dividers: bool,
+
+    /// The number of spaces on either side of each cell's content. Defaults to 1.
+    pub cell_padding: Option<u16>,
+
+    /// True if the table has focus. While focused, the selected row is highlighted, and the Up,
+    /// Down, Home and End keys change the selection.
+    pub has_focus: bool,
+
+    /// The index of the selected row. If `Some`, the selection is controlled by the parent, which
+    /// should update it in response to [`on_select`](Self::on_select). If `None`, the table keeps
+    /// track of the selection itself, starting with the first row.
+    pub selected_row: Option<usize>,
+
+    /// The handler to invoke when the user changes the selection.
+    pub on_select: Handler<'static, usize>,
+}
+
+#[derive(Default)]
+struct TableModel {
+    columns: Vec<TableColumn>,
+    header: Option<Vec<String>>,
+    rows: Vec<Vec<String>>,
+    footer: Option<Vec<String>>,
+    has_border: bool,
+    column_dividers: bool,
+    row_dividers: bool,
+    cell_padding: usize,
+}
+
+impl TableModel {
+    fn new(props: &TableProps) -> Self {
+        Self {
+            columns: props.columns.clone(),
+            header: (!props.hide_header).then(|| {
+                props
+                    .columns
+                    .iter()
+                    .map(|column| column.header.clone())
+                    .collect()
+            }),
+            rows: props.rows.clone(),
+            footer: props.footer.clone(),
+            has_border: !props.border_style.is_none(),
+            column_dividers: props.column_dividers,
+            row_dividers: props.row_dividers,
+            cell_padding: props.cell_padding.unwrap_or(1) as _,
+        }
+    }
+
+    /// Returns true if the model would be unchanged by rebuilding it from the given props.
+    fn matches(&self, props: &TableProps) -> bool {
+        self.columns == props.columns
+            && self.header.is_none() == props.hide_header
+            && self.rows == props.rows
+            && self.footer == props.footer
+            && self.has_border != props.border_style.is_none()
+            && self.column_dividers == props.column_dividers
+            && self.row_dividers == props.row_dividers
+            && self.cell_padding == props.cell_padding.unwrap_or(1) as usize
+    }
+
+    fn all_rows(&self) -> impl Iterator<Item = &Vec<String>> {
+        self.header
+            .iter()
+            .chain(self.rows.iter())
+            .chain(self.footer.iter())
+    }
+
+    /// Returns the width taken up by everything other than the content of the cells.
+    fn chrome_width(&self) -> usize {
+        let n = self.columns.len();
+        let border = if self.has_border { 2 } else { 0 };
+        let dividers = if self.column_dividers {
+            n.saturating_sub(1)
+        } else {
+            0
+        };
+        border + dividers + 2 * self.cell_padding * n
+    }
+
+    fn content_widths(&self) -> Vec<usize> {
+        (0..self.columns.len())
+            .map(|i| {
+                self.all_rows()
+                    .filter_map(|row| row.get(i))
+                    .flat_map(|cell| cell.lines())
+                    .map(|line| line.width())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect()
+    }
+
+    /// Returns the widths of the columns' content, given the width of the entire table, if known.
+    fn column_widths(&self, table_width: Option<usize>) -> Vec<usize> {
+        let content_widths = self.content_widths();
+        let available = table_width.map(|w| w.saturating_sub(self.chrome_width()));
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .zip(content_widths)
+            .map(|(column, content_width)| {
+                column.clamp_width(match (column.width, available) {
+                    (ColumnWidth::Fixed(width), _) => width as _,
+                    (ColumnWidth::Percent(percent), Some(available)) => {
+                        (available as f32 * percent / 100.0) as _
+                    }
+                    (ColumnWidth::Flex(_), Some(_)) => 0,
+                    _ => content_width,
+                })
+            })
+            .collect();
+
+        let Some(available) = available else {
+            return widths;
+        };
+
+        // Distribute any leftover space among the flexible columns.
+        let used: usize = widths.iter().sum();
+        let total_flex: f32 = self
+            .columns
+            .iter()
+            .filter_map(|column| match column.width {
+                ColumnWidth::Flex(factor) => Some(factor),
+                _ => None,
+            })
+            .sum();
+        if used < available && total_flex > 0.0 {
+            let remaining = (available - used) as f32;
+            let mut flex = 0.0;
+            let mut distributed = 0;
+            for (column, width) in self.columns.iter().zip(widths.iter_mut()) {
+                if let ColumnWidth::Flex(factor) = column.width {
+                    flex += factor;
+                    let share = (remaining * flex / total_flex).round() as usize - distributed;
+                    distributed += share;
+                    *width = column.clamp_width(*width + share);
+                }
+            }
+        }
+
+        // If the columns don't fit, shrink the widest ones.
+        let mut used: usize = widths.iter().sum();
+        while used > available {
+            let Some(i) = (0..widths.len())
+                .filter(|&i| widths[i] > self.columns[i].min_width())
+                .max_by_key(|&i| (widths[i], i))
+            else {
+                break;
+            };
+            widths[i] -= 1;
+            used -= 1;
+        }
+
+        widths
+    }
+
+    fn row_lines(&self, row: &[String], widths: &[usize]) -> Vec<Vec<String>> {
+        self.columns
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (column, &width))| {
+                column.fit(row.get(i).map(String::as_str).unwrap_or(""), width)
+            })
+            .collect()
+    }
+
+    fn row_height(&self, row: &[String], widths: &[usize]) -> usize {
+        self.row_lines(row, widths)
+            .iter()
+            .map(|lines| lines.len())
+            .max()
+            .unwrap_or(0)
+            .max(1)
+    }
+
+    fn height(&self, widths: &[usize]) -> usize {
+        let mut height = if self.has_border { 2 } else { 0 };
+        if let Some(header) = &self.header {
+            height += self.row_height(header, widths) + 1;
+        }
+        height += self
+            .rows
+            .iter()
+            .map(|row| self.row_height(row, widths))
+            .sum::<usize>();
+        if self.row_dividers {
+            height += self.rows.len().saturating_sub(1);
+        }
+        if let Some(footer) = &self.footer {
+            height += self.row_height(footer, widths) + 1;
+        }
+        height
+    }
+
+    fn measure(
+        &self,
+        known_size: Size<Option<f32>>,
+        available_space: Size<AvailableSpace>,
+    ) -> Size<f32> {
+        let width = match known_size.width {
+            Some(width) => width as usize,
+            None => {
+                let content_width =
+                    self.chrome_width() + self.column_widths(None).iter().sum::<usize>();
+                match available_space.width {
+                    AvailableSpace::Definite(width) => {
+                        if self
+                            .columns
+                            .iter()
+                            .any(|column| matches!(column.width, ColumnWidth::Flex(_)))
+                        {
+                            width as usize
+                        } else {
+                            content_width.min(width as usize)
+                        }
+                    }
+                    AvailableSpace::MaxContent => content_width,
+                    AvailableSpace::MinContent => {
+                        self.chrome_width()
+                            + self
+                                .columns
+                                .iter()
+                                .map(|column| column.min_width())
+                                .sum::<usize>()
+                    }
+                }
+            }
+        };
+        let widths = self.column_widths(Some(width));
+        Size {
+            width: (self.chrome_width() + widths.iter().sum::<usize>()) as _,
+            height: self.height(&widths) as _,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LineWeight {
+    Light,
+    Heavy,
+    Double,
+}
+
+impl LineWeight {
+    fn of(c: char) -> Option<Self> {
+        match c {
+            '─' | '│' => Some(Self::Light),
+            '━' | '┃' => Some(Self::Heavy),
+            '═' | '║' => Some(Self::Double),
+            _ => None,
+        }
+    }
+}
+
+/// A junction between a vertical and horizontal line, named after the arm which only one of the
+/// lines extends into.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Junction {
+    Down,
+    Up,
+    Right,
+    Left,
+    Cross,
+}
+
+impl Junction {
+    /// Returns the box drawing character which joins the given vertical and horizontal lines, or
+    /// `+` if there isn't one.
+    fn char(self, vertical: char, horizontal: char) -> char {
+        use LineWeight::*;
+        let chars = match (LineWeight::of(vertical), LineWeight::of(horizontal)) {
+            (Some(Light), Some(Light)) => "┬┴├┤┼",
+            (Some(Heavy), Some(Heavy)) => "┳┻┣┫╋",
+            (Some(Double), Some(Double)) => "╦╩╠╣╬",
+            (Some(Double), Some(Light)) => "╥╨╟╢╫",
+            (Some(Light), Some(Double)) => "╤╧╞╡╪",
+            (Some(Heavy), Some(Light)) => "┰┸┠┨╂",
+            (Some(Light), Some(Heavy)) => "┯┷┝┥┿",
+            _ => return '+',
+        };
+        chars.chars().nth(self as usize).unwrap()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum LinePosition {
+    Top,
+    Middle,
+    Bottom,
+}
+
+struct TableDrawer<'a> {
+    model: &'a TableModel,
+    widths: Vec<usize>,
+    chars: BorderCharacters,
+    border_style: CanvasTextStyle,
+    x: isize,
+}
+
+impl TableDrawer<'_> {
+    fn draw_line(&self, canvas: &mut CanvasSubviewMut, y: isize, position: LinePosition) {
+        let (horizontal, left, right, divider) = match position {
+            LinePosition::Top => (
+                self.chars.top,
+                self.chars.top_left,
+                self.chars.top_right,
+                Junction::Down,
+            ),
+            LinePosition::Middle => (
+                self.chars.top,
+                Junction::Right.char(self.chars.left, self.chars.top),
+                Junction::Left.char(self.chars.right, self.chars.top),
+                Junction::Cross,
+            ),
+            LinePosition::Bottom => (
+                self.chars.bottom,
+                self.chars.bottom_left,
+                self.chars.bottom_right,
+                Junction::Up,
+            ),
+        };
+        let mut line = String::new();
+        if self.model.has_border {
+            line.push(left);
+        }
+        for (i, width) in self.widths.iter().enumerate() {
+            if i > 0 && self.model.column_dividers {
+                line.push(divider.char(self.chars.left, horizontal));
+            }
+            for _ in 0..(width + 2 * self.model.cell_padding) {
+                line.push(horizontal);
+            }
+        }
+        if self.model.has_border {
+            line.push(right);
+        }
+        canvas.set_text(self.x, y, &line, self.border_style.clone());
+    }
+
+    /// Draws a row, returning its height.
+    fn draw_row(
+        &self,
+        canvas: &mut CanvasSubviewMut,
+        y: isize,
+        row: &[String],
+        style: &TableRowStyle,
+    ) -> usize {
+        let lines = self.model.row_lines(row, &self.widths);
+        let height = lines
+            .iter()
+            .map(|lines| lines.len())
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let text_style = style.text_style();
+        let padding = " ".repeat(self.model.cell_padding);
+        let is_whitespace_visible = style.reverse;
+        for line in 0..height {
+            let y = y + line as isize;
+            let mut x = self.x;
+            if self.model.has_border {
+                canvas.set_text(
+                    x,
+                    y,
+                    &self.chars.left.to_string(),
+                    self.border_style.clone(),
+                );
+                x += 1;
+            }
+            for (i, (width, lines)) in self.widths.iter().zip(&lines).enumerate() {
+                if i > 0 && self.model.column_dividers {
+                    canvas.set_text(
+                        x,
+                        y,
+                        &self.chars.left.to_string(),
+                        self.border_style.clone(),
+                    );
+                    x += 1;
+                }
+                let cell_width = width + 2 * self.model.cell_padding;
+                if let Some(color) = style.background_color {
+                    canvas.set_background_color(x, y, cell_width, 1, color);
+                }
+                let content = lines.get(line).map(String::as_str).unwrap_or("");
+                if is_whitespace_visible {
+                    let content = format!("{}{:width$}{}", padding, content, padding);
+                    canvas.set_text(x, y, &content, text_style.clone());
+                } else {
+                    // Avoid writing whitespace so that trailing spaces aren't output.
+                    let trimmed = content.trim_start();
+                    let offset = self.model.cell_padding + content.len() - trimmed.len();
+                    canvas.set_text(
+                        x + offset as isize,
+                        y,
+                        trimmed.trim_end(),
+                        text_style.clone(),
+                    );
+                }
+                x += cell_width as isize;
+            }
+            if self.model.has_border {
+                canvas.set_text(
+                    x,
+                    y,
+                    &self.chars.right.to_string(),
+                    self.border_style.clone(),
+                );
+            }
+        }
+        height
+    }
+}
+
+/// `Table` is a component which displays rows of text in aligned columns.
+///
+/// Columns can be sized to fit their content, or given fixed, percentage, or flexible widths.
+/// Content which doesn't fit is truncated or wrapped according to the column's
+/// [`overflow`](TableColumn::overflow).
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     Table(
+///         border_style: BorderStyle::Round,
+///         column_dividers: true,
+///         header_style: TableRowStyle { weight: Weight::Bold, ..Default::default() },
+///         columns: vec![
+///             TableColumn { align: TextAlign::Right, ..TableColumn::new("Id") },
+///             TableColumn::new("Name"),
+///         ],
+///         rows: vec![
+///             vec!["1".to_string(), "Alice".to_string()],
+///             vec!["2".to_string(), "Bob".to_string()],
+///         ],
+///     )
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct Table {
+    model: Arc<TableModel>,
+    header_style: TableRowStyle,
+    footer_style: TableRowStyle,
+    row_style: TableRowStyle,
+    stripe_style: Option<TableRowStyle>,
+    selected_row_style: TableRowStyle,
+    selected_row: Option<usize>,
+    border_style: BorderStyle,
+    border_text_style: CanvasTextStyle,
+}
+
+impl Component for Table {
+    type Props<'a> = TableProps;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Default::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        let row_count = props.rows.len();
+        let mut selected_row_state = hooks.use_state(|| 0usize);
+        let is_controlled = props.selected_row.is_some();
+        let selected_row = props
+            .selected_row
+            .unwrap_or_else(|| selected_row_state.get())
+            .min(row_count.saturating_sub(1));
+
+        hooks.use_local_terminal_events({
+            let mut on_select = props.on_select.take();
+            let has_focus = props.has_focus;
+            let mut selected_row = selected_row;
+            move |event| match event {
+                TerminalEvent::Key(KeyEvent { code, kind, .. })
+                    if has_focus && kind != KeyEventKind::Release && row_count > 0 =>
+                {
+                    let new_row = match code {
+                        KeyCode::Up => selected_row.saturating_sub(1),
+                        KeyCode::Down => (selected_row + 1).min(row_count - 1),
+                        KeyCode::Home => 0,
+                        KeyCode::End => row_count - 1,
+                        _ => return,
+                    };
+                    if new_row != selected_row {
+                        selected_row = new_row;
+                        if !is_controlled {
+                            selected_row_state.set(new_row);
+                        }
+                        on_select(new_row);
+                    }
+                }
+                _ => {}
+            }
+        });
+
+        self.selected_row = (props.has_focus && row_count > 0).then_some(selected_row);
+        self.header_style = props.header_style.clone();
+        self.footer_style = props.footer_style.clone();
+        self.row_style = props.row_style.clone();
+        self.stripe_style = props.stripe_style.clone();
+        self.selected_row_style = props.selected_row_style.clone().unwrap_or(TableRowStyle {
+            reverse: true,
+            ..Default::default()
+        });
+        self.border_style = props.border_style;
+        self.border_text_style = CanvasTextStyle {
+            color: props.border_color,
+            ..Default::default()
+        };
+
+        // Building the model copies every cell, so it's only rebuilt when the content changes.
+        if !self.model.matches(props) {
+            self.model = Arc::new(TableModel::new(props));
+        }
+
+        updater.set_layout_style_props(props.layout_style());
+        updater.set_measure_func({
+            let model = self.model.clone();
+            Box::new(move |known_size, available_space, _| {
+                model.measure(known_size, available_space)
+            })
+        });
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        let layout = drawer.layout();
+        let model = &*self.model;
+        let table_drawer = TableDrawer {
+            model,
+            widths: model.column_widths(Some(layout.content_box_width() as _)),
+            // Without a border, any grid lines are drawn with single lines.
+            chars: self
+                .border_style
+                .border_characters()
+                .unwrap_or_else(|| BorderStyle::Single.border_characters().unwrap()),
+            border_style: self.border_text_style.clone(),
+            x: (layout.border.left + layout.padding.left) as _,
+        };
+        let mut canvas = drawer.canvas();
+        let mut y = (layout.border.top + layout.padding.top) as isize;
+
+        if model.has_border {
+            table_drawer.draw_line(&mut canvas, y, LinePosition::Top);
+            y += 1;
+        }
+        if let Some(header) = &model.header {
+            y += table_drawer.draw_row(&mut canvas, y, header, &self.header_style) as isize;
+            table_drawer.draw_line(&mut canvas, y, LinePosition::Middle);
+            y += 1;
+        }
+        for (i, row) in model.rows.iter().enumerate() {
+            if i > 0 && model.row_dividers {
+                table_drawer.draw_line(&mut canvas, y, LinePosition::Middle);
+                y += 1;
+            }
+            let style = if self.selected_row == Some(i) {
+                &self.selected_row_style
+            } else if i % 2 == 1 {
+                self.stripe_style.as_ref().unwrap_or(&self.row_style)
+            } else {
+                &self.row_style
+            };
+            y += table_drawer.draw_row(&mut canvas, y, row, style) as isize;
+        }
+        if let Some(footer) = &model.footer {
+            table_drawer.draw_line(&mut canvas, y, LinePosition::Middle);
+            y += 1;
+            y += table_drawer.draw_row(&mut canvas, y, footer, &self.footer_style) as isize;
+        }
+        if model.has_border {
+            table_drawer.draw_line(&mut canvas, y, LinePosition::Bottom);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_events},
+    };
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn rows(rows: &[&[&str]]) -> Vec<Vec<String>> {
+        rows.iter()
+            .map(|row| row.iter().map(|cell| cell.to_string()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_junctions() {
+        assert_eq!(Junction::Down.char('│', '─'), '┬');
+        assert_eq!(Junction::Cross.char('║', '═'), '╬');
+        assert_eq!(Junction::Right.char('║', '─'), '╟');
+        assert_eq!(Junction::Left.char('│', '═'), '╡');
+        assert_eq!(Junction::Up.char('┃', '━'), '┻');
+        assert_eq!(Junction::Cross.char('|', '-'), '+');
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("hello", 5, true), "hello");
+        assert_eq!(truncate("hello world", 5, true), "hell…");
+        assert_eq!(truncate("hello world", 5, false), "hello");
+        assert_eq!(truncate("日本語", 5, true), "日本…");
+        assert_eq!(truncate("日本語", 5, false), "日本");
+        assert_eq!(truncate("hello", 0, true), "");
+    }
+
+    #[test]
+    fn test_table() {
+        let columns = vec![
+            TableColumn {
+                align: TextAlign::Right,
+                ..TableColumn::new("Id")
+            },
+            TableColumn::new("Name"),
+        ];
+        let data = rows(&[&["1", "Alice"], &["2", "Bob"], &["10", "Charlie"]]);
+
+        assert_eq!(
+            element! {
+                Table(columns: columns.clone(), rows: data.clone())
+            }
+            .to_string(),
+            indoc::indoc! {"
+                 Id  Name
+                ─────────────
+                  1  Alice
+                  2  Bob
+                 10  Charlie
+            "}
+        );
+
+        assert_eq!(
+            element! {
+                Table(
+                    columns: columns.clone(),
+                    rows: data.clone(),
+                    border_style: BorderStyle::Round,
+                    column_dividers: true,
+                    footer: vec!["".to_string(), "3 users".to_string()],
+                )
+            }
+            .to_string(),
+            indoc::indoc! {"
+                ╭────┬─────────╮
+                │ Id │ Name    │
+                ├────┼─────────┤
+                │  1 │ Alice   │
+                │  2 │ Bob     │
+                │ 10 │ Charlie │
+                ├────┼─────────┤
+                │    │ 3 users │
+                ╰────┴─────────╯
+            "}
+        );
+
+        assert_eq!(
+            element! {
+                Table(
+                    columns: columns.clone(),
+                    rows: data.clone(),
+                    border_style: BorderStyle::DoubleLeftRight,
+                    column_dividers: true,
+                    row_dividers: true,
+                    hide_header: true,
+                    cell_padding: 0,
+                )
+            }
+            .to_string(),
+            indoc::indoc! {"
+                ╓──╥───────╖
+                ║ 1║Alice  ║
+                ╟──╫───────╢
+                ║ 2║Bob    ║
+                ╟──╫───────╢
+                ║10║Charlie║
+                ╙──╨───────╜
+            "}
+        );
+    }
+
+    #[test]
+    fn test_table_column_widths() {
+        let data = rows(&[&["1", "Alice Anderson", "alice@example.com"]]);
+
+        // Flexible columns fill the space left over by the other columns.
+        assert_eq!(
+            element! {
+                View(width: 30) {
+                    Table(
+                        border_style: BorderStyle::Single,
+                        columns: vec![
+                            TableColumn { width: ColumnWidth::Fixed(2), ..TableColumn::new("#") },
+                            TableColumn { width: ColumnWidth::Flex(1.0), ..TableColumn::new("Name") },
+                            TableColumn::new("Email"),
+                        ],
+                        rows: data.clone(),
+                    )
+                }
+            }
+            .to_string(),
+            indoc::indoc! {"
+                ┌────────────────────────────┐
+                │ #   Na…  Email             │
+                ├────────────────────────────┤
+                │ 1   Al…  alice@example.com │
+                └────────────────────────────┘
+            "}
+        );
+
+        assert_eq!(
+            element! {
+                View(width: 30) {
+                    Table(
+                        columns: vec![
+                            TableColumn { width: ColumnWidth::Percent(50.0), overflow: CellOverflow::Wrap, ..TableColumn::new("Name") },
+                            TableColumn { width: ColumnWidth::Flex(1.0), overflow: CellOverflow::Clip, ..TableColumn::new("Email") },
+                        ],
+                        rows: rows(&[&["Alice Anderson", "alice@example.com"]]),
+                    )
+                }
+            }
+            .to_string(),
+            indoc::indoc! {"
+                 Name           Email
+                ──────────────────────────────
+                 Alice          alice@example
+                 Anderson
+            "}
+        );
+
+        // Columns which don't fit are shrunk, starting with the widest.
+        assert_eq!(
+            element! {
+                View(width: 24) {
+                    Table(
+                        columns: vec![
+                            TableColumn::new("Name"),
+                            TableColumn { min_width: Some(8), ..TableColumn::new("Email") },
+                        ],
+                        rows: rows(&[&["Alice Anderson", "alice@example.com"]]),
+                    )
+                }
+            }
+            .to_string(),
+            indoc::indoc! {"
+                 Name        Email
+                ────────────────────────
+                 Alice And…  alice@exa…
+            "}
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_table_selection() {
+        let table = || {
+            element! {
+                Table(
+                    has_focus: true,
+                    selected_row_style: TableRowStyle { color: Some(Color::Red), ..Default::default() },
+                    columns: vec![TableColumn::new("Name")],
+                    rows: rows(&[&["Alice"], &["Bob"], &["Charlie"]]),
+                )
+            }
+            .into_any()
+        };
+        let keys = [KeyCode::Down, KeyCode::Down, KeyCode::Down, KeyCode::Up];
+        let canvases = render_with_events(table, keys.into_iter().map(key).collect()).await;
+        let canvas = canvases.last().unwrap();
+        let mut actual = Vec::new();
+        canvas.write_ansi(&mut actual).unwrap();
+        let actual = String::from_utf8(actual).unwrap();
+        assert_eq!(
+            canvas.to_string(),
+            " Name\n─────────\n Alice\n Bob\n Charlie\n"
+        );
+        // Only Bob's row is red.
+        assert_eq!(
+            actual,
+            "\x1b[0m Name\x1b[K\r\n────────\x1b[K─\r\n Alice\x1b[K\r\n \x1b[38;5;9mBob\x1b[K\r\n \x1b[39mCharlie\x1b[K\x1b[0m\r\n"
+        );
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
-    render::MeasureFunc, segmented_string::SegmentedString, CanvasTextStyle, Color, Component,
-    ComponentDrawer, ComponentUpdater, Hooks, Props, Weight,
+    render::MeasureFunc, segmented_string::SegmentedString, unicode_linebreak::linebreaks,
+    CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Hooks, Props,
+    UnderlineStyle, Weight,
 };
+use std::sync::Arc;
 use taffy::{AvailableSpace, Size};
 use unicode_width::UnicodeWidthStr;
 
@@ -28,6 +30,10 @@ pub enum TextAlign {
 }
 
 /// The text decoration of a [`Text`] component.
+///
+/// Strikethrough and overline can be combined with any of these, and are set separately via the
+/// `strikethrough` and `overline` props.
+#[non_exhaustive]
 #[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
 pub enum TextDecoration {
     /// No text decoration. This is the default.
@@ -35,6 +41,29 @@ pub enum TextDecoration {
     None,
     /// The text is underlined.
     Underline,
+    /// The text is underlined with two lines.
+    DoubleUnderline,
+    /// The text is underlined with a wavy line.
+    CurlyUnderline,
+    /// The text is underlined with a dotted line.
+    DottedUnderline,
+    /// The text is underlined with a dashed line.
+    DashedUnderline,
+}
+
+impl TextDecoration {
+    pub(crate) fn apply(self, style: &mut CanvasTextStyle) {
+        let underline_style = match self {
+            Self::None => return,
+            Self::Underline => UnderlineStyle::Single,
+            Self::DoubleUnderline => UnderlineStyle::Double,
+            Self::CurlyUnderline => UnderlineStyle::Curly,
+            Self::DottedUnderline => UnderlineStyle::Dotted,
+            Self::DashedUnderline => UnderlineStyle::Dashed,
+        };
+        style.underline = true;
+        style.underline_style = underline_style;
+    }
 }
 
 /// The props which can be passed to the [`Text`] component.
@@ -59,8 +88,31 @@ pub struct TextProps {
     /// The text decoration.
     pub decoration: TextDecoration,
 
+    /// The color of the underline, if the text is underlined. If `None`, the text color is used.
+    pub underline_color: Option<Color>,
+
     /// Whether to italicize the text.
     pub italic: bool,
+
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Whether to draw a line above the text.
+    pub overline: bool,
+
+    /// Whether to swap the foreground and background colors of the text.
+    pub reverse: bool,
+
+    /// Whether to make the text blink.
+    pub blink: bool,
+
+    /// Whether to hide the text. The space it occupies will still be filled with the background
+    /// color.
+    pub hidden: bool,
+
+    /// The URL that the text links to, if any. In terminals that support it, the text will be
+    /// rendered as a clickable hyperlink.
+    pub link: Option<String>,
 }
 
 /// `Text` is a component that renders a text string.
@@ -112,6 +164,18 @@ impl Text {
         ret
     }
 
+    /// Returns the width of the widest run of text which can't be broken, which is the narrowest
+    /// the content can be wrapped without splitting words.
+    fn min_content_width(content: &str) -> usize {
+        let mut start = 0;
+        let mut max_width = 1;
+        for (end, _) in linebreaks(content) {
+            max_width = max_width.max(content[start..end].trim_end().width());
+            start = end;
+        }
+        max_width
+    }
+
     fn wrap(
         content: &str,
         text_wrap: TextWrap,
@@ -124,7 +188,9 @@ impl Text {
                 None => match available_width {
                     AvailableSpace::Definite(w) => Self::do_wrap(content, w as usize),
                     AvailableSpace::MaxContent => content.to_string(),
-                    AvailableSpace::MinContent => Self::do_wrap(content, 1),
+                    AvailableSpace::MinContent => {
+                        Self::do_wrap(content, Self::min_content_width(content))
+                    }
                 },
             },
             TextWrap::NoWrap => content.to_string(),
@@ -196,7 +262,9 @@ impl<'a, 'b> TextDrawer<'a, 'b> {
                     self.line_encountered_non_whitespace = true;
                 }
             }
-            self.drawer.canvas().set_text(self.x, self.y, line, style);
+            self.drawer
+                .canvas()
+                .set_text(self.x, self.y, line, style.clone());
             if lines.peek().is_some() {
                 self.y += 1;
                 self.x = 0;
@@ -224,9 +292,17 @@ impl Component for Text {
         self.style = CanvasTextStyle {
             color: props.color,
             weight: props.weight,
-            underline: props.decoration == TextDecoration::Underline,
+            underline_color: props.underline_color,
             italic: props.italic,
+            strikethrough: props.strikethrough,
+            overline: props.overline,
+            reverse: props.reverse,
+            blink: props.blink,
+            hidden: props.hidden,
+            link: props.link.as_deref().map(Arc::from),
+            ..Default::default()
         };
+        props.decoration.apply(&mut self.style);
         self.content = props.content.clone();
         self.wrap = props.wrap;
         self.align = props.align;
@@ -243,7 +319,7 @@ impl Component for Text {
         );
         let content = Self::align(content, self.align, width as _);
         let mut drawer = TextDrawer::new(drawer, self.align != TextAlign::Left);
-        drawer.append_lines(content.lines(), self.style);
+        drawer.append_lines(content.lines(), self.style.clone());
     }
 }
 
@@ -324,5 +400,70 @@ mod tests {
 
             assert_eq!(actual, expected);
         }
+
+        // Links that are wrapped should remain a single link.
+        assert_eq!(
+            element! {
+                View(width: 6) {
+                    Text(content: "foo bar baz", link: "https://example.com".to_string())
+                }
+            }
+            .to_html(),
+            "<pre><a href=\"https://example.com\">foo\nbar\nbaz</a>\n</pre>\n"
+        );
+    }
+
+    #[test]
+    fn test_text_attributes() {
+        let render = |mut e: Element<Text>| {
+            let mut actual = Vec::new();
+            e.render(None).write_ansi(&mut actual).unwrap();
+            String::from_utf8(actual).unwrap()
+        };
+
+        // Strikethrough and overline can be combined with any underline style.
+        assert_eq!(
+            render(element! {
+                Text(
+                    content: "foo",
+                    decoration: TextDecoration::CurlyUnderline,
+                    underline_color: Color::Red,
+                    strikethrough: true,
+                    overline: true,
+                )
+            }),
+            "\x1b[0m\x1b[4:3m\x1b[58;5;9m\x1b[9m\x1b[53mfo\x1b[Ko\x1b[0m\r\n"
+        );
+
+        assert_eq!(
+            render(element! {
+                Text(content: "foo", italic: true, reverse: true, blink: true, hidden: true)
+            }),
+            "\x1b[0m\x1b[3m\x1b[7m\x1b[5m\x1b[8mfo\x1b[Ko\x1b[0m\r\n"
+        );
+    }
+
+    #[test]
+    fn test_min_content_wrapping() {
+        // Min-content sizing wraps at word boundaries rather than splitting words into characters.
+        assert_eq!(
+            element! {
+                View(width: Size::MinContent) {
+                    Text(content: "foo barbaz qux")
+                }
+            }
+            .to_string(),
+            "foo\nbarbaz\nqux\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(width: Size::MinContent) {
+                    Text(content: "a well-known fact")
+                }
+            }
+            .to_string(),
+            "a\nwell-\nknown\nfact\n"
+        );
     }
 }
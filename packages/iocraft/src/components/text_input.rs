@@ -1,15 +1,16 @@
 use crate::{
     component,
-    components::{TextDrawer, TextWrap, View},
+    components::{TextDecoration, TextDrawer, TextWrap, View},
     element,
-    hooks::{UseMemo, UseState, UseTerminalEvents},
+    hooks::{UseLayout, UseMemo, UseState, UseTerminalEvents},
     segmented_string::SegmentedString,
     AnyElement, CanvasTextStyle, Color, Component, ComponentDrawer, ComponentUpdater, Handler,
-    Hook, Hooks, KeyCode, KeyEvent, KeyEventKind, LayoutStyle, Overflow, Position, Props, Size,
-    TerminalEvent,
+    Hooks, KeyCode, KeyEvent, KeyEventKind, LayoutStyle, Overflow, Position, Props, Size,
+    TerminalEvent, Weight,
 };
 use std::sync::Arc;
-use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 /// The props which can be passed to the [`TextInput`] component.
 #[non_exhaustive]
@@ -18,6 +19,41 @@ pub struct TextInputProps {
     /// The color to make the text.
     pub color: Option<Color>,
 
+    /// The weight of the text.
+    pub weight: Weight,
+
+    /// The text decoration.
+    pub decoration: TextDecoration,
+
+    /// The color of the underline, if the text is underlined. If `None`, the text color is used.
+    pub underline_color: Option<Color>,
+
+    /// Whether to italicize the text.
+    pub italic: bool,
+
+    /// Whether to draw a line through the text.
+    pub strikethrough: bool,
+
+    /// Whether to draw a line above the text.
+    pub overline: bool,
+
+    /// Whether to swap the foreground and background colors of the text.
+    pub reverse: bool,
+
+    /// Whether to make the text blink.
+    pub blink: bool,
+
+    /// Whether to hide the text. The space it occupies will still be filled with the background
+    /// color.
+    ///
+    /// The text is still written to the terminal, so use [`mask`](Self::mask) for sensitive values
+    /// such as passwords.
+    pub hidden: bool,
+
+    /// If given, each character of the value is drawn as this character instead, e.g. `'*'` for
+    /// passwords. The value itself is never written to the terminal.
+    pub mask: Option<char>,
+
     /// The current value.
     pub value: String,
 
@@ -34,28 +70,6 @@ pub struct TextInputProps {
     pub cursor_color: Option<Color>,
 }
 
-trait UseSize<'a> {
-    fn use_size(&mut self) -> (u16, u16);
-}
-
-impl<'a> UseSize<'a> for Hooks<'a, '_> {
-    fn use_size(&mut self) -> (u16, u16) {
-        self.use_hook(UseSizeImpl::default).size
-    }
-}
-
-#[derive(Default)]
-struct UseSizeImpl {
-    size: (u16, u16),
-}
-
-impl Hook for UseSizeImpl {
-    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
-        let s = drawer.size();
-        self.size = (s.width, s.height);
-    }
-}
-
 struct TextBufferRow {
     offset: usize,
     len: usize,
@@ -117,8 +131,8 @@ impl TextBuffer {
             0
         } else {
             self.text[..offset]
-                .char_indices()
-                .last()
+                .grapheme_indices(true)
+                .next_back()
                 .map_or(0, |(i, _)| i)
         }
     }
@@ -128,9 +142,9 @@ impl TextBuffer {
             self.text.len()
         } else {
             self.text[offset..]
-                .char_indices()
-                .nth(1)
-                .map_or(self.text.len(), |(i, _)| offset + i)
+                .graphemes(true)
+                .next()
+                .map_or(self.text.len(), |g| offset + g.len())
         }
     }
 
@@ -141,11 +155,11 @@ impl TextBuffer {
             row.offset + row.len
         } else {
             let mut width = 0;
-            for (idx, c) in self.text[row.offset..].char_indices() {
+            for (idx, g) in self.text[row.offset..].grapheme_indices(true) {
                 if width >= col {
                     return row.offset + idx;
                 }
-                width += c.width().unwrap_or(0);
+                width += g.width();
             }
             row.offset + row.len
         }
@@ -170,7 +184,7 @@ impl TextBuffer {
 
 #[derive(Default, Props)]
 struct TextBufferViewProps {
-    color: Option<Color>,
+    text_style: CanvasTextStyle,
     buffer: Arc<TextBuffer>,
 }
 
@@ -193,10 +207,7 @@ impl Component for TextBufferView {
         _hooks: Hooks,
         updater: &mut ComponentUpdater,
     ) {
-        self.text_style = CanvasTextStyle {
-            color: props.color,
-            ..Default::default()
-        };
+        self.text_style = props.text_style.clone();
         self.buffer = props.buffer.clone();
         updater.set_layout_style(
             LayoutStyle {
@@ -211,7 +222,7 @@ impl Component for TextBufferView {
 
     fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
         let mut drawer = TextDrawer::new(drawer, false);
-        drawer.append_lines(self.buffer.lines(), self.text_style);
+        drawer.append_lines(self.buffer.lines(), self.text_style.clone());
     }
 }
 
@@ -266,7 +277,8 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
     let mut scroll_offset_row = hooks.use_state(|| 0u16);
     let mut scroll_offset_col = hooks.use_state(|| 0u16);
     let mut vertical_movement_col_preference = hooks.use_state(|| None);
-    let (width, height) = hooks.use_size();
+    let layout = hooks.use_layout();
+    let (width, height) = (layout.width, layout.height);
 
     let max_text_width = if wrap == TextWrap::Wrap {
         // Reserve the last column for the cursor.
@@ -298,7 +310,22 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
         new_cursor_offset_hint.set(NewCursorOffsetHint::None);
     }
 
-    let (cursor_row, mut cursor_col) = buffer.row_column_for_offset(cursor_offset.get());
+    // When masked, the value is only used for editing, and the masked text is drawn instead.
+    let (display_buffer, display_cursor_offset) = match props.mask {
+        Some(mask) => (
+            Arc::new(TextBuffer::new(
+                masked(&props.value, mask),
+                max_text_width as _,
+            )),
+            masked(
+                props.value.get(..cursor_offset.get()).unwrap_or_default(),
+                mask,
+            )
+            .len(),
+        ),
+        None => (buffer.clone(), cursor_offset.get()),
+    };
+    let (cursor_row, mut cursor_col) = display_buffer.row_column_for_offset(display_cursor_offset);
 
     // If we're wrapping, don't let the cursor go past the visible area. No non-whitespace
     // characters will extend that far.
@@ -343,20 +370,22 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
                             on_change(value.clone());
                         }
                         KeyCode::Backspace => {
-                            if temp_cursor_offset > 0 {
-                                temp_cursor_offset -= value[..temp_cursor_offset]
-                                    .chars()
-                                    .last()
-                                    .unwrap()
-                                    .len_utf8();
-                                value.remove(temp_cursor_offset);
+                            // Remove the whole grapheme cluster before the cursor.
+                            if let Some((start, _)) = value[..temp_cursor_offset]
+                                .grapheme_indices(true)
+                                .next_back()
+                            {
+                                value.replace_range(start..temp_cursor_offset, "");
+                                temp_cursor_offset = start;
                             }
                             on_change(value.clone());
                             new_cursor_offset_hint.set(NewCursorOffsetHint::Backspace);
                         }
                         KeyCode::Delete => {
-                            if temp_cursor_offset < value.len() {
-                                value.remove(temp_cursor_offset);
+                            // Remove the whole grapheme cluster after the cursor.
+                            if let Some(g) = value[temp_cursor_offset..].graphemes(true).next() {
+                                let end = temp_cursor_offset + g.len();
+                                value.replace_range(temp_cursor_offset..end, "");
                             }
                             on_change(value.clone());
                             new_cursor_offset_hint.set(NewCursorOffsetHint::Deletion);
@@ -408,6 +437,20 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
         }
     });
 
+    let mut text_style = CanvasTextStyle {
+        color: props.color,
+        weight: props.weight,
+        underline_color: props.underline_color,
+        italic: props.italic,
+        strikethrough: props.strikethrough,
+        overline: props.overline,
+        reverse: props.reverse,
+        blink: props.blink,
+        hidden: props.hidden,
+        ..Default::default()
+    };
+    props.decoration.apply(&mut text_style);
+
     element! {
         View(overflow: Overflow::Hidden, width: 100pct, height: if multiline { Size::Percent(100.0) } else { Size::Length(1) }, position: Position::Relative) {
             View(position: Position::Absolute, top: -(scroll_offset_row.get() as i32), left: -(scroll_offset_col.get() as i32)) {
@@ -419,14 +462,21 @@ pub fn TextInput(mut hooks: Hooks, props: &mut TextInputProps) -> impl Into<AnyE
                     None
                 })
                 TextBufferView(
-                    buffer,
-                    color: props.color,
+                    buffer: display_buffer,
+                    text_style,
                 )
             }
         }
     }
 }
 
+/// Replaces each grapheme cluster of the text with the mask, keeping line breaks.
+fn masked(text: &str, mask: char) -> String {
+    text.graphemes(true)
+        .map(|g| if g.contains('\n') { '\n' } else { mask })
+        .collect()
+}
+
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 enum NewCursorOffsetHint {
     #[default]
@@ -573,6 +623,28 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[apply(test!)]
+    async fn test_text_input_grapheme_clusters() {
+        let actual = element! {
+            MyComponent(initial_value: "a🇺🇸e\u{301}")
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::with_events(futures::stream::iter(
+            vec![
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Release, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Release, KeyCode::Backspace)),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, KeyCode::Char('!'))),
+                TerminalEvent::Key(KeyEvent::new(KeyEventKind::Release, KeyCode::Char('!'))),
+            ],
+        )))
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        let expected = vec![" a🇺🇸e\u{301} \n", " a! \n"];
+        assert_eq!(actual, expected);
+    }
+
     #[apply(test!)]
     async fn test_text_input_overflow() {
         let actual = element!(MyComponent)
@@ -660,6 +732,35 @@ mod tests {
         assert_eq!(buffer.above_offset(5, Some(6)), 3);
     }
 
+    #[test]
+    fn test_text_buffer_grapheme_cluster_movement() {
+        // "a", a flag, "e" with a combining accent, and a family emoji.
+        let text = "a🇺🇸e\u{301}👨\u{200d}👩\u{200d}👧\nb";
+        let buffer = TextBuffer::new(text, 20);
+        assert_eq!(buffer.right_of_offset(1), 9);
+        assert_eq!(buffer.right_of_offset(9), 12);
+        assert_eq!(buffer.right_of_offset(12), 30);
+        assert_eq!(buffer.left_of_offset(30), 12);
+        assert_eq!(buffer.left_of_offset(12), 9);
+        assert_eq!(buffer.left_of_offset(9), 1);
+        assert_eq!(buffer.row_column_for_offset(30), (0, 6));
+        assert_eq!(buffer.above_offset(32, Some(4)), 12);
+    }
+
+    #[test]
+    fn test_masked() {
+        assert_eq!(masked("a🇺🇸e\u{301}一\nb", '*'), "****\n*");
+        assert_eq!(
+            element! {
+                View(width: 10) {
+                    TextInput(value: "hunter2", mask: '*')
+                }
+            }
+            .to_string(),
+            "*******\n"
+        );
+    }
+
     #[test]
     fn test_test_buffer_row_column_for_offset() {
         assert_eq!(
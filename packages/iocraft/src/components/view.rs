@@ -152,7 +152,8 @@ pub struct ViewProps<'a> {
     /// The edges to render the border on. By default, the border will be rendered on all edges.
     pub border_edges: Option<Edges>,
 
-    /// The color of the background.
+    /// The color of the background. If set, even to [`Color::Reset`], the view will hide anything
+    /// drawn beneath it.
     pub background_color: Option<Color>,
 }
 
@@ -198,34 +199,36 @@ impl Component for View {
         };
         self.border_edges = props.border_edges.unwrap_or(Edges::all());
         self.background_color = props.background_color;
-        let mut style: taffy::style::Style = props.layout_style().into();
-        style.border = if self.border_style.is_none() {
-            Rect::zero()
-        } else {
-            Rect {
-                top: LengthPercentage::Length(if self.border_edges.contains(Edges::Top) {
-                    1.0
-                } else {
-                    0.0
-                }),
-                bottom: LengthPercentage::Length(if self.border_edges.contains(Edges::Bottom) {
-                    1.0
-                } else {
-                    0.0
-                }),
-                left: LengthPercentage::Length(if self.border_edges.contains(Edges::Left) {
-                    1.0
-                } else {
-                    0.0
-                }),
-                right: LengthPercentage::Length(if self.border_edges.contains(Edges::Right) {
-                    1.0
-                } else {
-                    0.0
-                }),
-            }
-        };
-        updater.set_layout_style(style);
+        updater.set_layout_style_props_with(props.layout_style(), |style| {
+            style.border = if self.border_style.is_none() {
+                Rect::zero()
+            } else {
+                Rect {
+                    top: LengthPercentage::Length(if self.border_edges.contains(Edges::Top) {
+                        1.0
+                    } else {
+                        0.0
+                    }),
+                    bottom: LengthPercentage::Length(
+                        if self.border_edges.contains(Edges::Bottom) {
+                            1.0
+                        } else {
+                            0.0
+                        },
+                    ),
+                    left: LengthPercentage::Length(if self.border_edges.contains(Edges::Left) {
+                        1.0
+                    } else {
+                        0.0
+                    }),
+                    right: LengthPercentage::Length(if self.border_edges.contains(Edges::Right) {
+                        1.0
+                    } else {
+                        0.0
+                    }),
+                }
+            };
+        });
         updater.update_children(props.children.iter_mut(), None);
     }
 
@@ -274,35 +277,50 @@ impl Component for View {
 
             if self.border_edges.contains(Edges::Top) {
                 if self.border_edges.contains(Edges::Left) {
-                    canvas.set_text(0, 0, &border.top_left.to_string(), self.border_text_style);
+                    canvas.set_text(
+                        0,
+                        0,
+                        &border.top_left.to_string(),
+                        self.border_text_style.clone(),
+                    );
                 }
 
                 let top = border
                     .top
                     .to_string()
                     .repeat(layout.size.width as usize - left_border_size - right_border_size);
-                canvas.set_text(left_border_size as _, 0, &top, self.border_text_style);
+                canvas.set_text(
+                    left_border_size as _,
+                    0,
+                    &top,
+                    self.border_text_style.clone(),
+                );
 
                 if self.border_edges.contains(Edges::Right) {
                     canvas.set_text(
                         layout.size.width as isize - 1,
                         0,
                         &border.top_right.to_string(),
-                        self.border_text_style,
+                        self.border_text_style.clone(),
                     );
                 }
             }
 
             for y in top_border_size..(layout.size.height as isize - bottom_border_size) {
                 if self.border_edges.contains(Edges::Left) {
-                    canvas.set_text(0, y, &border.left.to_string(), self.border_text_style);
+                    canvas.set_text(
+                        0,
+                        y,
+                        &border.left.to_string(),
+                        self.border_text_style.clone(),
+                    );
                 }
                 if self.border_edges.contains(Edges::Right) {
                     canvas.set_text(
                         layout.size.width as isize - 1,
                         y,
                         &border.right.to_string(),
-                        self.border_text_style,
+                        self.border_text_style.clone(),
                     );
                 }
             }
@@ -313,7 +331,7 @@ impl Component for View {
                         0,
                         layout.size.height as isize - 1,
                         &border.bottom_left.to_string(),
-                        self.border_text_style,
+                        self.border_text_style.clone(),
                     );
                 }
 
@@ -325,7 +343,7 @@ impl Component for View {
                     left_border_size as _,
                     layout.size.height as isize - 1,
                     &bottom,
-                    self.border_text_style,
+                    self.border_text_style.clone(),
                 );
 
                 if self.border_edges.contains(Edges::Right) {
@@ -333,7 +351,7 @@ impl Component for View {
                         layout.size.width as isize - 1,
                         layout.size.height as isize - 1,
                         &border.bottom_right.to_string(),
-                        self.border_text_style,
+                        self.border_text_style.clone(),
                     );
                 }
             }
@@ -687,4 +705,106 @@ mod tests {
             "},
         );
     }
+
+    #[test]
+    fn test_view_z_index() {
+        // Without a z-index, later siblings are drawn on top.
+        assert_eq!(
+            element! {
+                View(width: 8, height: 2) {
+                    View(position: Position::Absolute, background_color: Color::Reset) {
+                        Text(content: "aaaa")
+                    }
+                    View(position: Position::Absolute, left: 2, background_color: Color::Reset) {
+                        Text(content: "bb")
+                    }
+                }
+            }
+            .to_string(),
+            "aabb\n\n"
+        );
+
+        // A nested element with a higher z-index is drawn on top of everything in lower layers,
+        // even if they come later in the tree. Elements with a negative z-index are drawn below
+        // everything else.
+        assert_eq!(
+            element! {
+                View(width: 8, height: 3) {
+                    View(position: Position::Absolute, top: 0, left: 0, z_index: -1) {
+                        Text(content: "zzzzzzzz")
+                    }
+                    View(position: Position::Absolute, top: 0, left: 0) {
+                        View(position: Position::Absolute, top: 1, left: 1, z_index: 1, background_color: Color::Reset) {
+                            Text(content: "popover")
+                        }
+                    }
+                    View(position: Position::Absolute, top: 1, left: 0, background_color: Color::Reset) {
+                        Text(content: "covered")
+                    }
+                    View(position: Position::Absolute, top: 0, left: 2, width: 2, height: 1, background_color: Color::Reset)
+                }
+            }
+            .to_string(),
+            "zz  zzzz\ncpopover\n\n"
+        );
+
+        // Wide characters partially covered by an element above them are hidden.
+        assert_eq!(
+            element! {
+                View(width: 8, height: 1) {
+                    View(position: Position::Absolute) {
+                        Text(content: "一二三四")
+                    }
+                    View(position: Position::Absolute, left: 3, height: 1, z_index: 1, border_style: BorderStyle::Single, border_edges: Edges::Left)
+                    View(position: Position::Absolute, left: 5, width: 1, height: 1, background_color: Color::Reset)
+                }
+            }
+            .to_string(),
+            "一 │  四\n"
+        );
+    }
+
+    #[test]
+    fn test_view_grid() {
+        assert_eq!(
+            element! {
+                View(
+                    display: Display::Grid,
+                    width: 12,
+                    grid_template_columns: [4, 1fr, 2fr],
+                    grid_auto_rows: vec![GridTrack::Length(1)],
+                ) {
+                    Text(content: "a")
+                    Text(content: "b")
+                    Text(content: "c")
+                    // Explicitly placed items are placed first, then the rest flow around them.
+                    View(grid_column: 2..4) {
+                        Text(content: "wide")
+                    }
+                    View(grid_row: 1, grid_column: GridPlacement::Line(-2)) {
+                        Text(content: "!")
+                    }
+                }
+            }
+            .to_string(),
+            "a   b  !\nc   wide\n"
+        );
+
+        assert_eq!(
+            element! {
+                View(
+                    display: Display::Grid,
+                    grid_auto_flow: GridAutoFlow::Column,
+                    grid_template_rows: vec![GridTrack::repeat(2, [1])],
+                    column_gap: 1,
+                ) {
+                    Text(content: "a")
+                    Text(content: "b")
+                    Text(content: "c")
+                }
+            }
+            .to_string(),
+            "a c\nb\n"
+        );
+    }
 }
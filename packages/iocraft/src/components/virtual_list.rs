@@ -0,0 +1,580 @@
+use super::scroll_view::ScrollbarStyle;
+use crate::{
+    components::View,
+    element,
+    hooks::{UseLayout, UseState, UseTerminalEvents},
+    AnyElement, Color, Component, ComponentDrawer, ComponentUpdater, FlexDirection,
+    FullscreenMouseEvent, Handler, Hooks, KeyCode, KeyEvent, KeyEventKind, MouseEventKind,
+    Overflow, Props, Size, TerminalEvent,
+};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use iocraft_macros::with_layout_style_props;
+use std::sync::{Arc, Mutex};
+use taffy::Point;
+
+/// The height of the items in a [`VirtualList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ItemHeight {
+    /// Every item is exactly this many rows tall. Items which render taller content are clipped.
+    /// Items can't be less than one row tall, so zero is treated as one.
+    Fixed(u16),
+    /// Items are expected to be about this many rows tall. Items are measured as they're rendered,
+    /// and the estimate is only used for items which haven't been rendered yet. As with
+    /// [`Fixed`](Self::Fixed), an estimate of zero is treated as one.
+    Estimated(u16),
+}
+
+impl Default for ItemHeight {
+    fn default() -> Self {
+        Self::Fixed(1)
+    }
+}
+
+/// An item to be rendered by a [`VirtualList`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VirtualListItem {
+    /// The index of the item.
+    pub index: usize,
+    /// True if the item is the selected one.
+    pub is_selected: bool,
+}
+
+/// `ItemRenderer` is the type of function used by [`VirtualList`] to render its items.
+///
+/// Any function that takes a [`VirtualListItem`] and returns an [`AnyElement`] can be converted
+/// into an `ItemRenderer`.
+pub struct ItemRenderer<'a>(
+    Box<dyn FnMut(VirtualListItem) -> AnyElement<'static> + Send + Sync + 'a>,
+);
+
+impl ItemRenderer<'_> {
+    fn render(&mut self, item: VirtualListItem) -> AnyElement<'static> {
+        (self.0)(item)
+    }
+}
+
+impl Default for ItemRenderer<'_> {
+    fn default() -> Self {
+        Self(Box::new(|_| element!(View).into_any()))
+    }
+}
+
+impl<'a, F> From<F> for ItemRenderer<'a>
+where
+    F: FnMut(VirtualListItem) -> AnyElement<'static> + Send + Sync + 'a,
+{
+    fn from(f: F) -> Self {
+        Self(Box::new(f))
+    }
+}
+
+/// The props which can be passed to the [`VirtualList`] component.
+#[non_exhaustive]
+#[with_layout_style_props]
+#[derive(Default, Props)]
+pub struct VirtualListProps<'a> {
+    /// The number of items in the list.
+    pub item_count: usize,
+
+    /// The height of the items.
+    pub item_height: ItemHeight,
+
+    /// The function used to render each item. It's only invoked for the items which are visible,
+    /// plus the [`overscan`](Self::overscan).
+    pub render_item: ItemRenderer<'a>,
+
+    /// The number of items above and below the visible ones to render.
+    pub overscan: usize,
+
+    /// True if the list has focus and should process keyboard input.
+    ///
+    /// The Up and Down keys move the selection by one item, Page Up and Page Down move it by a
+    /// page, and Home and End move it to the first and last items. The list is scrolled to keep the
+    /// selected item visible. Regardless of focus, the list can be scrolled via the mouse wheel
+    /// while in fullscreen mode.
+    pub has_focus: bool,
+
+    /// The index of the selected item. If `Some`, the selection is controlled by the parent, which
+    /// should update it in response to [`on_select`](Self::on_select). If `None`, the list keeps
+    /// track of the selection itself, starting with the first item.
+    pub selected_index: Option<usize>,
+
+    /// The handler to invoke when the user changes the selection.
+    pub on_select: Handler<'static, usize>,
+
+    /// If true, a scrollbar is drawn along the right edge.
+    pub show_scrollbar: bool,
+
+    /// The color of the scrollbar track. Defaults to dark gray.
+    pub scrollbar_color: Option<Color>,
+
+    /// The color of the scrollbar thumb. If `None`, the default text color is used.
+    pub scrollbar_thumb_color: Option<Color>,
+}
+
+#[derive(Default)]
+struct ItemHeights {
+    estimate: u16,
+    // The measured height of each item, or `None` if the item hasn't been measured.
+    measured: Vec<Option<u16>>,
+    // A Fenwick tree of the differences between the measured heights and the estimate, so that
+    // offsets can be computed in logarithmic time.
+    adjustments: Vec<i64>,
+}
+
+impl ItemHeights {
+    fn clear(&mut self) {
+        self.measured.clear();
+        self.adjustments.clear();
+    }
+
+    /// Forgets the heights of the items at or after the given index.
+    fn truncate(&mut self, len: usize) {
+        // Each node of the tree only covers items before it, so the tree remains valid.
+        self.measured.truncate(len);
+        self.adjustments.truncate(len);
+    }
+
+    fn height_of(&self, index: usize) -> u32 {
+        self.measured
+            .get(index)
+            .copied()
+            .flatten()
+            .unwrap_or(self.estimate) as _
+    }
+
+    /// Records the measured height of an item, returning true if it changed.
+    fn set_measured(&mut self, index: usize, height: u16) -> bool {
+        while self.measured.len() <= index {
+            // The new node covers the unmeasured item being added plus the items before it
+            // which aren't covered by the preceding nodes.
+            let node = self.measured.len() + 1;
+            let sum = self.adjustment_before(node - 1) - self.adjustment_before(node & (node - 1));
+            self.measured.push(None);
+            self.adjustments.push(sum);
+        }
+        if self.measured[index] == Some(height) {
+            return false;
+        }
+        let delta = height as i64 - self.height_of(index) as i64;
+        self.measured[index] = Some(height);
+        let mut node = index + 1;
+        while node <= self.adjustments.len() {
+            self.adjustments[node - 1] += delta;
+            node += node & node.wrapping_neg();
+        }
+        true
+    }
+
+    /// Returns the sum of the adjustments for the items before the given index.
+    fn adjustment_before(&self, index: usize) -> i64 {
+        let mut node = index.min(self.adjustments.len());
+        let mut sum = 0;
+        while node > 0 {
+            sum += self.adjustments[node - 1];
+            node &= node - 1;
+        }
+        sum
+    }
+
+    fn offset_of(&self, index: usize) -> u32 {
+        let adjustment = self.adjustment_before(index);
+        (index as i64 * self.estimate as i64 + adjustment).clamp(0, u32::MAX as _) as _
+    }
+
+    /// Returns the index of the item which contains the given offset.
+    fn index_at(&self, offset: u32, item_count: usize) -> usize {
+        let (mut low, mut high) = (0, item_count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.offset_of(mid + 1) <= offset {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low.min(item_count.saturating_sub(1))
+    }
+}
+
+/// `VirtualList` is a component which efficiently displays a very large number of items by only
+/// rendering the ones which are visible.
+///
+/// The list should be given a size, either directly or by its parent's layout. As with
+/// [`use_layout`](crate::hooks::UseLayout::use_layout), the visible items are determined by the
+/// layout of the previous render, so the list only renders its first item until it's been laid
+/// out.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # fn foo() -> impl Into<AnyElement<'static>> {
+/// element! {
+///     VirtualList(
+///         height: 10,
+///         item_count: 100_000usize,
+///         has_focus: true,
+///         render_item: |item: VirtualListItem| element! {
+///             Text(
+///                 content: format!("Item {}", item.index),
+///                 color: if item.is_selected { Some(Color::Blue) } else { None },
+///             )
+///         }.into_any(),
+///     )
+/// }
+/// # }
+/// ```
+#[derive(Default)]
+pub struct VirtualList {
+    heights: Arc<Mutex<ItemHeights>>,
+    is_estimated: bool,
+    last_selected_index: Option<usize>,
+    is_selected_item_pinned: bool,
+    first_rendered_index: usize,
+    scroll_offset: u32,
+    max_scroll_offset: u32,
+    scrollbar_style: ScrollbarStyle,
+    did_measure_new_heights: bool,
+    waker: Option<Waker>,
+}
+
+impl Component for VirtualList {
+    type Props<'a> = VirtualListProps<'a>;
+
+    fn new(_props: &Self::Props<'_>) -> Self {
+        Default::default()
+    }
+
+    fn update(
+        &mut self,
+        props: &mut Self::Props<'_>,
+        mut hooks: Hooks,
+        updater: &mut ComponentUpdater,
+    ) {
+        self.scrollbar_style =
+            ScrollbarStyle::new(props.scrollbar_color, props.scrollbar_thumb_color);
+
+        let item_count = props.item_count;
+        let (is_estimated, estimate) = match props.item_height {
+            ItemHeight::Fixed(h) => (false, h),
+            ItemHeight::Estimated(h) => (true, h),
+        };
+        let estimate = estimate.max(1);
+        {
+            let mut heights = self.heights.lock().unwrap();
+            if heights.estimate != estimate || !is_estimated {
+                heights.clear();
+            }
+            heights.estimate = estimate;
+            heights.truncate(item_count);
+        }
+        self.is_estimated = is_estimated;
+
+        let viewport_height = hooks.use_layout().content_height as u32;
+        let mut scroll_offset = hooks.use_state(|| 0u32);
+        let mut selected_index_state = hooks.use_state(|| 0usize);
+        let is_controlled = props.selected_index.is_some();
+        let selected_index = props
+            .selected_index
+            .unwrap_or_else(|| selected_index_state.get())
+            .min(item_count.saturating_sub(1));
+
+        let heights = self.heights.lock().unwrap();
+        let max_scroll_offset = heights
+            .offset_of(item_count)
+            .saturating_sub(viewport_height);
+
+        hooks.use_local_terminal_events({
+            let mut on_select = props.on_select.take();
+            let has_focus = props.has_focus;
+            let page_len = (viewport_height / estimate as u32).max(1) as usize;
+            // Subsequent events may be handled before the next render, so they need to build on
+            // this one.
+            let mut selected_index = selected_index;
+            move |event| match event {
+                TerminalEvent::Key(KeyEvent { code, kind, .. })
+                    if has_focus && kind != KeyEventKind::Release && item_count > 0 =>
+                {
+                    let last_index = item_count - 1;
+                    let new_index = match code {
+                        KeyCode::Up => selected_index.saturating_sub(1),
+                        KeyCode::Down => (selected_index + 1).min(last_index),
+                        KeyCode::PageUp => selected_index.saturating_sub(page_len),
+                        KeyCode::PageDown => (selected_index + page_len).min(last_index),
+                        KeyCode::Home => 0,
+                        KeyCode::End => last_index,
+                        _ => return,
+                    };
+                    if new_index != selected_index {
+                        selected_index = new_index;
+                        if !is_controlled {
+                            selected_index_state.set(new_index);
+                        }
+                        on_select(new_index);
+                    }
+                }
+                TerminalEvent::FullscreenMouse(FullscreenMouseEvent { kind, .. }) => match kind {
+                    MouseEventKind::ScrollUp => {
+                        scroll_offset.set(scroll_offset.get().saturating_sub(1));
+                    }
+                    MouseEventKind::ScrollDown => {
+                        scroll_offset.set((scroll_offset.get() + 1).min(max_scroll_offset));
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        });
+
+        // When the selection changes, the selected item is kept visible, even as items are
+        // measured, until the user scrolls away from it.
+        if self.last_selected_index != Some(selected_index) {
+            self.last_selected_index = Some(selected_index);
+            self.is_selected_item_pinned = true;
+        } else if scroll_offset.get() != self.scroll_offset {
+            self.is_selected_item_pinned = false;
+        }
+
+        let mut offset = scroll_offset.get().min(max_scroll_offset);
+        if self.is_selected_item_pinned && viewport_height > 0 && item_count > 0 {
+            let top = heights.offset_of(selected_index);
+            let bottom = top + heights.height_of(selected_index);
+            if top < offset {
+                offset = top;
+            } else if bottom > offset + viewport_height {
+                offset = (bottom - viewport_height).min(top);
+            }
+        }
+        if offset != scroll_offset.get() {
+            scroll_offset.set(offset);
+        }
+
+        let (first_index, end_index) = if item_count == 0 {
+            (0, 0)
+        } else {
+            let first_visible = heights.index_at(offset, item_count);
+            let last_visible =
+                heights.index_at(offset + viewport_height.saturating_sub(1), item_count);
+            (
+                first_visible.saturating_sub(props.overscan),
+                (last_visible + 1 + props.overscan).min(item_count),
+            )
+        };
+        updater.set_scroll_offset(Point {
+            x: 0,
+            y: (offset - heights.offset_of(first_index)).min(u16::MAX as _) as _,
+        });
+        drop(heights);
+
+        self.first_rendered_index = first_index;
+        self.scroll_offset = offset;
+        self.max_scroll_offset = max_scroll_offset;
+
+        updater.set_layout_style_props_with(props.layout_style(), |style| {
+            style.flex_direction = FlexDirection::Column;
+            style.overflow = Point {
+                x: Overflow::Hidden,
+                y: Overflow::Scroll,
+            };
+            style.scrollbar_width = if props.show_scrollbar { 1.0 } else { 0.0 };
+        });
+
+        let item_height = if is_estimated {
+            Size::Auto
+        } else {
+            Size::Length(estimate as _)
+        };
+        let item_overflow = if is_estimated {
+            None
+        } else {
+            Some(Overflow::Hidden)
+        };
+        let mut items = (first_index..end_index)
+            .map(|index| {
+                let item = props.render_item.render(VirtualListItem {
+                    index,
+                    is_selected: index == selected_index,
+                });
+                element! {
+                    View(key: index, flex_shrink: 0.0, height: item_height, overflow: item_overflow) {
+                        #(item)
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        updater.update_children(items.iter_mut(), None);
+    }
+
+    fn draw(&mut self, drawer: &mut ComponentDrawer<'_>) {
+        if self.is_estimated {
+            let mut heights = self.heights.lock().unwrap();
+            for (i, layout) in drawer.child_layouts().into_iter().enumerate() {
+                let index = self.first_rendered_index + i;
+                let height = layout.size.height as u16;
+                if heights.set_measured(index, height) {
+                    self.did_measure_new_heights = true;
+                }
+            }
+            if self.did_measure_new_heights {
+                if let Some(waker) = self.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+
+        let layout = drawer.layout();
+        if layout.scrollbar_size.width > 0.0 {
+            let mut canvas = drawer.canvas();
+            self.scrollbar_style.draw_vertical(
+                &mut canvas,
+                (layout.size.width - layout.border.right - layout.scrollbar_size.width) as _,
+                layout.border.top as _,
+                (layout.size.height
+                    - layout.border.top
+                    - layout.border.bottom
+                    - layout.scrollbar_size.height) as _,
+                self.max_scroll_offset,
+                self.scroll_offset,
+            );
+        }
+    }
+
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.did_measure_new_heights {
+            self.did_measure_new_heights = false;
+            Poll::Ready(())
+        } else {
+            self.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_with_events},
+    };
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[test]
+    fn test_item_heights() {
+        let mut heights = ItemHeights {
+            estimate: 2,
+            ..Default::default()
+        };
+        assert_eq!(heights.offset_of(0), 0);
+        assert_eq!(heights.offset_of(10), 20);
+        assert_eq!(heights.index_at(0, 100), 0);
+        assert_eq!(heights.index_at(1, 100), 0);
+        assert_eq!(heights.index_at(2, 100), 1);
+        assert_eq!(heights.index_at(1000, 100), 99);
+        assert_eq!(heights.index_at(0, 0), 0);
+
+        assert!(heights.set_measured(1, 5));
+        assert!(heights.set_measured(2, 1));
+        assert!(!heights.set_measured(2, 1));
+        assert_eq!(heights.offset_of(1), 2);
+        assert_eq!(heights.offset_of(2), 7);
+        assert_eq!(heights.offset_of(3), 8);
+        assert_eq!(heights.offset_of(4), 10);
+        assert_eq!(heights.index_at(6, 100), 1);
+        assert_eq!(heights.index_at(7, 100), 2);
+        assert_eq!(heights.index_at(8, 100), 3);
+        assert_eq!(heights.height_of(1), 5);
+        assert_eq!(heights.height_of(3), 2);
+
+        // Measuring items far past the others extends the tree without disturbing it.
+        assert!(heights.set_measured(1000, 6));
+        assert_eq!(heights.offset_of(4), 10);
+        assert_eq!(heights.offset_of(1000), 2002);
+        assert_eq!(heights.offset_of(1001), 2008);
+        assert_eq!(heights.offset_of(5000), 10006);
+        assert!(heights.set_measured(1, 2));
+        assert_eq!(heights.offset_of(1001), 2005);
+
+        heights.truncate(2);
+        assert_eq!(heights.offset_of(1001), 2002);
+        assert_eq!(heights.height_of(2), 2);
+    }
+
+    async fn render_with_key_presses(keys: Vec<KeyCode>, item_height: ItemHeight) -> String {
+        let list = move || {
+            element! {
+                VirtualList(
+                    width: 8,
+                    height: 3,
+                    item_count: 100_000usize,
+                    item_height,
+                    has_focus: true,
+                    show_scrollbar: true,
+                    render_item: move |item: VirtualListItem| {
+                        let marker = if item.is_selected { ">" } else { " " };
+                        let content = match item_height {
+                            // Every other item is two lines tall.
+                            ItemHeight::Estimated(_) if item.index % 2 == 1 => {
+                                format!("{}{}\n {}", marker, item.index, item.index)
+                            }
+                            _ => format!("{}{}", marker, item.index),
+                        };
+                        element!(Text(content)).into_any()
+                    },
+                )
+            }
+            .into_any()
+        };
+        render_with_events(list, keys.into_iter().map(key).collect())
+            .await
+            .pop()
+            .unwrap()
+            .to_string()
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list() {
+        let fixed = ItemHeight::default();
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Up], fixed).await,
+            ">0     █\n 1     │\n 2     │\n"
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Down; 4], fixed).await,
+            " 2     █\n 3     │\n>4     │\n"
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::End], fixed).await,
+            " 99997 │\n 99998 │\n>99999 █\n"
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::End, KeyCode::Up, KeyCode::PageUp], fixed).await,
+            " 99993 │\n 99994 │\n>99995 █\n"
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_virtual_list_estimated_heights() {
+        let estimated = ItemHeight::Estimated(1);
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::Down; 2], estimated).await,
+            " 1     █\n 1     │\n>2     │\n"
+        );
+
+        assert_eq!(
+            render_with_key_presses(vec![KeyCode::End], estimated).await,
+            " 99998 │\n>99999 │\n 99999 █\n"
+        );
+    }
+}
@@ -25,6 +25,12 @@ impl SystemContext {
     }
 }
 
+/// Provides the size of the terminal that components are being rendered to, if known.
+///
+/// This is kept separate from [`SystemContext`] so that it's still available to hooks while a
+/// component holds a mutable reference to the system context.
+pub(crate) struct TerminalSizeContext(pub Option<(u16, u16)>);
+
 /// A context that can be passed to components.
 pub enum Context<'a> {
     /// Provides the context via a mutable reference. Children will be able to get mutable or
@@ -35,7 +41,7 @@ pub enum Context<'a> {
     Ref(&'a (dyn Any + Send + Sync)),
     /// Provides the context via an owned value. Children will be able to get mutable or immutable
     /// references to the context.
-    Owned(Box<(dyn Any + Send + Sync)>),
+    Owned(Box<dyn Any + Send + Sync>),
 }
 
 impl<'a> Context<'a> {
@@ -76,7 +82,7 @@ impl<'a> Context<'a> {
     }
 
     #[doc(hidden)]
-    pub fn borrow(&mut self) -> Context {
+    pub fn borrow(&mut self) -> Context<'_> {
         match self {
             Context::Mut(context) => Context::Mut(*context),
             Context::Ref(context) => Context::Ref(*context),
@@ -117,7 +123,7 @@ impl<'a> ContextStack<'a> {
         }
     }
 
-    pub fn get_context<T: Any>(&self) -> Option<Ref<T>> {
+    pub fn get_context<T: Any>(&self) -> Option<Ref<'_, T>> {
         for context in self.contexts.iter().rev() {
             if let Ok(context) = context.try_borrow() {
                 if let Ok(ret) = Ref::filter_map(context, |context| context.downcast_ref::<T>()) {
@@ -128,7 +134,7 @@ impl<'a> ContextStack<'a> {
         None
     }
 
-    pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<T>> {
+    pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<'_, T>> {
         for context in self.contexts.iter().rev() {
             if let Ok(context) = context.try_borrow_mut() {
                 if let Ok(ret) = RefMut::filter_map(context, |context| context.downcast_mut::<T>())
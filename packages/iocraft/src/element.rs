@@ -2,7 +2,7 @@ use crate::{
     component::{Component, ComponentHelper, ComponentHelperExt},
     mock_terminal_render_loop,
     props::AnyProps,
-    render, terminal_render_loop, Canvas, MockTerminalConfig, Terminal,
+    render, terminal_render_loop, Canvas, ColorDepth, MockTerminalConfig, Terminal,
 };
 use any_key::AnyHash;
 use crossterm::terminal;
@@ -157,7 +157,7 @@ pub trait ElementExt: private::Sealed + Sized {
     fn key(&self) -> &ElementKey;
 
     #[doc(hidden)]
-    fn props_mut(&mut self) -> AnyProps;
+    fn props_mut(&mut self) -> AnyProps<'_>;
 
     #[doc(hidden)]
     fn helper(&self) -> Box<dyn ComponentHelperExt>;
@@ -174,7 +174,17 @@ pub trait ElementExt: private::Sealed + Sized {
         self.render(None).to_string()
     }
 
+    /// Renders the element into an HTML `<pre>` element. See [`Canvas::write_html`] for details.
+    fn to_html(&mut self) -> String {
+        let mut buf = Vec::new();
+        self.render(None).write_html(&mut buf).unwrap();
+        String::from_utf8_lossy(&buf).into_owned()
+    }
+
     /// Renders the element and prints it to stdout.
+    ///
+    /// To render with a specific color depth, pass [`stdout`] to
+    /// [`ElementExt::write_to_is_terminal_with_color_depth`] instead.
     fn print(&mut self) {
         self.write_to_is_terminal(stdout()).unwrap();
     }
@@ -191,14 +201,26 @@ pub trait ElementExt: private::Sealed + Sized {
     }
 
     /// Renders the element and writes it to the given raw file descriptor. If the file descriptor
-    /// is a TTY, the canvas will be rendered based on its size, with ANSI escape codes.
+    /// is a TTY, the canvas will be rendered based on its size, with ANSI escape codes using the
+    /// color depth detected via [`ColorDepth::detect`].
     #[cfg(unix)]
     fn write_to_raw_fd<F: Write + std::os::fd::AsRawFd>(&mut self, fd: F) -> io::Result<()> {
+        self.write_to_raw_fd_with_color_depth(fd, ColorDepth::detect())
+    }
+
+    /// Like [`ElementExt::write_to_raw_fd`], but uses the given color depth instead of detecting
+    /// it.
+    #[cfg(unix)]
+    fn write_to_raw_fd_with_color_depth<F: Write + std::os::fd::AsRawFd>(
+        &mut self,
+        fd: F,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
         use crossterm::tty::IsTty;
         if fd.is_tty() {
             let (width, _) = terminal::size()?;
             let canvas = self.render(Some(width as _));
-            canvas.write_ansi(fd)
+            canvas.write_ansi_with_color_depth(fd, color_depth)
         } else {
             self.write(fd)
         }
@@ -206,12 +228,23 @@ pub trait ElementExt: private::Sealed + Sized {
 
     /// Renders the element and writes it to the given writer also implementing
     /// [`IsTerminal`](std::io::IsTerminal). If the writer is a terminal, the canvas will be
-    /// rendered based on its size, with ANSI escape codes.
+    /// rendered based on its size, with ANSI escape codes using the color depth detected via
+    /// [`ColorDepth::detect`].
     fn write_to_is_terminal<W: Write + IsTerminal>(&mut self, w: W) -> io::Result<()> {
+        self.write_to_is_terminal_with_color_depth(w, ColorDepth::detect())
+    }
+
+    /// Like [`ElementExt::write_to_is_terminal`], but uses the given color depth instead of
+    /// detecting it.
+    fn write_to_is_terminal_with_color_depth<W: Write + IsTerminal>(
+        &mut self,
+        w: W,
+        color_depth: ColorDepth,
+    ) -> io::Result<()> {
         if w.is_terminal() {
             let (width, _) = terminal::size()?;
             let canvas = self.render(Some(width as _));
-            canvas.write_ansi(w)
+            canvas.write_ansi_with_color_depth(w, color_depth)
         } else {
             self.write(w)
         }
@@ -222,7 +255,18 @@ pub trait ElementExt: private::Sealed + Sized {
     /// This method should only be used if when stdio is a TTY terminal. If for example, stdout is
     /// a file, this will probably not produce the desired result. You can determine whether stdout
     /// is a terminal with [`IsTerminal`](std::io::IsTerminal).
-    fn render_loop(&mut self) -> impl Future<Output = io::Result<()>>;
+    ///
+    /// Colors are converted to the color depth detected via [`ColorDepth::detect`]. To use a
+    /// specific color depth, use [`ElementExt::render_loop_with_color_depth`] instead.
+    fn render_loop(&mut self) -> impl Future<Output = io::Result<()>> {
+        self.render_loop_with_color_depth(ColorDepth::detect())
+    }
+
+    /// Like [`ElementExt::render_loop`], but uses the given color depth instead of detecting it.
+    fn render_loop_with_color_depth(
+        &mut self,
+        color_depth: ColorDepth,
+    ) -> impl Future<Output = io::Result<()>>;
 
     /// Renders the element in a loop using a mock terminal, allowing you to simulate terminal
     /// events for testing purposes.
@@ -266,7 +310,18 @@ pub trait ElementExt: private::Sealed + Sized {
     /// This method should only be used if when stdio is a TTY terminal. If for example, stdout is
     /// a file, this will probably not produce the desired result. You can determine whether stdout
     /// is a terminal with [`IsTerminal`](std::io::IsTerminal).
-    fn fullscreen(&mut self) -> impl Future<Output = io::Result<()>>;
+    ///
+    /// Colors are converted to the color depth detected via [`ColorDepth::detect`]. To use a
+    /// specific color depth, use [`ElementExt::fullscreen_with_color_depth`] instead.
+    fn fullscreen(&mut self) -> impl Future<Output = io::Result<()>> {
+        self.fullscreen_with_color_depth(ColorDepth::detect())
+    }
+
+    /// Like [`ElementExt::fullscreen`], but uses the given color depth instead of detecting it.
+    fn fullscreen_with_color_depth(
+        &mut self,
+        color_depth: ColorDepth,
+    ) -> impl Future<Output = io::Result<()>>;
 }
 
 impl ElementExt for AnyElement<'_> {
@@ -274,7 +329,7 @@ impl ElementExt for AnyElement<'_> {
         &self.key
     }
 
-    fn props_mut(&mut self) -> AnyProps {
+    fn props_mut(&mut self) -> AnyProps<'_> {
         self.props.borrow()
     }
 
@@ -287,8 +342,8 @@ impl ElementExt for AnyElement<'_> {
         render(self, max_width)
     }
 
-    async fn render_loop(&mut self) -> io::Result<()> {
-        terminal_render_loop(self, Terminal::new()?).await
+    async fn render_loop_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(self, Terminal::new(color_depth)?).await
     }
 
     fn mock_terminal_render_loop(
@@ -298,8 +353,8 @@ impl ElementExt for AnyElement<'_> {
         mock_terminal_render_loop(self, config)
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
-        terminal_render_loop(self, Terminal::fullscreen()?).await
+    async fn fullscreen_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(self, Terminal::fullscreen(color_depth)?).await
     }
 }
 
@@ -308,7 +363,7 @@ impl ElementExt for &mut AnyElement<'_> {
         &self.key
     }
 
-    fn props_mut(&mut self) -> AnyProps {
+    fn props_mut(&mut self) -> AnyProps<'_> {
         self.props.borrow()
     }
 
@@ -321,8 +376,8 @@ impl ElementExt for &mut AnyElement<'_> {
         render(&mut **self, max_width)
     }
 
-    async fn render_loop(&mut self) -> io::Result<()> {
-        terminal_render_loop(&mut **self, Terminal::new()?).await
+    async fn render_loop_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(&mut **self, Terminal::new(color_depth)?).await
     }
 
     fn mock_terminal_render_loop(
@@ -332,8 +387,8 @@ impl ElementExt for &mut AnyElement<'_> {
         mock_terminal_render_loop(&mut **self, config)
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
-        terminal_render_loop(&mut **self, Terminal::fullscreen()?).await
+    async fn fullscreen_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(&mut **self, Terminal::fullscreen(color_depth)?).await
     }
 }
 
@@ -345,7 +400,7 @@ where
         &self.key
     }
 
-    fn props_mut(&mut self) -> AnyProps {
+    fn props_mut(&mut self) -> AnyProps<'_> {
         AnyProps::borrowed(&mut self.props)
     }
 
@@ -358,8 +413,8 @@ where
         render(self, max_width)
     }
 
-    async fn render_loop(&mut self) -> io::Result<()> {
-        terminal_render_loop(self, Terminal::new()?).await
+    async fn render_loop_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(self, Terminal::new(color_depth)?).await
     }
 
     fn mock_terminal_render_loop(
@@ -368,8 +423,9 @@ where
     ) -> impl Stream<Item = Canvas> {
         mock_terminal_render_loop(self, config)
     }
-    async fn fullscreen(&mut self) -> io::Result<()> {
-        terminal_render_loop(self, Terminal::fullscreen()?).await
+
+    async fn fullscreen_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(self, Terminal::fullscreen(color_depth)?).await
     }
 }
 
@@ -381,7 +437,7 @@ where
         &self.key
     }
 
-    fn props_mut(&mut self) -> AnyProps {
+    fn props_mut(&mut self) -> AnyProps<'_> {
         AnyProps::borrowed(&mut self.props)
     }
 
@@ -394,8 +450,8 @@ where
         render(&mut **self, max_width)
     }
 
-    async fn render_loop(&mut self) -> io::Result<()> {
-        terminal_render_loop(&mut **self, Terminal::new()?).await
+    async fn render_loop_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(&mut **self, Terminal::new(color_depth)?).await
     }
 
     fn mock_terminal_render_loop(
@@ -405,8 +461,8 @@ where
         mock_terminal_render_loop(&mut **self, config)
     }
 
-    async fn fullscreen(&mut self) -> io::Result<()> {
-        terminal_render_loop(&mut **self, Terminal::fullscreen()?).await
+    async fn fullscreen_with_color_depth(&mut self, color_depth: ColorDepth) -> io::Result<()> {
+        terminal_render_loop(&mut **self, Terminal::fullscreen(color_depth)?).await
     }
 }
 
@@ -414,16 +470,20 @@ where
 mod tests {
     use crate::prelude::*;
 
-    #[allow(clippy::unnecessary_mut_passed)]
+    // Goes through the trait so that the `&mut` implementations are exercised too.
+    fn use_element(mut element: impl ElementExt) {
+        element.key();
+        element.print();
+        element.eprint();
+    }
+
     #[test]
     fn test_element() {
         let mut view_element = element!(View);
         view_element.key();
         view_element.print();
         view_element.eprint();
-        (&mut view_element).key();
-        (&mut view_element).print();
-        (&mut view_element).eprint();
+        use_element(&mut view_element);
 
         #[cfg(unix)]
         view_element.write_to_raw_fd(std::io::stdout()).unwrap();
@@ -432,9 +492,7 @@ mod tests {
         any_element.key();
         any_element.print();
         any_element.eprint();
-        (&mut any_element).key();
-        (&mut any_element).print();
-        (&mut any_element).eprint();
+        use_element(&mut any_element);
 
         let mut view_element = element!(View);
         let mut any_element_ref: AnyElement = (&mut view_element).into();
@@ -48,6 +48,8 @@ mod use_context;
 pub use use_context::*;
 mod use_future;
 pub use use_future::*;
+mod use_layout;
+pub use use_layout::*;
 mod use_memo;
 pub use use_memo::*;
 mod use_output;
@@ -58,3 +60,5 @@ mod use_terminal_events;
 pub use use_terminal_events::*;
 mod use_terminal_size;
 pub use use_terminal_size::*;
+mod use_ticker;
+pub use use_ticker::*;
@@ -0,0 +1,170 @@
+use crate::{ComponentDrawer, Hook, Hooks};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// The computed layout of a component, as returned by [`UseLayout::use_layout`].
+///
+/// All positions are relative to the top left of the canvas.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ComponentLayout {
+    /// The horizontal position of the component.
+    pub x: i16,
+    /// The vertical position of the component.
+    pub y: i16,
+    /// The width of the component, including its border and padding.
+    pub width: u16,
+    /// The height of the component, including its border and padding.
+    pub height: u16,
+    /// The horizontal position of the component's content box, i.e. inside its border and padding.
+    pub content_x: i16,
+    /// The vertical position of the component's content box, i.e. inside its border and padding.
+    pub content_y: i16,
+    /// The width of the component's content box.
+    pub content_width: u16,
+    /// The height of the component's content box.
+    pub content_height: u16,
+}
+
+impl ComponentLayout {
+    fn from_drawer(drawer: &ComponentDrawer) -> Self {
+        let position = drawer.canvas_position();
+        let size = drawer.size();
+        let layout = drawer.layout();
+        let left = (layout.border.left + layout.padding.left) as u16;
+        let top = (layout.border.top + layout.padding.top) as u16;
+        let right = (layout.border.right + layout.padding.right) as u16;
+        let bottom = (layout.border.bottom + layout.padding.bottom) as u16;
+        Self {
+            x: position.x,
+            y: position.y,
+            width: size.width,
+            height: size.height,
+            content_x: position.x + left as i16,
+            content_y: position.y + top as i16,
+            content_width: size.width.saturating_sub(left + right),
+            content_height: size.height.saturating_sub(top + bottom),
+        }
+    }
+}
+
+/// `UseLayout` is a hook that returns the layout computed for the component the last time it was
+/// drawn.
+///
+/// Layout is computed after the component renders, so the first render sees an empty layout. Once
+/// the component is drawn, it's re-rendered whenever its layout changes. This allows components to
+/// adapt to the space they're given, but care should be taken not to render something that changes
+/// the layout back, or the component will never settle.
+///
+/// When rendering without a render loop, e.g. via [`ElementExt::to_string`](crate::ElementExt::to_string),
+/// there is only a single render, so the layout will always be empty.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// #[component]
+/// fn Greeting(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let layout = hooks.use_layout();
+///
+///     element! {
+///         View(width: 100pct) {
+///             Text(content: if layout.width >= 40 {
+///                 "Hello, and welcome to the wide layout!"
+///             } else {
+///                 "Hello!"
+///             })
+///         }
+///     }
+/// }
+/// ```
+pub trait UseLayout: private::Sealed {
+    /// Returns the component's most recently computed layout.
+    fn use_layout(&mut self) -> ComponentLayout;
+}
+
+impl UseLayout for Hooks<'_, '_> {
+    fn use_layout(&mut self) -> ComponentLayout {
+        self.use_hook(UseLayoutImpl::default).layout
+    }
+}
+
+#[derive(Default)]
+struct UseLayoutImpl {
+    layout: ComponentLayout,
+    is_changed: bool,
+    waker: Option<Waker>,
+}
+
+impl Hook for UseLayoutImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if self.is_changed {
+            self.is_changed = false;
+            Poll::Ready(())
+        } else {
+            self.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn pre_component_draw(&mut self, drawer: &mut ComponentDrawer) {
+        let layout = ComponentLayout::from_drawer(drawer);
+        if layout != self.layout {
+            self.layout = layout;
+            self.is_changed = true;
+            if let Some(waker) = self.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let layout = hooks.use_layout();
+
+        if layout.width > 0 {
+            system.exit();
+        }
+
+        element! {
+            View(border_style: BorderStyle::Single, padding_left: 1, width: 20, height: 4) {
+                Text(content: format!(
+                    "{},{} {}x{}",
+                    layout.content_x, layout.content_y, layout.content_width, layout.content_height,
+                ))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_layout() {
+        let actual = element! {
+            View(padding_left: 2) {
+                MyComponent
+            }
+        }
+        .mock_terminal_render_loop(MockTerminalConfig::default())
+        .map(|c| c.to_string())
+        .collect::<Vec<_>>()
+        .await;
+        assert_eq!(
+            actual.last().unwrap(),
+            "  ┌──────────────────┐\n  │ 4,1 17x2         │\n  │                  │\n  └──────────────────┘\n"
+        );
+    }
+}
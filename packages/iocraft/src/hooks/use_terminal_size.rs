@@ -1,5 +1,6 @@
 use crate::{
-    hooks::{UseState, UseTerminalEvents},
+    context::TerminalSizeContext,
+    hooks::{UseContext, UseState, UseTerminalEvents},
     Hooks, TerminalEvent,
 };
 use crossterm::terminal;
@@ -17,13 +18,19 @@ pub trait UseTerminalSize: private::Sealed {
 
 impl UseTerminalSize for Hooks<'_, '_> {
     fn use_terminal_size(&mut self) -> (u16, u16) {
-        let mut size = self.use_state(|| terminal::size().unwrap_or((0, 0)));
+        let terminal_size = self
+            .try_use_context::<TerminalSizeContext>()
+            .and_then(|size| size.0);
+        let mut size =
+            self.use_state(|| terminal_size.unwrap_or_else(|| terminal::size().unwrap_or((0, 0))));
         self.use_terminal_events(move |event| {
             if let TerminalEvent::Resize(width, height) = event {
                 size.set((width, height));
             }
         });
-        size.get()
+        // If the size is provided by the render loop, it's always up to date, even if the resize
+        // event hasn't been handled yet.
+        terminal_size.unwrap_or_else(|| size.get())
     }
 }
 
@@ -59,4 +66,39 @@ mod tests {
             .await;
         assert_eq!(actual.last().unwrap(), "100x40\n");
     }
+
+    #[component]
+    fn MyWrappingComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let (width, height) = hooks.use_terminal_size();
+
+        if width == 12 {
+            system.exit();
+        }
+
+        element! {
+            View(width, flex_direction: FlexDirection::Column) {
+                Text(content: format!("{}x{}", width, height))
+                Text(content: "aaaa bbbb cccc")
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_terminal_size_with_mock_size() {
+        let actual = element!(MyWrappingComponent)
+            .mock_terminal_render_loop(
+                MockTerminalConfig::with_events(futures::stream::iter(vec![
+                    TerminalEvent::Resize(12, 20),
+                ]))
+                .with_size(8, 10),
+            )
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual,
+            vec!["8x10\naaaa\nbbbb\ncccc\n", "12x20\naaaa bbbb\ncccc\n",]
+        );
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{Hook, Hooks};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+use std::{
+    sync::{Condvar, Mutex, OnceLock},
+    thread,
+    time::Instant,
+};
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for crate::Hooks<'_, '_> {}
+}
+
+/// `UseTicker` is a hook that re-renders the component at a regular interval, for things like
+/// animations.
+///
+/// All tickers are driven by a single background thread, so any number of components can animate
+/// without each running their own timer.
+///
+/// # Example
+///
+/// ```
+/// # use iocraft::prelude::*;
+/// # use std::time::Duration;
+/// #[component]
+/// fn Blinker(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+///     let tick = hooks.use_ticker(Duration::from_millis(500));
+///
+///     element! {
+///         Text(content: if tick % 2 == 0 { "●" } else { " " })
+///     }
+/// }
+/// ```
+pub trait UseTicker: private::Sealed {
+    /// Returns the number of whole intervals that have elapsed since the hook was first used, and
+    /// schedules a re-render for when the next one elapses.
+    fn use_ticker(&mut self, interval: Duration) -> u64;
+}
+
+impl UseTicker for Hooks<'_, '_> {
+    fn use_ticker(&mut self, interval: Duration) -> u64 {
+        let h = self.use_hook(UseTickerImpl::new);
+        h.interval = interval.max(Duration::from_millis(1));
+        h.tick = h.current_tick();
+        h.tick
+    }
+}
+
+struct UseTickerImpl {
+    start: Instant,
+    interval: Duration,
+    tick: u64,
+    scheduled: Option<(Instant, Waker)>,
+}
+
+impl UseTickerImpl {
+    fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            interval: Duration::from_secs(1),
+            tick: 0,
+            scheduled: None,
+        }
+    }
+
+    fn current_tick(&self) -> u64 {
+        (self.start.elapsed().as_nanos() / self.interval.as_nanos()) as u64
+    }
+}
+
+impl Hook for UseTickerImpl {
+    fn poll_change(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        let tick = self.current_tick();
+        if tick != self.tick {
+            return Poll::Ready(());
+        }
+        let deadline = self.start
+            + Duration::from_nanos((self.interval.as_nanos() * (tick as u128 + 1)) as u64);
+        let is_scheduled = self
+            .scheduled
+            .as_ref()
+            .is_some_and(|(d, waker)| *d == deadline && waker.will_wake(cx.waker()));
+        if !is_scheduled {
+            Ticker::get().schedule(deadline, cx.waker().clone());
+            self.scheduled = Some((deadline, cx.waker().clone()));
+        }
+        Poll::Pending
+    }
+}
+
+/// Wakes tasks at requested times, using a single thread that's started the first time it's
+/// needed.
+struct Ticker {
+    timers: Mutex<Vec<(Instant, Waker)>>,
+    condvar: Condvar,
+}
+
+impl Ticker {
+    fn get() -> &'static Self {
+        static TICKER: OnceLock<Ticker> = OnceLock::new();
+        let mut is_new = false;
+        let ticker = TICKER.get_or_init(|| {
+            is_new = true;
+            Self {
+                timers: Mutex::new(Vec::new()),
+                condvar: Condvar::new(),
+            }
+        });
+        if is_new {
+            thread::Builder::new()
+                .name("iocraft-ticker".to_string())
+                .spawn(move || ticker.run())
+                .expect("failed to spawn ticker thread");
+        }
+        ticker
+    }
+
+    fn schedule(&self, deadline: Instant, waker: Waker) {
+        self.timers.lock().unwrap().push((deadline, waker));
+        self.condvar.notify_one();
+    }
+
+    fn run(&self) {
+        let mut timers = self.timers.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            timers.retain(|(deadline, waker)| {
+                if *deadline <= now {
+                    waker.wake_by_ref();
+                    false
+                } else {
+                    true
+                }
+            });
+            timers = match timers.iter().map(|(deadline, _)| *deadline).min() {
+                Some(deadline) => self.condvar.wait_timeout(timers, deadline - now).unwrap().0,
+                None => self.condvar.wait(timers).unwrap(),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use futures::stream::StreamExt;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+    use std::time::Duration;
+
+    #[component]
+    fn MyComponent(mut hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        let tick = hooks.use_ticker(Duration::from_millis(10));
+
+        if tick >= 3 {
+            system.exit();
+        }
+
+        element! {
+            Text(content: format!("tick: {}", tick.min(3)))
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_use_ticker() {
+        let actual = element!(MyComponent)
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(actual.first().unwrap(), "tick: 0\n");
+        assert_eq!(actual.last().unwrap(), "tick: 3\n");
+    }
+}
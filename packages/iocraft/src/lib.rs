@@ -7,7 +7,7 @@
 //! ## Features
 //!
 //! - Define your UI using a clean, highly readable syntax.
-//! - Organize your UI using flexbox layouts powered by [`taffy`](https://docs.rs/taffy/).
+//! - Organize your UI using flexbox and grid layouts powered by [`taffy`](https://docs.rs/taffy/).
 //! - Output colored and styled UIs to the terminal or ASCII output anywhere else.
 //! - Create animated or interactive elements with event handling and hooks.
 //! - Build fullscreen terminal applications with ease.
@@ -99,12 +99,15 @@ mod context;
 mod element;
 mod handler;
 mod hook;
+mod multi_progress;
 mod multimap;
 mod props;
 mod render;
 pub(crate) mod segmented_string;
 mod style;
 mod terminal;
+#[cfg(test)]
+mod test_utils;
 pub(crate) mod unicode_linebreak;
 
 mod flattened_exports {
@@ -114,6 +117,7 @@ mod flattened_exports {
     pub use crate::element::*;
     pub use crate::handler::*;
     pub use crate::hook::*;
+    pub use crate::multi_progress::*;
     pub use crate::props::*;
     pub use crate::render::*;
     pub use crate::style::*;
@@ -129,6 +133,8 @@ pub mod components;
 
 pub mod hooks;
 
+pub mod prompt;
+
 /// By importing this module, you'll bring all of the crate's commonly used types into scope.
 pub mod prelude {
     pub use crate::components::*;
@@ -0,0 +1,596 @@
+use crate::{
+    component,
+    components::{ProgressBar, Static, Text, View},
+    element,
+    hooks::{UseContext, UseFuture, UseState},
+    AnyElement, ElementExt, FlexDirection, Hooks, Props, SystemContext,
+};
+use futures::{
+    channel::mpsc::{self, UnboundedReceiver, UnboundedSender},
+    StreamExt,
+};
+use std::{
+    collections::HashMap,
+    io::{self, IsTerminal, Write},
+    mem,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    thread::{self, JoinHandle},
+};
+
+const DEFAULT_TEMPLATE: &str = "{bar} {percent} {value}/{total} ETA {eta}";
+const DEFAULT_INDETERMINATE_TEMPLATE: &str = "{bar} {value} {elapsed}";
+const FINISHED_TEMPLATE: &str = "{bar} {percent}";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct BarState {
+    id: u64,
+    value: u64,
+    total: Option<u64>,
+    message: String,
+    template: String,
+}
+
+impl BarState {
+    fn percent(&self) -> Option<u64> {
+        self.total.map(|total| match total {
+            0 => 100,
+            _ => self.value.min(total) * 100 / total,
+        })
+    }
+
+    /// Returns the state which, when changed, causes a new line to be logged for the bar. Progress
+    /// is only logged in steps of 10% so that the log doesn't get flooded.
+    fn log_state(&self) -> (String, Option<u64>) {
+        (
+            self.message.clone(),
+            self.percent().map(|percent| percent / 10),
+        )
+    }
+
+    /// Describes the bar in a single line of text, for when it can't be drawn.
+    fn summary(&self) -> String {
+        let progress = match (self.total, self.percent()) {
+            (Some(total), Some(percent)) => format!("{}% ({}/{})", percent, self.value, total),
+            _ if self.value == 0 => String::new(),
+            _ => self.value.to_string(),
+        };
+        match (self.message.as_str(), progress.as_str()) {
+            (message, "") => message.to_string(),
+            ("", progress) => progress.to_string(),
+            (message, progress) => format!("{}: {}", message, progress),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Update {
+    Add(BarState),
+    Inc(u64, u64),
+    SetValue(u64, u64),
+    SetTotal(u64, Option<u64>),
+    SetMessage(u64, String),
+    SetTemplate(u64, String),
+    Finish(u64, Option<String>),
+    Println(String),
+    Exit,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Output {
+    Line(String),
+    Bar(BarState),
+}
+
+/// The state of all bars, which is updated by messages from the handles.
+#[derive(Debug, Default)]
+struct Bars {
+    active: Vec<BarState>,
+    // Finished bars and printed lines which haven't been written yet.
+    output: Vec<Output>,
+    // The number of times output has been taken to be written.
+    output_batches: usize,
+    exited: bool,
+}
+
+impl Bars {
+    fn take_output(&mut self) -> Vec<Output> {
+        self.output_batches += 1;
+        mem::take(&mut self.output)
+    }
+
+    fn bar_mut(&mut self, id: u64) -> Option<&mut BarState> {
+        self.active.iter_mut().find(|bar| bar.id == id)
+    }
+
+    fn apply(&mut self, update: Update) {
+        match update {
+            Update::Add(bar) => self.active.push(bar),
+            Update::Inc(id, delta) => {
+                if let Some(bar) = self.bar_mut(id) {
+                    bar.value = bar.value.saturating_add(delta);
+                }
+            }
+            Update::SetValue(id, value) => {
+                if let Some(bar) = self.bar_mut(id) {
+                    bar.value = value;
+                }
+            }
+            Update::SetTotal(id, total) => {
+                if let Some(bar) = self.bar_mut(id) {
+                    bar.total = total;
+                }
+            }
+            Update::SetMessage(id, message) => {
+                if let Some(bar) = self.bar_mut(id) {
+                    bar.message = message;
+                }
+            }
+            Update::SetTemplate(id, template) => {
+                if let Some(bar) = self.bar_mut(id) {
+                    bar.template = template;
+                }
+            }
+            Update::Finish(id, message) => {
+                if let Some(i) = self.active.iter().position(|bar| bar.id == id) {
+                    let mut bar = self.active.remove(i);
+                    if let Some(total) = bar.total {
+                        bar.value = total;
+                    }
+                    if let Some(message) = message {
+                        bar.message = message;
+                    }
+                    self.output.push(Output::Bar(bar));
+                }
+            }
+            Update::Println(line) => self.output.push(Output::Line(line)),
+            Update::Exit => self.exited = true,
+        }
+    }
+}
+
+/// Logs bars line by line, for when the output isn't a terminal.
+struct LineLogger {
+    bars: Bars,
+    writer: Box<dyn Write + Send>,
+    // The message and percentage of each active bar, as of the last line logged for it.
+    logged: HashMap<u64, (String, Option<u64>)>,
+}
+
+impl LineLogger {
+    fn new(writer: Box<dyn Write + Send>) -> Self {
+        Self {
+            bars: Bars::default(),
+            writer,
+            logged: HashMap::new(),
+        }
+    }
+
+    fn apply(&mut self, update: Update) {
+        self.bars.apply(update);
+        for output in self.bars.take_output() {
+            let _ = match output {
+                Output::Line(line) => writeln!(self.writer, "{}", line),
+                // Don't repeat the last line logged for the bar if finishing it changed nothing.
+                Output::Bar(bar) if self.logged.remove(&bar.id) == Some(bar.log_state()) => Ok(()),
+                Output::Bar(bar) => writeln!(self.writer, "{}", bar.summary()),
+            };
+        }
+        for bar in &self.bars.active {
+            // Progress is only logged in steps of 10% so that the log doesn't get flooded.
+            let current = (
+                bar.message.clone(),
+                bar.percent().map(|percent| percent / 10),
+            );
+            match self.logged.get(&bar.id) {
+                None => {
+                    self.logged.insert(bar.id, current);
+                }
+                Some(logged) if *logged != current => {
+                    let _ = writeln!(self.writer, "{}", bar.summary());
+                    self.logged.insert(bar.id, current);
+                }
+                Some(_) => {}
+            }
+        }
+        let _ = self.writer.flush();
+    }
+}
+
+#[derive(Clone)]
+enum Sink {
+    Terminal(UnboundedSender<Update>),
+    Lines(Arc<Mutex<LineLogger>>),
+}
+
+impl Sink {
+    fn send(&self, update: Update) {
+        match self {
+            // If the render loop has already exited, there's nothing left to update.
+            Self::Terminal(tx) => {
+                let _ = tx.unbounded_send(update);
+            }
+            Self::Lines(logger) => logger.lock().unwrap().apply(update),
+        }
+    }
+}
+
+fn bar_element(bar: &BarState, template: &str) -> AnyElement<'static> {
+    element! {
+        View(key: bar.id) {
+            #((!bar.message.is_empty()).then(|| element! {
+                Text(content: format!("{} ", bar.message))
+            }))
+            ProgressBar(
+                value: bar.value,
+                total: bar.total,
+                template: template.to_string(),
+                flex_grow: 1.0,
+            )
+        }
+    }
+    .into_any()
+}
+
+#[derive(Default, Props)]
+struct MultiProgressViewProps {
+    messages: Option<UnboundedReceiver<Update>>,
+}
+
+#[component]
+fn MultiProgressView(
+    mut hooks: Hooks,
+    props: &mut MultiProgressViewProps,
+) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut bars = hooks.use_state(Bars::default);
+
+    let messages = props.messages.take();
+    hooks.use_future(async move {
+        let Some(mut messages) = messages else {
+            return;
+        };
+        while let Some(update) = messages.next().await {
+            // Apply everything that's queued up at once so that we only re-render once.
+            let mut bars = bars.write();
+            bars.apply(update);
+            while let Ok(update) = messages.try_recv() {
+                bars.apply(update);
+            }
+        }
+        bars.write().exited = true;
+    });
+
+    // Output is only kept until it's been written, so that it doesn't accumulate. Each batch is
+    // written by a new static element.
+    let output = if bars.read().output.is_empty() {
+        Vec::new()
+    } else {
+        bars.write().take_output()
+    };
+
+    let bars = bars.read();
+    if bars.exited {
+        system.exit();
+    }
+
+    element! {
+        View(flex_direction: FlexDirection::Column) {
+            Static(key: bars.output_batches) {
+                #(output.into_iter().map(|output| match output {
+                    Output::Line(line) => element!(Text(content: line)).into_any(),
+                    Output::Bar(bar) => bar_element(
+                        &BarState {
+                            // Finished indeterminate bars are displayed as full.
+                            total: Some(bar.total.unwrap_or(bar.value)),
+                            ..bar
+                        },
+                        FINISHED_TEMPLATE,
+                    ),
+                }))
+            }
+            #(bars.active.iter().map(|bar| bar_element(bar, &bar.template)))
+        }
+    }
+}
+
+struct MultiProgressInner {
+    sink: Sink,
+    next_id: AtomicU64,
+    render_thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for MultiProgressInner {
+    fn drop(&mut self) {
+        self.sink.send(Update::Exit);
+        if let Some(render_thread) = self.render_thread.take() {
+            let _ = render_thread.join();
+        }
+    }
+}
+
+/// `MultiProgress` displays any number of progress bars from ordinary, imperative code.
+///
+/// It's an alternative to building an element tree for programs which just want to report
+/// progress. Each bar is controlled by a [`ProgressBarHandle`], which can be sent to and used from
+/// any thread. Bars are drawn by a [`ProgressBar`] component in a [render
+/// loop](crate::ElementExt::render_loop) running on a background thread. When a bar is finished, it's
+/// written permanently above the bars that are still in progress.
+///
+/// If stdout isn't a terminal, bars are logged line by line instead, including whenever their
+/// message changes and every 10% of progress.
+///
+/// The display is torn down once the `MultiProgress` and all of its clones are dropped, after
+/// which any remaining handles have no effect.
+///
+/// # Example
+///
+/// ```no_run
+/// # use iocraft::prelude::*;
+/// let progress = MultiProgress::new();
+/// let threads = (1..=3)
+///     .map(|n| {
+///         let bar = progress.add(100);
+///         bar.set_message(format!("Worker {}", n));
+///         std::thread::spawn(move || {
+///             for _ in 0..100 {
+///                 std::thread::sleep(std::time::Duration::from_millis(10 * n));
+///                 bar.inc(1);
+///             }
+///             bar.finish_with_message(format!("Worker {} done", n));
+///         })
+///     })
+///     .collect::<Vec<_>>();
+/// for thread in threads {
+///     thread.join().unwrap();
+/// }
+/// ```
+#[derive(Clone)]
+pub struct MultiProgress {
+    inner: Arc<MultiProgressInner>,
+}
+
+impl MultiProgress {
+    /// Creates a new `MultiProgress` which draws to stdout, or logs to it line by line if it isn't
+    /// a terminal.
+    pub fn new() -> Self {
+        if !io::stdout().is_terminal() {
+            return Self::with_line_output(io::stdout());
+        }
+        let (tx, rx) = mpsc::unbounded();
+        let render_thread = thread::Builder::new()
+            .name("iocraft-multi-progress".to_string())
+            .spawn(move || {
+                let _ = futures::executor::block_on(
+                    element!(MultiProgressView(messages: rx)).render_loop(),
+                );
+            })
+            .expect("failed to spawn render thread");
+        Self::with_sink(Sink::Terminal(tx), Some(render_thread))
+    }
+
+    /// Creates a new `MultiProgress` which logs to the given writer line by line instead of
+    /// drawing bars.
+    pub fn with_line_output<W: Write + Send + 'static>(writer: W) -> Self {
+        Self::with_sink(
+            Sink::Lines(Arc::new(Mutex::new(LineLogger::new(Box::new(writer))))),
+            None,
+        )
+    }
+
+    fn with_sink(sink: Sink, render_thread: Option<JoinHandle<()>>) -> Self {
+        Self {
+            inner: Arc::new(MultiProgressInner {
+                sink,
+                next_id: AtomicU64::new(0),
+                render_thread,
+            }),
+        }
+    }
+
+    fn add_bar(&self, total: Option<u64>, template: &str) -> ProgressBarHandle {
+        let handle = ProgressBarHandle {
+            id: self.inner.next_id.fetch_add(1, Ordering::Relaxed),
+            sink: self.inner.sink.clone(),
+        };
+        self.inner.sink.send(Update::Add(BarState {
+            id: handle.id,
+            value: 0,
+            total,
+            message: String::new(),
+            template: template.to_string(),
+        }));
+        handle
+    }
+
+    /// Adds a bar below the others, for work whose total amount is known.
+    pub fn add(&self, total: u64) -> ProgressBarHandle {
+        self.add_bar(Some(total), DEFAULT_TEMPLATE)
+    }
+
+    /// Adds an indeterminate bar below the others, for work whose total amount isn't known.
+    pub fn add_indeterminate(&self) -> ProgressBarHandle {
+        self.add_bar(None, DEFAULT_INDETERMINATE_TEMPLATE)
+    }
+
+    /// Writes a line of text above the bars.
+    pub fn println<S: Into<String>>(&self, line: S) {
+        self.inner.sink.send(Update::Println(line.into()));
+    }
+}
+
+impl Default for MultiProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A handle to one of the bars of a [`MultiProgress`].
+///
+/// Handles can be cloned and sent to other threads. Updates made after the bar is finished are
+/// ignored.
+#[derive(Clone)]
+pub struct ProgressBarHandle {
+    id: u64,
+    sink: Sink,
+}
+
+impl ProgressBarHandle {
+    /// Adds to the amount of progress made.
+    pub fn inc(&self, delta: u64) {
+        self.sink.send(Update::Inc(self.id, delta));
+    }
+
+    /// Sets the amount of progress made.
+    pub fn set_value(&self, value: u64) {
+        self.sink.send(Update::SetValue(self.id, value));
+    }
+
+    /// Sets the total amount of work. If `None`, the bar becomes indeterminate.
+    pub fn set_total(&self, total: Option<u64>) {
+        self.sink.send(Update::SetTotal(self.id, total));
+    }
+
+    /// Sets the message displayed before the bar.
+    pub fn set_message<S: Into<String>>(&self, message: S) {
+        self.sink.send(Update::SetMessage(self.id, message.into()));
+    }
+
+    /// Sets the layout of the bar and its labels. See
+    /// [`ProgressBarProps::template`](crate::components::ProgressBarProps::template) for the
+    /// placeholders that can be used.
+    pub fn set_template<S: Into<String>>(&self, template: S) {
+        self.sink
+            .send(Update::SetTemplate(self.id, template.into()));
+    }
+
+    /// Completes the bar and moves it above the bars that are still in progress.
+    pub fn finish(&self) {
+        self.sink.send(Update::Finish(self.id, None));
+    }
+
+    /// Completes the bar with a final message and moves it above the bars that are still in
+    /// progress.
+    pub fn finish_with_message<S: Into<String>>(&self, message: S) {
+        self.sink
+            .send(Update::Finish(self.id, Some(message.into())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_line_output() {
+        let buffer = SharedBuffer::default();
+        let progress = MultiProgress::with_line_output(buffer.clone());
+
+        let a = progress.add(200);
+        let b = progress.add_indeterminate();
+        a.set_message("Downloading");
+        for _ in 0..50 {
+            a.inc(1);
+        }
+        b.inc(3);
+        progress.println("Halfway there");
+        a.set_value(200);
+        b.finish_with_message("Resolved");
+        b.set_message("Ignored");
+        a.finish();
+
+        // Output isn't kept around once it's been written.
+        let Sink::Lines(logger) = &progress.inner.sink else {
+            unreachable!()
+        };
+        assert!(logger.lock().unwrap().bars.output.is_empty());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output,
+            indoc::indoc! {"
+                Downloading: 0% (0/200)
+                Downloading: 10% (20/200)
+                Downloading: 20% (40/200)
+                Halfway there
+                Downloading: 100% (200/200)
+                Resolved: 3
+            "}
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_multi_progress_view() {
+        let (tx, rx) = mpsc::unbounded();
+        let progress = MultiProgress::with_sink(Sink::Terminal(tx), None);
+        let a = progress.add(4);
+        let b = progress.add(10);
+        a.set_message("a");
+        b.set_message("b");
+        a.inc(2);
+        b.set_value(5);
+        b.set_template("{value}/{total}");
+        a.finish_with_message("a done");
+        progress.println("hello");
+        drop(progress);
+
+        let actual = element!(MultiProgressView(messages: rx))
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string())
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(
+            actual,
+            vec![
+                "".to_string(),
+                format!("a done {} 100%\n", "█".repeat(40)),
+                "hello\n".to_string(),
+                "b 5/10\n".to_string(),
+            ]
+        );
+    }
+
+    #[apply(test!)]
+    async fn test_multi_progress_view_output_batches() {
+        let (tx, rx) = mpsc::unbounded();
+        let progress = MultiProgress::with_sink(Sink::Terminal(tx), None);
+        progress.println("one");
+
+        let mut element = element!(MultiProgressView(messages: rx));
+        let mut canvases = element
+            .mock_terminal_render_loop(MockTerminalConfig::default())
+            .map(|c| c.to_string());
+        let mut actual = Vec::new();
+        while let Some(canvas) = canvases.next().await {
+            let is_first_batch = canvas == "one\n";
+            actual.push(canvas);
+            if is_first_batch {
+                break;
+            }
+        }
+        progress.println("two");
+        drop(progress);
+        actual.extend(canvases.collect::<Vec<_>>().await);
+
+        // Each line is only written once.
+        assert_eq!(actual, vec!["", "one\n", "", "two\n", ""]);
+    }
+}
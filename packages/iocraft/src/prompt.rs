@@ -0,0 +1,618 @@
+//! This module contains functions for asking the user one-off questions without writing a
+//! component.
+//!
+//! Each function renders a prompt inline, waits for the user to answer it, and returns the answer.
+//! Once answered, the prompt is replaced with a one-line summary of the answer, which remains in
+//! the terminal's scrollback. If the user presses Esc or Ctrl-C instead, an error of kind
+//! [`Interrupted`](std::io::ErrorKind::Interrupted) is returned.
+//!
+//! The functions are async so that they can be used alongside other iocraft render loops, and
+//! have blocking equivalents in the [`blocking`] module for use in synchronous code.
+//!
+//! Prompts require stdout to be a terminal. If it isn't, an error of kind
+//! [`Unsupported`](std::io::ErrorKind::Unsupported) is returned.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use iocraft::prompt;
+//!
+//! fn main() -> std::io::Result<()> {
+//!     let name = prompt::blocking::input("What's your name?")?;
+//!     let colors = ["Red", "Green", "Blue"];
+//!     let color = prompt::blocking::select("What's your favorite color?", colors)?;
+//!     if prompt::blocking::confirm("Save your answers?", true)? {
+//!         println!("Saved: {} likes {}.", name, colors[color]);
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+use crate::{
+    component,
+    components::{SelectItem, SelectList, Text, TextInput, View},
+    element,
+    hooks::{UseContext, UseState, UseTerminalEvents},
+    render::terminal_render_loop,
+    terminal::Terminal,
+    AnyElement, Color, ColorDepth, ElementExt, FlexDirection, Hooks, KeyCode, KeyEvent,
+    KeyEventKind, KeyModifiers, Props, SystemContext, TerminalEvent, Weight,
+};
+use std::io::{self, IsTerminal};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Status {
+    #[default]
+    Pending,
+    Submitted,
+    Cancelled,
+}
+
+/// Returns the key that was pressed, ignoring releases.
+fn pressed_key(event: &TerminalEvent) -> Option<KeyCode> {
+    match event {
+        TerminalEvent::Key(KeyEvent { code, kind, .. }) if *kind != KeyEventKind::Release => {
+            Some(*code)
+        }
+        _ => None,
+    }
+}
+
+/// Returns true if the event cancels the prompt, i.e. it's a press of Esc or Ctrl-C.
+fn is_cancellation(event: &TerminalEvent) -> bool {
+    match event {
+        TerminalEvent::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+        }) if *kind != KeyEventKind::Release => {
+            *code == KeyCode::Esc
+                || (*code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL))
+        }
+        _ => false,
+    }
+}
+
+/// The question, as displayed while waiting for an answer.
+fn question(message: &str, hint: &str) -> AnyElement<'static> {
+    element! {
+        // The input after the question takes up the rest of the line, so it mustn't shrink.
+        View(flex_shrink: 0.0) {
+            Text(content: "? ", color: Color::Cyan, weight: Weight::Bold)
+            Text(content: message, weight: Weight::Bold)
+            #((!hint.is_empty()).then(|| element! {
+                Text(content: format!(" {}", hint), color: Color::DarkGrey)
+            }))
+            Text(content: " ")
+        }
+    }
+    .into_any()
+}
+
+/// The line left behind once the question has been answered, or cancelled if there's no answer.
+fn summary(message: &str, answer: Option<&str>) -> AnyElement<'static> {
+    match answer {
+        Some(answer) => element! {
+            View {
+                Text(content: "✔ ", color: Color::Green, weight: Weight::Bold)
+                Text(content: message, weight: Weight::Bold)
+                Text(content: " · ", color: Color::DarkGrey)
+                Text(content: answer, color: Color::Cyan)
+            }
+        }
+        .into_any(),
+        None => element! {
+            View {
+                Text(content: "✘ ", color: Color::Red, weight: Weight::Bold)
+                Text(content: message, weight: Weight::Bold)
+            }
+        }
+        .into_any(),
+    }
+}
+
+#[derive(Default, Props)]
+struct InputPromptProps<'a> {
+    message: String,
+    password: bool,
+    out: Option<&'a mut Option<String>>,
+}
+
+#[component]
+fn InputPrompt<'a>(
+    mut hooks: Hooks,
+    props: &mut InputPromptProps<'a>,
+) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut value = hooks.use_state(String::new);
+    let mut status = hooks.use_state(Status::default);
+
+    hooks.use_terminal_events(move |event| {
+        if is_cancellation(&event) {
+            status.set(Status::Cancelled);
+        } else if pressed_key(&event) == Some(KeyCode::Enter) {
+            status.set(Status::Submitted);
+        }
+    });
+
+    match status.get() {
+        Status::Pending => element! {
+            View {
+                #(question(&props.message, ""))
+                TextInput(
+                    has_focus: true,
+                    value: value.to_string(),
+                    on_change: move |new_value| value.set(new_value),
+                    mask: props.password.then_some('*'),
+                )
+            }
+        }
+        .into_any(),
+        Status::Submitted => {
+            if let Some(out) = props.out.as_mut() {
+                **out = Some(value.to_string());
+            }
+            system.exit();
+            let answer = value.to_string();
+            summary(
+                &props.message,
+                Some(if props.password { "[hidden]" } else { &answer }),
+            )
+        }
+        Status::Cancelled => {
+            system.exit();
+            summary(&props.message, None)
+        }
+    }
+}
+
+#[derive(Default, Props)]
+struct ConfirmPromptProps<'a> {
+    message: String,
+    default: bool,
+    out: Option<&'a mut Option<bool>>,
+}
+
+#[component]
+fn ConfirmPrompt<'a>(
+    mut hooks: Hooks,
+    props: &mut ConfirmPromptProps<'a>,
+) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut answer = hooks.use_state(|| None);
+    let mut status = hooks.use_state(Status::default);
+    let default = props.default;
+
+    hooks.use_terminal_events(move |event| {
+        if is_cancellation(&event) {
+            status.set(Status::Cancelled);
+            return;
+        }
+        let value = match pressed_key(&event) {
+            Some(KeyCode::Char('y' | 'Y')) => true,
+            Some(KeyCode::Char('n' | 'N')) => false,
+            Some(KeyCode::Enter) => default,
+            _ => return,
+        };
+        answer.set(Some(value));
+        status.set(Status::Submitted);
+    });
+
+    match (status.get(), answer.get()) {
+        (Status::Submitted, Some(answer)) => {
+            if let Some(out) = props.out.as_mut() {
+                **out = Some(answer);
+            }
+            system.exit();
+            summary(&props.message, Some(if answer { "yes" } else { "no" }))
+        }
+        (Status::Cancelled, _) => {
+            system.exit();
+            summary(&props.message, None)
+        }
+        _ => question(
+            &props.message,
+            if props.default { "(Y/n)" } else { "(y/N)" },
+        ),
+    }
+}
+
+#[derive(Default, Props)]
+struct SelectPromptProps<'a> {
+    message: String,
+    items: Vec<String>,
+    multiple: bool,
+    out: Option<&'a mut Option<Vec<usize>>>,
+}
+
+#[component]
+fn SelectPrompt<'a>(
+    mut hooks: Hooks,
+    props: &mut SelectPromptProps<'a>,
+) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut selected = hooks.use_state(Vec::new);
+    let mut status = hooks.use_state(Status::default);
+    let multiple = props.multiple;
+
+    hooks.use_terminal_events(move |event| {
+        if is_cancellation(&event) {
+            status.set(Status::Cancelled);
+        } else if multiple && pressed_key(&event) == Some(KeyCode::Enter) {
+            // In single-select mode, the list submits the highlighted item itself.
+            status.set(Status::Submitted);
+        }
+    });
+
+    match status.get() {
+        Status::Pending => element! {
+            View(flex_direction: FlexDirection::Column) {
+                #(question(
+                    &props.message,
+                    if multiple { "(space to toggle, enter to confirm)" } else { "" },
+                ))
+                SelectList(
+                    items: props.items.iter().map(SelectItem::new).collect::<Vec<_>>(),
+                    multiple,
+                    checked: multiple.then(|| selected.read().clone()),
+                    on_check: move |checked| selected.set(checked),
+                    on_submit: move |i| {
+                        if !multiple {
+                            selected.set(vec![i]);
+                            status.set(Status::Submitted);
+                        }
+                    },
+                    has_focus: true,
+                )
+            }
+        }
+        .into_any(),
+        Status::Submitted => {
+            let selected = selected.read().clone();
+            let answer = selected
+                .iter()
+                .map(|&i| props.items[i].as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            if let Some(out) = props.out.as_mut() {
+                **out = Some(selected);
+            }
+            system.exit();
+            summary(&props.message, Some(&answer))
+        }
+        Status::Cancelled => {
+            system.exit();
+            summary(&props.message, None)
+        }
+    }
+}
+
+async fn run<E: ElementExt + Send>(mut element: E) -> io::Result<()> {
+    if !io::stdout().is_terminal() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "prompts require stdout to be a terminal",
+        ));
+    }
+    let mut terminal = Terminal::new(ColorDepth::detect())?;
+    // The prompts handle Ctrl-C like Esc, so that the summary shows they were cancelled.
+    terminal.ignore_ctrl_c();
+    terminal_render_loop(&mut element, terminal).await
+}
+
+fn answer<T>(answer: Option<T>) -> io::Result<T> {
+    answer.ok_or_else(|| io::Error::new(io::ErrorKind::Interrupted, "prompt was cancelled"))
+}
+
+fn select_items<I, S>(items: I) -> io::Result<Vec<String>>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let items = items.into_iter().map(Into::into).collect::<Vec<_>>();
+    if items.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "there are no items to select from",
+        ));
+    }
+    Ok(items)
+}
+
+/// Asks the user to enter a line of text.
+pub async fn input(message: &str) -> io::Result<String> {
+    let mut value = None;
+    run(element!(InputPrompt(message, out: &mut value))).await?;
+    answer(value)
+}
+
+/// Asks the user to enter a line of text without displaying it, e.g. for passwords. Each typed
+/// character is shown as an asterisk.
+pub async fn password(message: &str) -> io::Result<String> {
+    let mut value = None;
+    run(element!(InputPrompt(message, password: true, out: &mut value))).await?;
+    answer(value)
+}
+
+/// Asks the user a yes or no question, which they can answer with Y or N. Pressing Enter answers
+/// with the given default.
+pub async fn confirm(message: &str, default: bool) -> io::Result<bool> {
+    let mut value = None;
+    run(element!(ConfirmPrompt(message, default, out: &mut value))).await?;
+    answer(value)
+}
+
+/// Asks the user to choose one of the given items, returning its index.
+///
+/// An error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) is returned if there are no
+/// items.
+pub async fn select<I, S>(message: &str, items: I) -> io::Result<usize>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut value: Option<Vec<usize>> = None;
+    run(element!(SelectPrompt(message, items: select_items(items)?, out: &mut value))).await?;
+    answer(value).map(|selected| selected[0])
+}
+
+/// Asks the user to choose any number of the given items, returning their indices in ascending
+/// order.
+///
+/// An error of kind [`InvalidInput`](io::ErrorKind::InvalidInput) is returned if there are no
+/// items.
+pub async fn multi_select<I, S>(message: &str, items: I) -> io::Result<Vec<usize>>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut value = None;
+    run(element!(SelectPrompt(
+        message,
+        items: select_items(items)?,
+        multiple: true,
+        out: &mut value,
+    )))
+    .await?;
+    answer(value)
+}
+
+/// Blocking equivalents of the prompt functions, for use in synchronous code.
+pub mod blocking {
+    use futures::executor::block_on;
+    use std::io;
+
+    /// Asks the user to enter a line of text. See [`super::input`].
+    pub fn input(message: &str) -> io::Result<String> {
+        block_on(super::input(message))
+    }
+
+    /// Asks the user to enter a line of text without displaying it. See [`super::password`].
+    pub fn password(message: &str) -> io::Result<String> {
+        block_on(super::password(message))
+    }
+
+    /// Asks the user a yes or no question. See [`super::confirm`].
+    pub fn confirm(message: &str, default: bool) -> io::Result<bool> {
+        block_on(super::confirm(message, default))
+    }
+
+    /// Asks the user to choose one of the given items. See [`super::select`].
+    pub fn select<I, S>(message: &str, items: I) -> io::Result<usize>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        block_on(super::select(message, items))
+    }
+
+    /// Asks the user to choose any number of the given items. See [`super::multi_select`].
+    pub fn multi_select<I, S>(message: &str, items: I) -> io::Result<Vec<usize>>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        block_on(super::multi_select(message, items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        prelude::*,
+        test_utils::{key, render_element_with_events, render_with_event_batches},
+    };
+    use macro_rules_attribute::apply;
+    use smol_macros::test;
+
+    fn chars(s: &str) -> Vec<TerminalEvent> {
+        s.chars().map(|c| key(KeyCode::Char(c))).collect()
+    }
+
+    fn ctrl_c() -> TerminalEvent {
+        TerminalEvent::Key(KeyEvent {
+            code: KeyCode::Char('c'),
+            modifiers: KeyModifiers::CONTROL,
+            kind: KeyEventKind::Press,
+        })
+    }
+
+    // Prompts exit on their own once answered, so most of these tests don't need the harness.
+    async fn render_with_events<E: ElementExt>(element: E, events: Vec<TerminalEvent>) -> String {
+        render_element_with_events(element, events)
+            .await
+            .pop()
+            .unwrap()
+            .to_string()
+    }
+
+    #[apply(test!)]
+    async fn test_input() {
+        let mut value = None;
+        let events = [chars("Ferris"), vec![key(KeyCode::Enter)]].concat();
+        let canvas = render_with_events(
+            element!(InputPrompt(message: "Name?", out: &mut value)),
+            events,
+        )
+        .await;
+        assert_eq!(canvas, "✔ Name? · Ferris\n");
+        assert_eq!(value, Some("Ferris".to_string()));
+
+        let mut value = None;
+        let events = [chars("hunter2"), vec![key(KeyCode::Enter)]].concat();
+        let canvas = render_with_events(
+            element!(InputPrompt(message: "Password?", password: true, out: &mut value)),
+            events,
+        )
+        .await;
+        assert_eq!(canvas, "✔ Password? · [hidden]\n");
+        assert_eq!(value, Some("hunter2".to_string()));
+
+        // Passwords are never written, not even while they're being typed.
+        let canvases = render_with_event_batches(
+            || {
+                element! {
+                    View(width: 40, flex_direction: FlexDirection::Column) {
+                        InputPrompt(message: "Password?", password: true)
+                    }
+                }
+                .into_any()
+            },
+            vec![chars("hunter2"), vec![key(KeyCode::Enter)]],
+        )
+        .await;
+        let canvases: Vec<_> = canvases.iter().map(|c| c.to_string()).collect();
+        // The trailing space is the cursor.
+        assert!(canvases.contains(&"? Password? ******* \n".to_string()));
+        assert!(canvases.iter().all(|c| !c.contains("hunter2")));
+
+        let mut value: Option<String> = None;
+        let events = [chars("abc"), vec![key(KeyCode::Esc)]].concat();
+        let canvas = render_with_events(
+            element!(InputPrompt(message: "Name?", out: &mut value)),
+            events,
+        )
+        .await;
+        assert_eq!(canvas, "✘ Name?\n");
+        assert_eq!(value, None);
+    }
+
+    #[apply(test!)]
+    async fn test_ctrl_c() {
+        let mut value: Option<String> = None;
+        let events = [chars("abc"), vec![ctrl_c()]].concat();
+        let canvas = render_with_events(
+            element!(InputPrompt(message: "Name?", out: &mut value)),
+            events,
+        )
+        .await;
+        assert_eq!(canvas, "✘ Name?\n");
+        assert_eq!(value, None);
+
+        let mut value: Option<bool> = None;
+        let canvas = render_with_events(
+            element!(ConfirmPrompt(message: "Continue?", out: &mut value)),
+            vec![ctrl_c()],
+        )
+        .await;
+        assert_eq!(canvas, "✘ Continue?\n");
+        assert_eq!(value, None);
+
+        let mut value: Option<Vec<usize>> = None;
+        let canvas = render_with_events(
+            element!(SelectPrompt(
+                message: "Colors?",
+                items: vec!["Red".to_string()],
+                multiple: true,
+                out: &mut value,
+            )),
+            vec![key(KeyCode::Char(' ')), ctrl_c()],
+        )
+        .await;
+        assert_eq!(canvas, "✘ Colors?\n");
+        assert_eq!(value, None);
+    }
+
+    #[apply(test!)]
+    async fn test_confirm() {
+        assert_eq!(
+            element!(ConfirmPrompt(message: "Continue?", default: true)).to_string(),
+            "? Continue? (Y/n)\n"
+        );
+
+        for (default, events, expected) in [
+            (true, vec![key(KeyCode::Enter)], Some(true)),
+            (true, vec![key(KeyCode::Char('n'))], Some(false)),
+            (
+                false,
+                vec![key(KeyCode::Char('x')), key(KeyCode::Enter)],
+                Some(false),
+            ),
+            (false, vec![key(KeyCode::Char('Y'))], Some(true)),
+            (false, vec![key(KeyCode::Esc)], None),
+        ] {
+            let mut value = None;
+            render_with_events(
+                element!(ConfirmPrompt(message: "Continue?", default, out: &mut value)),
+                events,
+            )
+            .await;
+            assert_eq!(value, expected);
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_select() {
+        let items = vec!["Red".to_string(), "Green".to_string(), "Blue".to_string()];
+
+        assert_eq!(
+            element!(SelectPrompt(message: "Color?", items: items.clone())).to_string(),
+            "? Color?\n❯ Red\n  Green\n  Blue\n"
+        );
+
+        let mut value = None;
+        let canvas = render_with_events(
+            element!(SelectPrompt(message: "Color?", items: items.clone(), out: &mut value)),
+            vec![key(KeyCode::Down), key(KeyCode::Enter)],
+        )
+        .await;
+        assert_eq!(canvas, "✔ Color? · Green\n");
+        assert_eq!(value, Some(vec![1]));
+
+        let mut value = None;
+        let canvas = render_with_events(
+            element!(SelectPrompt(
+                message: "Colors?",
+                items: items.clone(),
+                multiple: true,
+                out: &mut value,
+            )),
+            vec![
+                key(KeyCode::Char(' ')),
+                key(KeyCode::Down),
+                key(KeyCode::Down),
+                key(KeyCode::Char(' ')),
+                key(KeyCode::Enter),
+            ],
+        )
+        .await;
+        assert_eq!(canvas, "✔ Colors? · Red, Blue\n");
+        assert_eq!(value, Some(vec![0, 2]));
+
+        let mut value: Option<Vec<usize>> = None;
+        let canvas = render_with_events(
+            element!(SelectPrompt(message: "Color?", items, out: &mut value)),
+            vec![key(KeyCode::Esc)],
+        )
+        .await;
+        assert_eq!(canvas, "✘ Color?\n");
+        assert_eq!(value, None);
+    }
+
+    #[test]
+    fn test_select_without_items() {
+        let err = blocking::select("Color?", Vec::<String>::new()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -1,10 +1,11 @@
 use crate::{
     canvas::{Canvas, CanvasSubviewMut},
     component::{ComponentHelperExt, Components, InstantiatedComponent},
-    context::{Context, ContextStack, SystemContext},
+    context::{Context, ContextStack, SystemContext, TerminalSizeContext},
     element::ElementExt,
     multimap::AppendOnlyMultimap,
     props::AnyProps,
+    style::{ContentSizing, LayoutStyle},
     terminal::{MockTerminalConfig, MockTerminalOutputStream, Terminal, TerminalEvents},
 };
 use core::{
@@ -18,15 +19,19 @@ use futures::{
     future::{select, FutureExt, LocalBoxFuture},
     stream::{Stream, StreamExt},
 };
-use std::io;
+use std::{collections::BTreeSet, io};
 use taffy::{
-    AvailableSpace, Display, Layout, NodeId, Overflow, Point, Rect, Size, Style, TaffyTree,
+    AvailableSpace, BoxSizing, Display, Layout, NodeId, Overflow, Point, Rect, Size, Style,
+    TaffyTree,
 };
 
 pub(crate) struct UpdateContext<'a> {
     terminal: Option<&'a mut Terminal>,
     layout_engine: &'a mut LayoutEngine,
     did_clear_terminal_output: bool,
+    // Whether any component has content-based sizes which need to be resolved before the layout
+    // is computed.
+    has_content_sizing: bool,
 }
 
 /// Provides information and operations that low level component implementations may need to
@@ -34,6 +39,7 @@ pub(crate) struct UpdateContext<'a> {
 pub struct ComponentUpdater<'a, 'b: 'a, 'c: 'a> {
     node_id: NodeId,
     transparent_layout: bool,
+    z_index: Option<i32>,
     children: &'a mut Components,
     unattached_child_node_ids: &'a mut Vec<NodeId>,
     context: &'a mut UpdateContext<'b>,
@@ -51,6 +57,7 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
         Self {
             node_id,
             transparent_layout: false,
+            z_index: None,
             children,
             unattached_child_node_ids,
             context,
@@ -84,18 +91,36 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
         }
     }
 
+    /// Returns whether the component is being rendered to a terminal, as opposed to being rendered
+    /// once, e.g. via [`ElementExt::to_string`].
+    pub(crate) fn is_rendering_to_terminal(&self) -> bool {
+        self.context.terminal.is_some()
+    }
+
+    /// Renders the given element and writes it to the terminal above the currently rendered
+    /// output. Unlike the rest of the output, it will never be cleared or redrawn.
+    pub(crate) fn write_static_output<E: ElementExt>(&mut self, e: E) {
+        if let Some(terminal) = self.context.terminal.as_mut() {
+            let canvas = render(e, terminal.width().map(|w| w as usize));
+            // Errors are ignored here. If the terminal can't be written to, the render loop's own
+            // write of the canvas will fail and report it.
+            let _ = terminal.write_static_canvas(&canvas);
+            self.context.did_clear_terminal_output = true;
+        }
+    }
+
     #[doc(hidden)]
     pub fn component_context_stack(&self) -> &ContextStack<'c> {
         self.component_context_stack
     }
 
     /// Gets an immutable reference to context of the given type.
-    pub fn get_context<T: Any>(&self) -> Option<Ref<T>> {
+    pub fn get_context<T: Any>(&self) -> Option<Ref<'_, T>> {
         self.component_context_stack.get_context()
     }
 
     /// Gets a mutable reference to context of the given type.
-    pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<T>> {
+    pub fn get_context_mut<T: Any>(&self) -> Option<RefMut<'_, T>> {
         self.component_context_stack.get_context_mut()
     }
 
@@ -107,14 +132,61 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
             .expect("we should be able to set the style");
     }
 
-    /// Sets the measure function of the current component, which is invoked to calculate the area
-    /// that the component's content should occupy.
-    pub fn set_measure_func(&mut self, measure_func: MeasureFunc) {
+    /// Sets the layout style of the current component from layout style props, such as those
+    /// returned by the `layout_style` method of props using `#[with_layout_style_props]`.
+    ///
+    /// Unlike converting the props to a [`taffy::style::Style`] and passing it to
+    /// [`ComponentUpdater::set_layout_style`], this also applies the props which the layout
+    /// engine doesn't support directly, such as `order`, `z_index`, and content-based sizes like
+    /// [`Size::MinContent`](crate::Size::MinContent).
+    pub fn set_layout_style_props(&mut self, layout_style: LayoutStyle) {
+        self.set_layout_style_props_with(layout_style, |_| {});
+    }
+
+    /// Like [`ComponentUpdater::set_layout_style_props`], but allows the style to be adjusted
+    /// before it's applied, e.g. to set properties which aren't exposed as props.
+    pub fn set_layout_style_props_with<F>(&mut self, layout_style: LayoutStyle, f: F)
+    where
+        F: FnOnce(&mut taffy::style::Style),
+    {
+        self.set_order(layout_style.order);
+        self.set_z_index(layout_style.z_index);
+        let content_sizing = layout_style.content_sizing();
+        self.context.has_content_sizing |= content_sizing.is_intrinsic();
+        self.node_context_mut().content_sizing = content_sizing;
+        let mut style = layout_style.into();
+        f(&mut style);
+        self.set_layout_style(style);
+    }
+
+    /// Sets the order in which the current component is laid out relative to its siblings.
+    /// Components are laid out in ascending order, with components of the same order keeping
+    /// their order in the tree.
+    pub fn set_order(&mut self, order: i32) {
+        self.node_context_mut().order = order;
+    }
+
+    /// Sets the offset by which the current component's children are scrolled. When drawn, the
+    /// children are shifted up and to the left by this amount.
+    ///
+    /// The offset is clamped so that content can't be scrolled past its end, so `u16::MAX` can be
+    /// used to scroll to the end. For content to be scrollable along an axis, the component's
+    /// overflow must be set to [`Overflow::Scroll`] for that axis.
+    pub fn set_scroll_offset(&mut self, scroll_offset: Point<u16>) {
+        self.node_context_mut().scroll_offset = scroll_offset;
+    }
+
+    fn node_context_mut(&mut self) -> &mut LayoutEngineNodeContext {
         self.context
             .layout_engine
             .get_node_context_mut(self.node_id)
             .expect("we should be able to get the node")
-            .measure_func = Some(measure_func);
+    }
+
+    /// Sets the measure function of the current component, which is invoked to calculate the area
+    /// that the component's content should occupy.
+    pub fn set_measure_func(&mut self, measure_func: MeasureFunc) {
+        self.node_context_mut().measure_func = Some(measure_func);
         self.context
             .layout_engine
             .mark_dirty(self.node_id)
@@ -144,6 +216,18 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
         self.transparent_layout
     }
 
+    /// Sets the z-index of the current component, which determines the order in which it and its
+    /// descendants are drawn relative to other components. Components with a higher z-index are
+    /// drawn later, on top of those with a lower one. If `None`, the component is drawn as part of
+    /// its parent's layer.
+    pub fn set_z_index(&mut self, z_index: Option<i32>) {
+        self.z_index = z_index;
+    }
+
+    pub(crate) fn z_index(&self) -> Option<i32> {
+        self.z_index
+    }
+
     /// Updates the children of the current component.
     pub fn update_children<I, T>(&mut self, children: I, context: Option<Context>)
     where
@@ -195,6 +279,12 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
                     used_components.push_back(child.key().clone(), component);
                 }
 
+                let layout_engine = &mut self.context.layout_engine;
+                direct_child_node_ids.sort_by_key(|&node_id| {
+                    layout_engine
+                        .get_node_context(node_id)
+                        .map_or(0, |cx| cx.order)
+                });
                 self.context
                     .layout_engine
                     .set_children(self.node_id, &direct_child_node_ids)
@@ -214,6 +304,8 @@ impl<'a, 'b, 'c> ComponentUpdater<'a, 'b, 'c> {
 struct DrawContext<'a> {
     layout_engine: &'a LayoutEngine,
     canvas: &'a mut Canvas,
+    // The layer currently being drawn. Each layer is drawn in a separate pass over the tree.
+    layer: i32,
 }
 
 /// Provides information and operations that low level component implementations may need to
@@ -223,6 +315,8 @@ pub struct ComponentDrawer<'a> {
     node_position: Point<i16>,
     node_size: Size<u16>,
     clip_rect: Rect<u16>,
+    // The layer that the current node belongs to.
+    node_layer: i32,
     context: DrawContext<'a>,
 }
 
@@ -236,6 +330,17 @@ impl ComponentDrawer<'_> {
             .expect("we should be able to get the layout")
     }
 
+    /// Gets the calculated layouts of the current node's children, in layout order.
+    pub(crate) fn child_layouts(&self) -> Vec<Layout> {
+        let layout_engine = &self.context.layout_engine;
+        layout_engine
+            .children(self.node_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|node_id| layout_engine.layout(node_id).ok().copied())
+            .collect()
+    }
+
     /// Gets the style of the current node.
     pub fn style(&self) -> &Style {
         self.context
@@ -254,8 +359,52 @@ impl ComponentDrawer<'_> {
         self.node_position
     }
 
+    /// Gets the offset by which the children of the component are scrolled, after clamping it to
+    /// the scrollable range. See [`ComponentUpdater::set_scroll_offset`].
+    pub fn scroll_offset(&self) -> Point<u16> {
+        let offset = self
+            .context
+            .layout_engine
+            .get_node_context(self.node_id)
+            .map(|context| context.scroll_offset)
+            .unwrap_or_default();
+        let max_offset = self.max_scroll_offset();
+        Point {
+            x: offset.x.min(max_offset.x),
+            y: offset.y.min(max_offset.y),
+        }
+    }
+
+    /// Gets the largest offset by which the children of the component can be scrolled, which is
+    /// zero for axes along which the content fits or the component's overflow isn't
+    /// [`Overflow::Scroll`].
+    pub fn max_scroll_offset(&self) -> Point<u16> {
+        let style = self.style();
+        let layout = self.layout();
+        let content_end = layout.content_size
+            + Size {
+                width: layout.padding.right,
+                height: layout.padding.bottom,
+            };
+        let viewport_end = Size {
+            width: layout.size.width - layout.border.right - layout.scrollbar_size.width,
+            height: layout.size.height - layout.border.bottom - layout.scrollbar_size.height,
+        };
+        let max_offset = |overflow, content_end: f32, viewport_end: f32| {
+            if overflow == Overflow::Scroll {
+                (content_end - viewport_end).max(0.0) as u16
+            } else {
+                0
+            }
+        };
+        Point {
+            x: max_offset(style.overflow.x, content_end.width, viewport_end.width),
+            y: max_offset(style.overflow.y, content_end.height, viewport_end.height),
+        }
+    }
+
     /// Gets the region of the canvas that the component should be drawn to.
-    pub fn canvas(&mut self) -> CanvasSubviewMut {
+    pub fn canvas(&mut self) -> CanvasSubviewMut<'_> {
         self.context.canvas.subview_mut(
             self.node_position.x as _,
             self.node_position.y as _,
@@ -275,11 +424,12 @@ impl ComponentDrawer<'_> {
         let old_node_id = self.node_id;
         let old_node_position = self.node_position;
         let old_node_size = self.node_size;
+        let scroll_offset = self.scroll_offset();
         self.node_id = node_id;
         let layout = self.layout();
         self.node_position = Point {
-            x: self.node_position.x + layout.location.x as i16,
-            y: self.node_position.y + layout.location.y as i16,
+            x: self.node_position.x + layout.location.x as i16 - scroll_offset.x as i16,
+            y: self.node_position.y + layout.location.y as i16 - scroll_offset.y as i16,
         };
         self.node_size = Size {
             width: layout.size.width as u16,
@@ -291,6 +441,24 @@ impl ComponentDrawer<'_> {
         self.node_size = old_node_size;
     }
 
+    /// Moves the current node into the layer given by its z-index, if any, and invokes the given
+    /// closure.
+    pub(crate) fn with_z_index<F>(&mut self, z_index: Option<i32>, f: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        let old_node_layer = self.node_layer;
+        self.node_layer = z_index.unwrap_or(self.node_layer);
+        f(self);
+        self.node_layer = old_node_layer;
+    }
+
+    /// Returns whether the current node belongs to the layer currently being drawn. Nodes in other
+    /// layers still need to be traversed, but shouldn't draw anything.
+    pub(crate) fn is_drawing_node_layer(&self) -> bool {
+        self.node_layer == self.context.layer
+    }
+
     /// Prepares to begin drawing a node's children by shrinking the clipping rectangle if necessary.
     pub(crate) fn with_clip_rect_for_children<F>(&mut self, f: F)
     where
@@ -311,7 +479,9 @@ impl ComponentDrawer<'_> {
                 .left
                 .max((self.node_position.x + layout.border.left as i16).max(0) as u16);
             self.clip_rect.right = self.clip_rect.right.min(
-                (self.node_position.x + self.node_size.width as i16 - layout.border.right as i16)
+                (self.node_position.x + self.node_size.width as i16
+                    - layout.border.right as i16
+                    - layout.scrollbar_size.width as i16)
                     .max(0) as u16,
             );
         }
@@ -321,7 +491,9 @@ impl ComponentDrawer<'_> {
                 .top
                 .max((self.node_position.y + layout.border.top as i16).max(0) as u16);
             self.clip_rect.bottom = self.clip_rect.bottom.min(
-                (self.node_position.y + self.node_size.height as i16 - layout.border.bottom as i16)
+                (self.node_position.y + self.node_size.height as i16
+                    - layout.border.bottom as i16
+                    - layout.scrollbar_size.height as i16)
                     .max(0) as u16,
             );
         }
@@ -338,10 +510,86 @@ pub type MeasureFunc =
 #[derive(Default)]
 pub(crate) struct LayoutEngineNodeContext {
     measure_func: Option<MeasureFunc>,
+    order: i32,
+    content_sizing: ContentSizing,
+    scroll_offset: Point<u16>,
 }
 
 pub(crate) type LayoutEngine = TaffyTree<LayoutEngineNodeContext>;
 
+fn measure_node(
+    known_dimensions: Size<Option<f32>>,
+    available_space: Size<AvailableSpace>,
+    _node_id: NodeId,
+    node_context: Option<&mut LayoutEngineNodeContext>,
+    style: &Style,
+) -> Size<f32> {
+    match node_context.and_then(|cx| cx.measure_func.as_ref()) {
+        Some(f) => f(known_dimensions, available_space, style),
+        None => Size::ZERO,
+    }
+}
+
+// Replaces content-based sizes with lengths. Descendants are resolved first so that they have
+// their final sizes when their ancestors' content is measured.
+fn resolve_content_sizing(layout_engine: &mut LayoutEngine, node_id: NodeId) {
+    for child_node_id in layout_engine
+        .children(node_id)
+        .expect("we should be able to get the children")
+    {
+        resolve_content_sizing(layout_engine, child_node_id);
+    }
+
+    let Some(content_sizing) = layout_engine
+        .get_node_context(node_id)
+        .map(|cx| cx.content_sizing)
+        .filter(ContentSizing::is_intrinsic)
+    else {
+        return;
+    };
+
+    let mut style = layout_engine
+        .style(node_id)
+        .expect("we should be able to get the style")
+        .clone();
+    let mut measure = |available_space| {
+        layout_engine
+            .compute_layout_with_measure(
+                node_id,
+                Size {
+                    width: available_space,
+                    height: available_space,
+                },
+                measure_node,
+            )
+            .expect("we should be able to compute the layout");
+        let layout = layout_engine
+            .layout(node_id)
+            .expect("we should be able to get the layout");
+        match style.box_sizing {
+            BoxSizing::BorderBox => layout.size,
+            BoxSizing::ContentBox => Size {
+                width: layout.size.width
+                    - layout.padding.left
+                    - layout.padding.right
+                    - layout.border.left
+                    - layout.border.right,
+                height: layout.size.height
+                    - layout.padding.top
+                    - layout.padding.bottom
+                    - layout.border.top
+                    - layout.border.bottom,
+            },
+        }
+    };
+    let min_content = measure(AvailableSpace::MinContent);
+    let max_content = measure(AvailableSpace::MaxContent);
+    content_sizing.resolve(&mut style, min_content, max_content);
+    layout_engine
+        .set_style(node_id, style)
+        .expect("we should be able to set the style");
+}
+
 struct Tree<'a> {
     layout_engine: LayoutEngine,
     wrapper_node_id: NodeId,
@@ -379,25 +627,38 @@ impl<'a> Tree<'a> {
         terminal: Option<&mut Terminal>,
     ) -> RenderOutput {
         let mut wrapper_child_node_ids = vec![self.root_component.node_id()];
-        let did_clear_terminal_output = {
+        let (did_clear_terminal_output, has_content_sizing) = {
+            let terminal_size = TerminalSizeContext(terminal.as_ref().and_then(|t| t.size()));
             let mut context = UpdateContext {
                 terminal,
                 layout_engine: &mut self.layout_engine,
                 did_clear_terminal_output: false,
+                has_content_sizing: false,
             };
             let mut component_context_stack = ContextStack::root(&mut self.system_context);
-            self.root_component.update(
-                &mut context,
-                &mut wrapper_child_node_ids,
-                &mut component_context_stack,
-                self.root_component_props.borrow(),
+            component_context_stack.with_context(
+                Some(Context::Ref(&terminal_size)),
+                |component_context_stack| {
+                    self.root_component.update(
+                        &mut context,
+                        &mut wrapper_child_node_ids,
+                        component_context_stack,
+                        self.root_component_props.borrow(),
+                    );
+                },
             );
-            context.did_clear_terminal_output
+            (
+                context.did_clear_terminal_output,
+                context.has_content_sizing,
+            )
         };
         self.layout_engine
             .set_children(self.wrapper_node_id, &wrapper_child_node_ids)
             .expect("we should be able to set the children");
 
+        if has_content_sizing {
+            resolve_content_sizing(&mut self.layout_engine, self.wrapper_node_id);
+        }
         self.layout_engine
             .compute_layout_with_measure(
                 self.wrapper_node_id,
@@ -407,12 +668,7 @@ impl<'a> Tree<'a> {
                         .unwrap_or(AvailableSpace::MaxContent),
                     height: AvailableSpace::MaxContent,
                 },
-                |known_dimensions, available_space, _node_id, node_context, style| {
-                    match node_context.and_then(|cx| cx.measure_func.as_ref()) {
-                        Some(f) => f(known_dimensions, available_space, style),
-                        None => Size::ZERO,
-                    }
-                },
+                measure_node,
             )
             .expect("we should be able to compute the layout");
 
@@ -428,28 +684,35 @@ impl<'a> Tree<'a> {
             .layout_engine
             .layout(self.root_component.node_id())
             .expect("we should be able to get the root layout");
-        let mut drawer = ComponentDrawer {
-            node_id: self.root_component.node_id(),
-            node_position: Point {
-                x: root_layout.location.x as _,
-                y: root_layout.location.y as _,
-            },
-            node_size: Size {
-                width: root_layout.size.width as _,
-                height: root_layout.size.height as _,
-            },
-            clip_rect: Rect {
-                left: 0,
-                right: wrapper_layout.size.width as _,
-                top: 0,
-                bottom: wrapper_layout.size.height as _,
-            },
-            context: DrawContext {
-                layout_engine: &self.layout_engine,
-                canvas: &mut canvas,
-            },
-        };
-        self.root_component.draw(&mut drawer);
+        // Layers are drawn from lowest to highest so that higher layers occlude lower ones.
+        let mut layers = BTreeSet::new();
+        self.root_component.collect_layers(0, &mut layers);
+        for layer in layers {
+            let mut drawer = ComponentDrawer {
+                node_id: self.root_component.node_id(),
+                node_position: Point {
+                    x: root_layout.location.x as _,
+                    y: root_layout.location.y as _,
+                },
+                node_size: Size {
+                    width: root_layout.size.width as _,
+                    height: root_layout.size.height as _,
+                },
+                clip_rect: Rect {
+                    left: 0,
+                    right: wrapper_layout.size.width as _,
+                    top: 0,
+                    bottom: wrapper_layout.size.height as _,
+                },
+                node_layer: 0,
+                context: DrawContext {
+                    layout_engine: &self.layout_engine,
+                    canvas: &mut canvas,
+                    layer,
+                },
+            };
+            self.root_component.draw(&mut drawer);
+        }
         RenderOutput {
             canvas,
             did_clear_terminal_output,
@@ -461,11 +724,15 @@ impl<'a> Tree<'a> {
         loop {
             let width = term.width().map(|w| w as usize);
             execute!(term, terminal::BeginSynchronizedUpdate,)?;
-            let output = self.render(width, Some(&mut term));
+            let mut output = self.render(width, Some(&mut term));
+            if let Some(max_height) = term.max_canvas_height() {
+                // Rows which scroll out of view can't be rewound and redrawn, so anything that
+                // doesn't fit needs to be cut off.
+                output
+                    .canvas
+                    .truncate_with_overflow_indicator(width, max_height);
+            }
             if output.did_clear_terminal_output || prev_canvas.as_ref() != Some(&output.canvas) {
-                if !output.did_clear_terminal_output {
-                    term.clear_canvas()?;
-                }
                 term.write_canvas(&output.canvas)?;
             }
             prev_canvas = Some(output.canvas);
@@ -488,7 +755,7 @@ pub(crate) fn render<E: ElementExt>(mut e: E, max_width: Option<usize>) -> Canva
     tree.render(max_width, None).canvas
 }
 
-pub(crate) async fn terminal_render_loop<E>(mut e: E, term: Terminal) -> io::Result<()>
+pub(crate) async fn terminal_render_loop<E>(e: &mut E, term: Terminal) -> io::Result<()>
 where
     E: ElementExt,
 {
@@ -525,8 +792,19 @@ where
     E: ElementExt + 'a,
 {
     let (term, output) = Terminal::mock(config);
+    mock_terminal_render_loop_with_terminal(e, term, output)
+}
+
+pub(crate) fn mock_terminal_render_loop_with_terminal<'a, E>(
+    mut e: E,
+    term: Terminal,
+    output: MockTerminalOutputStream,
+) -> MockTerminalRenderLoop<'a>
+where
+    E: ElementExt + 'a,
+{
     MockTerminalRenderLoop {
-        render_loop: terminal_render_loop(e, term).boxed_local(),
+        render_loop: async move { terminal_render_loop(&mut e, term).await }.boxed_local(),
         render_loop_is_done: false,
         output,
     }
@@ -594,6 +872,40 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[component]
+    fn TallComponent(hooks: Hooks) -> impl Into<AnyElement<'static>> {
+        let mut system = hooks.use_context_mut::<SystemContext>();
+        system.exit();
+        element! {
+            View(flex_direction: FlexDirection::Column) {
+                #((1..=5).map(|i| element! { Text(content: format!("line {}", i)) }))
+            }
+        }
+    }
+
+    #[apply(test!)]
+    async fn test_terminal_render_loop_overflow() {
+        let config = MockTerminalConfig {
+            height: Some(4),
+            ..Default::default()
+        };
+        let canvases: Vec<_> = mock_terminal_render_loop(element!(TallComponent), config)
+            .collect()
+            .await;
+        let actual = canvases.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(actual, vec!["line 1\nline 2\n… 3 more lines\n"]);
+
+        let config = MockTerminalConfig {
+            height: Some(6),
+            ..Default::default()
+        };
+        let canvases: Vec<_> = mock_terminal_render_loop(element!(TallComponent), config)
+            .collect()
+            .await;
+        let actual = canvases.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        assert_eq!(actual, vec!["line 1\nline 2\nline 3\nline 4\nline 5\n"]);
+    }
+
     async fn await_send_future<F: Future<Output = io::Result<()>> + Send>(f: F) {
         f.await.unwrap();
     }
@@ -602,7 +914,17 @@ mod tests {
     #[apply(test!)]
     async fn test_terminal_render_loop_send() {
         let (term, _output) = Terminal::mock(MockTerminalConfig::default());
-        await_send_future(terminal_render_loop(element!(MyComponent), term)).await;
+        await_send_future(terminal_render_loop(&mut element!(MyComponent), term)).await;
+    }
+
+    // Make sure the futures returned by the render loop methods can be sent across threads.
+    #[test]
+    fn test_render_loop_future_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        let mut e = element!(MyComponent);
+        assert_send(&e.render_loop());
+        assert_send(&e.render_loop_with_color_depth(ColorDepth::NoColor));
+        assert_send(&e.fullscreen_with_color_depth(ColorDepth::NoColor));
     }
 
     #[component]
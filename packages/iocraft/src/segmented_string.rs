@@ -3,6 +3,7 @@ use core::{
     fmt::{self, Display},
     mem,
 };
+use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// A `SegmentedString` is a string consisting of multiple segments, which don't have to be
@@ -93,7 +94,7 @@ impl<'a> SegmentedString<'a> {
         index: usize,
         offset: usize,
         end: usize,
-    ) -> SegmentedStringLineSegment {
+    ) -> SegmentedStringLineSegment<'_> {
         let text = self.segments[index][offset..end].trim_end_matches('\n');
         let width = text.width();
         SegmentedStringLineSegment {
@@ -129,7 +130,7 @@ impl<'a> SegmentedString<'a> {
     }
 
     /// Wraps the string into lines of a given width.
-    pub fn wrap(&self, width: usize) -> Vec<SegmentedStringLine> {
+    pub fn wrap(&self, width: usize) -> Vec<SegmentedStringLine<'_>> {
         if self.segments.is_empty() {
             return vec![];
         }
@@ -205,11 +206,11 @@ impl<'a> SegmentedString<'a> {
                         // This segment is too wide, we need to forcefully break it
                         let mut w = 0;
                         let mut start_idx = 0;
-                        for (idx, c) in segment.text.char_indices() {
+                        for (idx, g) in segment.text.grapheme_indices(true) {
                             if idx >= trailing_whitespace_idx {
                                 break;
                             }
-                            let char_width = c.width().unwrap_or(0);
+                            let char_width = g.width();
                             if w > 0 && w + char_width > width {
                                 // We have a full line
                                 current_line.push_segment(segment.substring(start_idx, idx));
@@ -398,6 +399,24 @@ mod tests {
             );
         }
 
+        {
+            // Forced breaks never split grapheme clusters.
+            let family = "👨\u{200d}👩\u{200d}👧";
+            let text = format!("{}{}{}e\u{301}e\u{301}", family, family, family);
+            let lines = SegmentedString::from(text.as_str())
+                .wrap(4)
+                .into_iter()
+                .map(|line| line.to_string())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                lines,
+                vec![
+                    format!("{}{}", family, family),
+                    format!("{}e\u{301}e\u{301}", family),
+                ]
+            );
+        }
+
         {
             let segmented_string: SegmentedString =
                 ["this is ", "a wrapping test"].into_iter().collect();
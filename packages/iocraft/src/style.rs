@@ -1,15 +1,22 @@
+use crate::canvas::{ansi_named_color, color_rgb, XTERM_PALETTE};
 use bitflags::bitflags;
 use iocraft_macros::with_layout_style_props;
+use std::ops::Range;
 use taffy::{
     geometry,
-    style::{Dimension, LengthPercentage, LengthPercentageAuto},
-    Rect, Style,
+    style::{
+        Dimension, LengthPercentage, LengthPercentageAuto, MaxTrackSizingFunction,
+        MinTrackSizingFunction, NonRepeatedTrackSizingFunction, TrackSizingFunction,
+    },
+    style_helpers::{line, span},
+    Line, Rect, Style,
 };
 
 // Re-export basic enum types.
 pub use crossterm::style::Color;
 pub use taffy::style::{
-    AlignContent, AlignItems, Display, FlexDirection, FlexWrap, JustifyContent, Overflow, Position,
+    AlignContent, AlignItems, AlignSelf, BoxSizing, Display, FlexDirection, FlexWrap, GridAutoFlow,
+    GridTrackRepetition, JustifyContent, JustifyItems, JustifySelf, Overflow, Position,
 };
 
 /// Defines a type that represents a percentage [0.0-100.0] and is convertible to any of the
@@ -18,6 +25,12 @@ pub use taffy::style::{
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Percent(pub f32);
 
+/// Defines a type that represents a share of the free space in a grid container. As a shorthand,
+/// you can express this in the [`element!`](crate::element!) macro using the `fr` suffix, e.g.
+/// `1fr`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Fraction(pub f32);
+
 macro_rules! impl_from_length {
     ($name:ident) => {
         impl From<i16> for $name {
@@ -167,12 +180,59 @@ new_size_type!(
     Margin::Length(0)
 );
 
-new_size_type!(
-    /// Defines a width or height of an element.
-    Size,
-    u32,
-    Size::Auto
-);
+/// Defines a width or height of an element.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Size {
+    /// The default behavior.
+    #[default]
+    Unset,
+    /// Automatically selects a suitable size.
+    Auto,
+    /// Sets an absolute value.
+    Length(u32),
+    /// Sets a percentage of the width or height of the parent.
+    Percent(f32),
+    /// Sets the smallest size the element can be without its content overflowing, e.g. the width
+    /// of the longest word in a text element.
+    ///
+    /// See [the MDN documentation for min-content](https://developer.mozilla.org/en-US/docs/Web/CSS/min-content).
+    MinContent,
+    /// Sets the size the element would be if it had unlimited space, e.g. the width of a text
+    /// element's content without any wrapping.
+    ///
+    /// See [the MDN documentation for max-content](https://developer.mozilla.org/en-US/docs/Web/CSS/max-content).
+    MaxContent,
+    /// Uses the available space, but no more than [`Size::MaxContent`] and no less than
+    /// [`Size::MinContent`].
+    ///
+    /// See [the MDN documentation for fit-content](https://developer.mozilla.org/en-US/docs/Web/CSS/fit-content).
+    FitContent,
+}
+
+impl Size {
+    // Whether the size depends on the element's content.
+    fn is_intrinsic(self) -> bool {
+        matches!(self, Size::MinContent | Size::MaxContent | Size::FitContent)
+    }
+}
+
+impl From<Size> for Dimension {
+    fn from(p: Size) -> Self {
+        match p {
+            // Content-based sizes are resolved to lengths separately, before the layout is
+            // computed.
+            Size::Unset | Size::Auto | Size::MinContent | Size::MaxContent | Size::FitContent => {
+                Dimension::Auto
+            }
+            Size::Length(l) => Dimension::Length(l as _),
+            Size::Percent(p) => Dimension::Percent(p / 100.0),
+        }
+    }
+}
+
+impl_from_length!(Size);
+impl_from_percent!(Size);
 
 new_size_type!(
     /// Sets the position of a positioned element.
@@ -180,7 +240,7 @@ new_size_type!(
     /// See [the MDN documentation for inset](https://developer.mozilla.org/en-US/docs/Web/CSS/inset).
     Inset,
     i32,
-    Size::Auto
+    Inset::Auto
 );
 
 /// Sets the initial main size of a flex item.
@@ -207,6 +267,211 @@ impl From<FlexBasis> for Dimension {
     }
 }
 
+/// Defines the size of a grid track, i.e. a row or column.
+///
+/// See [the MDN documentation for grid-template-columns](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-template-columns).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum GridTrack {
+    /// Sizes the track to fit its content, and lets it grow into any free space.
+    #[default]
+    Auto,
+    /// Sets an absolute value.
+    Length(u32),
+    /// Sets a percentage of the width or height of the grid container.
+    Percent(f32),
+    /// Sets a share of the free space in the grid container.
+    Fraction(f32),
+    /// Sizes the track to the smallest size which fits its content.
+    MinContent,
+    /// Sizes the track to the largest size its content could need.
+    MaxContent,
+    /// Sizes the track to fit its content, but no larger than the given value.
+    FitContent(u32),
+    /// Sizes the track to at least the first track size and at most the second.
+    MinMax(Box<GridTrack>, Box<GridTrack>),
+    /// Repeats the given tracks. This can only be used at the top level of a template.
+    Repeat(GridTrackRepetition, Vec<GridTrack>),
+}
+
+impl GridTrack {
+    /// Creates a track which is at least `min` and at most `max` in size.
+    ///
+    /// See [the MDN documentation for minmax](https://developer.mozilla.org/en-US/docs/Web/CSS/minmax).
+    pub fn minmax(min: impl Into<GridTrack>, max: impl Into<GridTrack>) -> Self {
+        Self::MinMax(Box::new(min.into()), Box::new(max.into()))
+    }
+
+    /// Creates a fragment which repeats the given tracks `count` times.
+    ///
+    /// See [the MDN documentation for repeat](https://developer.mozilla.org/en-US/docs/Web/CSS/repeat).
+    pub fn repeat<T: Into<GridTrack>>(count: u16, tracks: impl IntoIterator<Item = T>) -> Self {
+        Self::Repeat(
+            GridTrackRepetition::Count(count),
+            tracks.into_iter().map(Into::into).collect(),
+        )
+    }
+
+    fn min_sizing_function(&self) -> MinTrackSizingFunction {
+        match self {
+            Self::Length(l) => MinTrackSizingFunction::Fixed(LengthPercentage::Length(*l as _)),
+            Self::Percent(p) => MinTrackSizingFunction::Fixed(LengthPercentage::Percent(p / 100.0)),
+            Self::MinContent => MinTrackSizingFunction::MinContent,
+            Self::MaxContent => MinTrackSizingFunction::MaxContent,
+            _ => MinTrackSizingFunction::Auto,
+        }
+    }
+
+    fn max_sizing_function(&self) -> MaxTrackSizingFunction {
+        match self {
+            Self::Length(l) => MaxTrackSizingFunction::Fixed(LengthPercentage::Length(*l as _)),
+            Self::Percent(p) => MaxTrackSizingFunction::Fixed(LengthPercentage::Percent(p / 100.0)),
+            Self::Fraction(f) => MaxTrackSizingFunction::Fraction(*f),
+            Self::MinContent => MaxTrackSizingFunction::MinContent,
+            Self::MaxContent => MaxTrackSizingFunction::MaxContent,
+            Self::FitContent(l) => {
+                MaxTrackSizingFunction::FitContent(LengthPercentage::Length(*l as _))
+            }
+            _ => MaxTrackSizingFunction::Auto,
+        }
+    }
+
+    fn non_repeated(&self) -> NonRepeatedTrackSizingFunction {
+        match self {
+            Self::MinMax(min, max) => NonRepeatedTrackSizingFunction {
+                min: min.min_sizing_function(),
+                max: max.max_sizing_function(),
+            },
+            _ => NonRepeatedTrackSizingFunction {
+                min: self.min_sizing_function(),
+                max: self.max_sizing_function(),
+            },
+        }
+    }
+}
+
+impl From<&GridTrack> for TrackSizingFunction {
+    fn from(t: &GridTrack) -> Self {
+        match t {
+            GridTrack::Repeat(repetition, tracks) => TrackSizingFunction::Repeat(
+                *repetition,
+                tracks.iter().map(GridTrack::non_repeated).collect(),
+            ),
+            _ => TrackSizingFunction::Single(t.non_repeated()),
+        }
+    }
+}
+
+impl From<Fraction> for GridTrack {
+    fn from(f: Fraction) -> Self {
+        GridTrack::Fraction(f.0)
+    }
+}
+
+impl_from_length!(GridTrack);
+impl_from_percent!(GridTrack);
+
+/// Used by the [`element!`](crate::element!) macro to set a collection from an array whose
+/// elements have different types, e.g. `[1fr, 10, 2fr]`.
+#[doc(hidden)]
+pub fn set_items<T, const N: usize>(dest: &mut Vec<T>, items: [T; N]) {
+    *dest = items.into();
+}
+
+/// Defines a grid line at which a grid item starts or ends.
+///
+/// See [the MDN documentation for grid-row-start](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row-start).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GridPlacement {
+    /// Places the item automatically, according to the container's `grid_auto_flow`.
+    #[default]
+    Auto,
+    /// Places the item at the given line. Lines are numbered from 1, and negative values count
+    /// backwards from the end of the explicit grid.
+    Line(i16),
+    /// Makes the item span the given number of tracks.
+    Span(u16),
+}
+
+impl From<i16> for GridPlacement {
+    fn from(l: i16) -> Self {
+        GridPlacement::Line(l)
+    }
+}
+
+impl From<i32> for GridPlacement {
+    fn from(l: i32) -> Self {
+        GridPlacement::Line(l as _)
+    }
+}
+
+impl From<GridPlacement> for taffy::style::GridPlacement {
+    fn from(p: GridPlacement) -> Self {
+        match p {
+            GridPlacement::Auto => taffy::style::GridPlacement::Auto,
+            GridPlacement::Line(l) => line(l),
+            GridPlacement::Span(s) => span(s),
+        }
+    }
+}
+
+/// Defines the grid lines at which a grid item starts and ends in one axis.
+///
+/// This can be created from a single [`GridPlacement`] or line number, which sets the start, or
+/// from a range of line numbers. For example, `1..3` places an item in the first two tracks.
+///
+/// See [the MDN documentation for grid-row](https://developer.mozilla.org/en-US/docs/Web/CSS/grid-row).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GridLines {
+    /// The line at which the item starts.
+    pub start: GridPlacement,
+    /// The line at which the item ends.
+    pub end: GridPlacement,
+}
+
+impl<T: Into<GridPlacement>> From<T> for GridLines {
+    fn from(start: T) -> Self {
+        Self {
+            start: start.into(),
+            end: GridPlacement::Auto,
+        }
+    }
+}
+
+impl From<Range<i16>> for GridLines {
+    fn from(r: Range<i16>) -> Self {
+        Self {
+            start: r.start.into(),
+            end: r.end.into(),
+        }
+    }
+}
+
+impl From<Range<i32>> for GridLines {
+    fn from(r: Range<i32>) -> Self {
+        Self {
+            start: r.start.into(),
+            end: r.end.into(),
+        }
+    }
+}
+
+impl From<GridLines> for Line<taffy::style::GridPlacement> {
+    fn from(l: GridLines) -> Self {
+        let end = match (l.start, l.end) {
+            // Taffy can't auto-place an item in one axis if it has both lines set in the other,
+            // so express ranges as an equivalent span where possible.
+            (GridPlacement::Line(start), GridPlacement::Line(end)) if 0 < start && start < end => {
+                GridPlacement::Span((end - start) as _)
+            }
+            (_, end) => end,
+        };
+        Line {
+            start: l.start.into(),
+            end: end.into(),
+        }
+    }
+}
+
 /// A weight which can be applied to text.
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum Weight {
@@ -219,6 +484,144 @@ pub enum Weight {
     Light,
 }
 
+/// The style of the line used to underline text.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum UnderlineStyle {
+    /// A single straight line.
+    #[default]
+    Single,
+    /// Two straight lines.
+    Double,
+    /// A wavy line.
+    Curly,
+    /// A dotted line.
+    Dotted,
+    /// A dashed line.
+    Dashed,
+}
+
+/// The range of colors which can be displayed by a terminal.
+///
+/// When writing ANSI output, colors which can't be displayed are converted to the nearest color
+/// which can be.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorDepth {
+    /// No colors at all. Text attributes such as weight and decorations are still written.
+    NoColor,
+    /// The 16 standard ANSI colors.
+    Ansi16,
+    /// The 256 colors of the extended ANSI palette.
+    Ansi256,
+    /// 24-bit RGB colors.
+    TrueColor,
+}
+
+impl ColorDepth {
+    /// Detects the color depth of the terminal from the environment.
+    ///
+    /// If `FORCE_COLOR` is set, color is enabled even if it otherwise wouldn't be. Its value can
+    /// be `0` or `false` to disable color, `2` for 256 colors, or `3` for 24-bit colors. Otherwise,
+    /// if `NO_COLOR` is set to a non-empty value, color is disabled. Otherwise, the depth is
+    /// determined by `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        Self::detect_from(|name| std::env::var(name).ok())
+    }
+
+    fn detect_from(var: impl Fn(&str) -> Option<String>) -> Self {
+        let term = var("TERM");
+        let detected = if matches!(var("COLORTERM").as_deref(), Some("truecolor" | "24bit")) {
+            Self::TrueColor
+        } else {
+            match term.as_deref() {
+                Some("dumb") => Self::NoColor,
+                Some(term)
+                    if term.contains("truecolor")
+                        || term.contains("24bit")
+                        || term.contains("direct") =>
+                {
+                    Self::TrueColor
+                }
+                Some(term) if term.contains("256") => Self::Ansi256,
+                Some(_) => Self::Ansi16,
+                // Modern Windows consoles support 24-bit colors, but don't set `TERM`.
+                None if cfg!(windows) => Self::TrueColor,
+                None => Self::Ansi16,
+            }
+        };
+
+        if let Some(force) = var("FORCE_COLOR") {
+            return match force.as_str() {
+                "0" | "false" => Self::NoColor,
+                "2" => detected.max(Self::Ansi256),
+                "3" => Self::TrueColor,
+                _ => detected.max(Self::Ansi16),
+            };
+        }
+        if var("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            return Self::NoColor;
+        }
+        detected
+    }
+
+    /// Converts the color to the nearest one which can be displayed at this depth. Returns `None`
+    /// if no color can be displayed.
+    pub fn downgrade(self, color: Color) -> Option<Color> {
+        match (self, color) {
+            (Self::NoColor, _) => None,
+            (Self::TrueColor, color) => Some(color),
+            (Self::Ansi256, Color::Rgb { r, g, b }) => {
+                Some(Color::AnsiValue(nearest_ansi256(r, g, b)))
+            }
+            (Self::Ansi256, color) => Some(color),
+            (Self::Ansi16, Color::Rgb { .. } | Color::AnsiValue(16..)) => {
+                let rgb = color_rgb(color, &XTERM_PALETTE)?;
+                Some(ansi_named_color(nearest_ansi16(rgb)))
+            }
+            (Self::Ansi16, Color::AnsiValue(i)) => Some(ansi_named_color(i)),
+            (Self::Ansi16, color) => Some(color),
+        }
+    }
+}
+
+fn color_distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    let d = |a: u8, b: u8| (a as i32 - b as i32).unsigned_abs().pow(2);
+    d(r1, r2) + d(g1, g2) + d(b1, b2)
+}
+
+fn nearest_ansi16(rgb: (u8, u8, u8)) -> u8 {
+    (0..16)
+        .min_by_key(|&i| color_distance(rgb, XTERM_PALETTE[i as usize]))
+        .unwrap_or(0)
+}
+
+fn nearest_ansi256(r: u8, g: u8, b: u8) -> u8 {
+    // Find the nearest color in the 6x6x6 cube and the nearest gray, and use whichever is closer.
+    let cube_index = |v: u8| {
+        if v < 48 {
+            0
+        } else if v < 115 {
+            1
+        } else {
+            ((v as u32 - 35) / 40) as u8
+        }
+    };
+    let (ri, gi, bi) = (cube_index(r), cube_index(g), cube_index(b));
+    let cube = 16 + 36 * ri + 6 * gi + bi;
+    let average = (r as u32 + g as u32 + b as u32) / 3;
+    let gray = 232 + (average.saturating_sub(3) / 10).min(23) as u8;
+    let distance = |i: u8| {
+        color_distance(
+            (r, g, b),
+            color_rgb(Color::AnsiValue(i), &XTERM_PALETTE).unwrap_or_default(),
+        )
+    };
+    if distance(gray) < distance(cube) {
+        gray
+    } else {
+        cube
+    }
+}
+
 bitflags! {
     /// Defines the edges of an element, e.g. for border styling.
     #[derive(Clone, Copy, Debug, Default, PartialEq)]
@@ -242,6 +645,101 @@ pub struct LayoutStyle {
     // fields added by proc macro, defined in ../macros/src/lib.rs
 }
 
+impl LayoutStyle {
+    pub(crate) fn content_sizing(&self) -> ContentSizing {
+        ContentSizing {
+            width: self.width,
+            height: self.height,
+            min_width: self.min_width,
+            min_height: self.min_height,
+            max_width: self.max_width,
+            max_height: self.max_height,
+        }
+    }
+}
+
+/// The size properties of an element which may depend on its content. Since the layout engine
+/// doesn't support these natively, they're resolved to lengths before the layout is computed.
+#[derive(Clone, Copy, Default)]
+pub(crate) struct ContentSizing {
+    width: Size,
+    height: Size,
+    min_width: Size,
+    min_height: Size,
+    max_width: Size,
+    max_height: Size,
+}
+
+impl ContentSizing {
+    pub(crate) fn is_intrinsic(&self) -> bool {
+        [
+            self.width,
+            self.height,
+            self.min_width,
+            self.min_height,
+            self.max_width,
+            self.max_height,
+        ]
+        .into_iter()
+        .any(Size::is_intrinsic)
+    }
+
+    /// Replaces the content-based sizes in the style with lengths, given the element's
+    /// min-content and max-content sizes.
+    pub(crate) fn resolve(
+        &self,
+        style: &mut Style,
+        min_content: geometry::Size<f32>,
+        max_content: geometry::Size<f32>,
+    ) {
+        resolve_content_sizing_axis(
+            [self.width, self.min_width, self.max_width],
+            [
+                &mut style.size.width,
+                &mut style.min_size.width,
+                &mut style.max_size.width,
+            ],
+            (min_content.width, max_content.width),
+        );
+        resolve_content_sizing_axis(
+            [self.height, self.min_height, self.max_height],
+            [
+                &mut style.size.height,
+                &mut style.min_size.height,
+                &mut style.max_size.height,
+            ],
+            (min_content.height, max_content.height),
+        );
+    }
+}
+
+// Resolves the size, min size, and max size of one axis.
+fn resolve_content_sizing_axis(
+    [size, min_size, max_size]: [Size; 3],
+    [style_size, style_min_size, style_max_size]: [&mut Dimension; 3],
+    (min_content, max_content): (f32, f32),
+) {
+    let resolve = |size: Size, fit_content: f32, dimension: &mut Dimension| match size {
+        Size::MinContent => *dimension = Dimension::Length(min_content),
+        Size::MaxContent => *dimension = Dimension::Length(max_content),
+        Size::FitContent => *dimension = Dimension::Length(fit_content),
+        _ => {}
+    };
+    resolve(min_size, min_content, style_min_size);
+    resolve(max_size, max_content, style_max_size);
+    if size == Size::FitContent {
+        // Let the element take the available space, within the limits of its content.
+        if matches!(min_size, Size::Unset | Size::Auto) {
+            *style_min_size = Dimension::Length(min_content);
+        }
+        if matches!(max_size, Size::Unset | Size::Auto) {
+            *style_max_size = Dimension::Length(max_content);
+        }
+    } else {
+        resolve(size, max_content, style_size);
+    }
+}
+
 impl From<LayoutStyle> for Style {
     fn from(s: LayoutStyle) -> Self {
         Self {
@@ -285,15 +783,125 @@ impl From<LayoutStyle> for Style {
                 y: s.overflow_y.or(s.overflow).unwrap_or_default(),
             },
             position: s.position,
+            box_sizing: s.box_sizing,
+            aspect_ratio: s.aspect_ratio,
             flex_direction: s.flex_direction,
             flex_wrap: s.flex_wrap,
             flex_basis: s.flex_basis.into(),
             flex_grow: s.flex_grow,
             flex_shrink: s.flex_shrink.unwrap_or(1.0),
             align_items: s.align_items,
+            align_self: s.align_self,
+            justify_items: s.justify_items,
+            justify_self: s.justify_self,
             align_content: s.align_content,
             justify_content: s.justify_content,
+            grid_template_rows: s.grid_template_rows.iter().map(Into::into).collect(),
+            grid_template_columns: s.grid_template_columns.iter().map(Into::into).collect(),
+            grid_auto_rows: s
+                .grid_auto_rows
+                .iter()
+                .map(GridTrack::non_repeated)
+                .collect(),
+            grid_auto_columns: s
+                .grid_auto_columns
+                .iter()
+                .map(GridTrack::non_repeated)
+                .collect(),
+            grid_auto_flow: s.grid_auto_flow,
+            grid_row: s.grid_row.into(),
+            grid_column: s.grid_column.into(),
             ..Default::default()
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_color_depth_detect() {
+        let detect = |vars: &[(&str, &str)]| {
+            ColorDepth::detect_from(|name| {
+                vars.iter()
+                    .find(|(k, _)| *k == name)
+                    .map(|(_, v)| v.to_string())
+            })
+        };
+        assert_eq!(detect(&[("TERM", "xterm")]), ColorDepth::Ansi16);
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorDepth::Ansi256);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(detect(&[("TERM", "dumb")]), ColorDepth::NoColor);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")]),
+            ColorDepth::NoColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect(&[("TERM", "dumb"), ("NO_COLOR", "1"), ("FORCE_COLOR", "1")]),
+            ColorDepth::Ansi16
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("FORCE_COLOR", "")]),
+            ColorDepth::Ansi256
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm"), ("FORCE_COLOR", "3")]),
+            ColorDepth::TrueColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("FORCE_COLOR", "0")]),
+            ColorDepth::NoColor
+        );
+    }
+
+    #[test]
+    fn test_color_depth_downgrade() {
+        let orange = Color::Rgb {
+            r: 255,
+            g: 135,
+            b: 0,
+        };
+        assert_eq!(ColorDepth::TrueColor.downgrade(orange), Some(orange));
+        assert_eq!(
+            ColorDepth::Ansi256.downgrade(orange),
+            Some(Color::AnsiValue(208))
+        );
+        assert_eq!(
+            ColorDepth::Ansi256.downgrade(Color::Rgb {
+                r: 128,
+                g: 128,
+                b: 130
+            }),
+            Some(Color::AnsiValue(244))
+        );
+        for (r, ansi) in [(47, 21), (48, 57), (60, 57), (114, 57), (115, 93)] {
+            assert_eq!(
+                ColorDepth::Ansi256.downgrade(Color::Rgb { r, g: 0, b: 255 }),
+                Some(Color::AnsiValue(ansi)),
+                "r = {r}"
+            );
+        }
+        assert_eq!(ColorDepth::Ansi16.downgrade(orange), Some(Color::Yellow));
+        assert_eq!(
+            ColorDepth::Ansi16.downgrade(Color::AnsiValue(196)),
+            Some(Color::Red)
+        );
+        assert_eq!(
+            ColorDepth::Ansi16.downgrade(Color::AnsiValue(4)),
+            Some(Color::DarkBlue)
+        );
+        assert_eq!(
+            ColorDepth::Ansi16.downgrade(Color::Reset),
+            Some(Color::Reset)
+        );
+        assert_eq!(ColorDepth::NoColor.downgrade(Color::Red), None);
+    }
+}
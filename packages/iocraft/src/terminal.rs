@@ -1,4 +1,4 @@
-use crate::canvas::Canvas;
+use crate::{canvas::Canvas, style::ColorDepth};
 use crossterm::{
     cursor,
     event::{self, Event, EventStream},
@@ -113,19 +113,27 @@ impl Stream for TerminalEvents {
 
 trait TerminalImpl: Write + Send {
     fn width(&self) -> Option<u16>;
+    fn height(&self) -> Option<u16>;
+    fn is_fullscreen(&self) -> bool;
     fn is_raw_mode_enabled(&self) -> bool;
     fn clear_canvas(&mut self) -> io::Result<()>;
     fn write_canvas(&mut self, canvas: &Canvas) -> io::Result<()>;
+    fn write_static_canvas(&mut self, canvas: &Canvas) -> io::Result<()>;
     fn event_stream(&mut self) -> io::Result<BoxStream<'static, TerminalEvent>>;
+
+    /// Called when the terminal has been resized.
+    fn handle_resize(&mut self) {}
 }
 
 struct StdTerminal {
     input_is_terminal: bool,
-    dest: io::Stdout,
+    dest: Box<dyn Write + Send>,
     fullscreen: bool,
     raw_mode_enabled: bool,
     enabled_keyboard_enhancement: bool,
-    prev_canvas_height: u16,
+    prev_canvas: Option<Canvas>,
+    is_resized: bool,
+    color_depth: ColorDepth,
 }
 
 impl Write for StdTerminal {
@@ -143,15 +151,31 @@ impl TerminalImpl for StdTerminal {
         terminal::size().ok().map(|(w, _)| w)
     }
 
+    fn height(&self) -> Option<u16> {
+        terminal::size().ok().map(|(_, h)| h)
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        self.fullscreen
+    }
+
     fn is_raw_mode_enabled(&self) -> bool {
         self.raw_mode_enabled
     }
 
     fn clear_canvas(&mut self) -> io::Result<()> {
-        if self.prev_canvas_height == 0 {
-            return Ok(());
+        let prev_canvas_height = match self.prev_canvas.take() {
+            Some(prev_canvas) if prev_canvas.height() > 0 => prev_canvas.height(),
+            _ => return Ok(()),
+        };
+        let lines_to_rewind = prev_canvas_height - if self.fullscreen { 1 } else { 0 };
+        if lines_to_rewind == 0 {
+            return queue!(
+                self.dest,
+                cursor::MoveToColumn(0),
+                terminal::Clear(terminal::ClearType::FromCursorDown)
+            );
         }
-        let lines_to_rewind = self.prev_canvas_height - if self.fullscreen { 1 } else { 0 };
         queue!(
             self.dest,
             cursor::MoveToPreviousLine(lines_to_rewind as _),
@@ -160,15 +184,44 @@ impl TerminalImpl for StdTerminal {
     }
 
     fn write_canvas(&mut self, canvas: &Canvas) -> io::Result<()> {
-        self.prev_canvas_height = canvas.height() as _;
-        if self.fullscreen {
-            canvas.write_ansi_without_final_newline(self)?;
-        } else {
-            canvas.write_ansi(self)?;
+        let is_resized = mem::take(&mut self.is_resized);
+        if self
+            .prev_canvas
+            .as_ref()
+            .is_some_and(|prev_canvas| is_resized || prev_canvas.width() != canvas.width())
+        {
+            // The terminal may have reflowed the previous canvas, so a diff would move the cursor
+            // to the wrong rows. Clear it and write the new canvas in full instead.
+            self.clear_canvas()?;
+        }
+        match self.prev_canvas.take() {
+            // If the previous canvas is still on screen, only write what has changed.
+            Some(prev_canvas) => canvas.write_ansi_diff(
+                &mut self.dest,
+                &prev_canvas,
+                self.fullscreen,
+                self.color_depth,
+            )?,
+            None if self.fullscreen => {
+                canvas.write_ansi_without_final_newline(&mut self.dest, self.color_depth)?
+            }
+            None => canvas.write_ansi_with_color_depth(&mut self.dest, self.color_depth)?,
         }
+        self.prev_canvas = Some(canvas.clone());
         Ok(())
     }
 
+    fn write_static_canvas(&mut self, canvas: &Canvas) -> io::Result<()> {
+        // Static output must be written below anything already in the scrollback, so the current
+        // canvas needs to be cleared first. The next canvas will then be written in full below it.
+        self.clear_canvas()?;
+        canvas.write_ansi_with_color_depth(&mut self.dest, self.color_depth)
+    }
+
+    fn handle_resize(&mut self) {
+        self.is_resized = true;
+    }
+
     fn event_stream(&mut self) -> io::Result<BoxStream<'static, TerminalEvent>> {
         if !self.input_is_terminal {
             return Ok(stream::pending().boxed());
@@ -201,11 +254,18 @@ impl TerminalImpl for StdTerminal {
 }
 
 impl StdTerminal {
-    fn new(fullscreen: bool) -> io::Result<Self>
+    fn new(fullscreen: bool, color_depth: ColorDepth) -> io::Result<Self>
     where
         Self: Sized,
     {
-        let mut dest = stdout();
+        Self::with_dest(Box::new(stdout()), fullscreen, color_depth)
+    }
+
+    fn with_dest(
+        mut dest: Box<dyn Write + Send>,
+        fullscreen: bool,
+        color_depth: ColorDepth,
+    ) -> io::Result<Self> {
         queue!(dest, cursor::Hide)?;
         if fullscreen {
             queue!(dest, terminal::EnterAlternateScreen)?;
@@ -216,7 +276,9 @@ impl StdTerminal {
             fullscreen,
             raw_mode_enabled: false,
             enabled_keyboard_enhancement: false,
-            prev_canvas_height: 0,
+            prev_canvas: None,
+            is_resized: false,
+            color_depth,
         })
     }
 
@@ -280,13 +342,34 @@ impl Stream for MockTerminalOutputStream {
 pub struct MockTerminalConfig {
     /// The events to be emitted by the mock terminal.
     pub events: BoxStream<'static, TerminalEvent>,
+
+    /// The initial width of the mock terminal, in columns. If `None`, the terminal is treated as
+    /// having unlimited width.
+    pub width: Option<u16>,
+
+    /// The initial height of the mock terminal, in rows. If `None`, the terminal is treated as
+    /// having unlimited height.
+    pub height: Option<u16>,
 }
 
 impl MockTerminalConfig {
     /// Creates a new `MockTerminalConfig` with the given event stream.
+    ///
+    /// Any [`TerminalEvent::Resize`] events in the stream will update the size of the mock
+    /// terminal.
     pub fn with_events<T: Stream<Item = TerminalEvent> + Send + 'static>(events: T) -> Self {
         Self {
             events: events.boxed(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the initial size of the mock terminal.
+    pub fn with_size(self, width: u16, height: u16) -> Self {
+        Self {
+            width: Some(width),
+            height: Some(height),
+            ..self
         }
     }
 }
@@ -295,12 +378,15 @@ impl Default for MockTerminalConfig {
     fn default() -> Self {
         Self {
             events: stream::pending().boxed(),
+            width: None,
+            height: None,
         }
     }
 }
 
 struct MockTerminal {
     config: MockTerminalConfig,
+    size: Arc<Mutex<(Option<u16>, Option<u16>)>>,
     output: mpsc::UnboundedSender<Canvas>,
 }
 
@@ -310,6 +396,7 @@ impl MockTerminal {
         let output = MockTerminalOutputStream { inner: output_rx };
         (
             Self {
+                size: Arc::new(Mutex::new((config.width, config.height))),
                 config,
                 output: output_tx,
             },
@@ -330,7 +417,15 @@ impl Write for MockTerminal {
 
 impl TerminalImpl for MockTerminal {
     fn width(&self) -> Option<u16> {
-        None
+        self.size.lock().unwrap().0
+    }
+
+    fn height(&self) -> Option<u16> {
+        self.size.lock().unwrap().1
+    }
+
+    fn is_fullscreen(&self) -> bool {
+        false
     }
 
     fn is_raw_mode_enabled(&self) -> bool {
@@ -346,10 +441,22 @@ impl TerminalImpl for MockTerminal {
         Ok(())
     }
 
+    fn write_static_canvas(&mut self, canvas: &Canvas) -> io::Result<()> {
+        self.write_canvas(canvas)
+    }
+
     fn event_stream(&mut self) -> io::Result<BoxStream<'static, TerminalEvent>> {
         let mut events = stream::pending().boxed();
         mem::swap(&mut events, &mut self.config.events);
-        Ok(events.chain(stream::pending()).boxed())
+        let size = self.size.clone();
+        Ok(events
+            .inspect(move |event| {
+                if let TerminalEvent::Resize(width, height) = event {
+                    *size.lock().unwrap() = (Some(*width), Some(*height));
+                }
+            })
+            .chain(stream::pending())
+            .boxed())
     }
 }
 
@@ -357,16 +464,17 @@ pub(crate) struct Terminal {
     inner: Box<dyn TerminalImpl>,
     event_stream: Option<BoxStream<'static, TerminalEvent>>,
     subscribers: Vec<Weak<Mutex<TerminalEventsInner>>>,
+    ignore_ctrl_c: bool,
     received_ctrl_c: bool,
 }
 
 impl Terminal {
-    pub fn new() -> io::Result<Self> {
-        Ok(Self::new_with_impl(StdTerminal::new(false)?))
+    pub fn new(color_depth: ColorDepth) -> io::Result<Self> {
+        Ok(Self::new_with_impl(StdTerminal::new(false, color_depth)?))
     }
 
-    pub fn fullscreen() -> io::Result<Self> {
-        Ok(Self::new_with_impl(StdTerminal::new(true)?))
+    pub fn fullscreen(color_depth: ColorDepth) -> io::Result<Self> {
+        Ok(Self::new_with_impl(StdTerminal::new(true, color_depth)?))
     }
 
     pub fn mock(config: MockTerminalConfig) -> (Self, MockTerminalOutputStream) {
@@ -379,6 +487,7 @@ impl Terminal {
             inner: Box::new(inner),
             event_stream: None,
             subscribers: Vec::new(),
+            ignore_ctrl_c: false,
             received_ctrl_c: false,
         }
     }
@@ -391,6 +500,23 @@ impl Terminal {
         self.inner.width()
     }
 
+    pub fn size(&self) -> Option<(u16, u16)> {
+        self.inner.width().zip(self.inner.height())
+    }
+
+    /// Returns the maximum height of a canvas that can be written without any of it scrolling out
+    /// of view, if known.
+    pub fn max_canvas_height(&self) -> Option<usize> {
+        self.inner.height().map(|h| {
+            if self.inner.is_fullscreen() {
+                h as usize
+            } else {
+                // Inline canvases are followed by a newline, which takes up a row.
+                (h as usize).saturating_sub(1)
+            }
+        })
+    }
+
     pub fn clear_canvas(&mut self) -> io::Result<()> {
         self.inner.clear_canvas()
     }
@@ -399,6 +525,15 @@ impl Terminal {
         self.inner.write_canvas(canvas)
     }
 
+    pub fn write_static_canvas(&mut self, canvas: &Canvas) -> io::Result<()> {
+        self.inner.write_static_canvas(canvas)
+    }
+
+    /// Delivers Ctrl-C to subscribers like any other key instead of ending the render loop.
+    pub fn ignore_ctrl_c(&mut self) {
+        self.ignore_ctrl_c = true;
+    }
+
     pub fn received_ctrl_c(&self) -> bool {
         self.received_ctrl_c
     }
@@ -413,11 +548,12 @@ impl Terminal {
                         modifiers: KeyModifiers::CONTROL,
                     }) = event
                     {
-                        self.received_ctrl_c = true;
+                        self.received_ctrl_c = !self.ignore_ctrl_c;
                     }
                     if self.received_ctrl_c {
                         return;
                     }
+                    let is_resize = matches!(event, TerminalEvent::Resize(..));
                     self.subscribers.retain(|subscriber| {
                         if let Some(subscriber) = subscriber.upgrade() {
                             let mut subscriber = subscriber.lock().unwrap();
@@ -430,6 +566,11 @@ impl Terminal {
                             false
                         }
                     });
+                    if is_resize {
+                        self.inner.handle_resize();
+                        // The layout depends on the terminal size, so we need to render again.
+                        return;
+                    }
                 }
             }
             None => pending().await,
@@ -461,17 +602,88 @@ impl Write for Terminal {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
     use crate::prelude::*;
 
+    #[derive(Clone, Default)]
+    struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl SharedBuffer {
+        fn take_string(&self) -> String {
+            String::from_utf8(mem::take(&mut *self.0.lock().unwrap())).unwrap()
+        }
+    }
+
+    impl Write for SharedBuffer {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn text_canvas(width: usize, text: &str) -> Canvas {
+        let mut canvas = Canvas::new(width, 1);
+        canvas
+            .subview_mut(0, 0, 0, 0, width, 1)
+            .set_text(0, 0, text, CanvasTextStyle::default());
+        canvas
+    }
+
+    fn full_write(canvas: &Canvas) -> String {
+        let mut buf = Vec::new();
+        canvas
+            .write_ansi_with_color_depth(&mut buf, ColorDepth::TrueColor)
+            .unwrap();
+        String::from_utf8(buf).unwrap()
+    }
+
     #[test]
     fn test_std_terminal() {
         // There's unfortunately not much here we can really test, but we'll do our best.
         // TODO: Is there a library we can use to emulate terminal input/output?
-        let mut terminal = Terminal::new().unwrap();
+        let mut terminal = Terminal::new(ColorDepth::TrueColor).unwrap();
         assert!(!terminal.is_raw_mode_enabled());
         assert!(!terminal.received_ctrl_c());
         assert!(!terminal.is_raw_mode_enabled());
         let canvas = Canvas::new(10, 1);
         terminal.write_canvas(&canvas).unwrap();
+        terminal.write_static_canvas(&canvas).unwrap();
+        terminal.write_canvas(&canvas).unwrap();
+    }
+
+    #[test]
+    fn test_std_terminal_redraws_after_resize() {
+        let buffer = SharedBuffer::default();
+        let mut terminal =
+            StdTerminal::with_dest(Box::new(buffer.clone()), false, ColorDepth::TrueColor).unwrap();
+        terminal.write_canvas(&text_canvas(10, "foo")).unwrap();
+        buffer.take_string();
+
+        // Unchanged dimensions only need a diff.
+        let canvas = text_canvas(10, "bar");
+        terminal.write_canvas(&canvas).unwrap();
+        let output = buffer.take_string();
+        assert!(!output.contains("\x1b[J"));
+        assert_ne!(output, full_write(&canvas));
+
+        // A width change clears the previous canvas and writes the new one in full.
+        let canvas = text_canvas(8, "bar");
+        terminal.write_canvas(&canvas).unwrap();
+        assert_eq!(
+            buffer.take_string(),
+            format!("\x1b[1F\x1b[J{}", full_write(&canvas))
+        );
+
+        // So does a resize event, even if the canvas width stays the same.
+        terminal.handle_resize();
+        let canvas = text_canvas(8, "baz");
+        terminal.write_canvas(&canvas).unwrap();
+        assert_eq!(
+            buffer.take_string(),
+            format!("\x1b[1F\x1b[J{}", full_write(&canvas))
+        );
     }
 }
@@ -0,0 +1,109 @@
+use crate::{prelude::*, render::mock_terminal_render_loop_with_terminal};
+use futures::{
+    channel::mpsc,
+    stream::{self, StreamExt},
+};
+use std::sync::Arc;
+
+type RenderFn = Arc<dyn Fn() -> AnyElement<'static> + Send + Sync>;
+
+#[derive(Default, Props)]
+struct HarnessProps {
+    render: Option<RenderFn>,
+    event_count: usize,
+    on_render: Option<mpsc::UnboundedSender<usize>>,
+}
+
+/// Renders the element returned by `render` until the given number of terminal events has been
+/// handled.
+///
+/// The harness re-renders after every event, so like any parent component, it passes fresh props
+/// to its child each time.
+#[component]
+fn Harness(mut hooks: Hooks, props: &HarnessProps) -> impl Into<AnyElement<'static>> {
+    let mut system = hooks.use_context_mut::<SystemContext>();
+    let mut events = hooks.use_state(|| 0);
+    let mut is_done = hooks.use_state(|| false);
+
+    hooks.use_terminal_events(move |_| events += 1);
+
+    // Exit one render after the last event so that anything reacting to the latest layout, such
+    // as scroll offsets, has settled.
+    if is_done.get() {
+        system.exit();
+    } else if events == props.event_count {
+        is_done.set(true);
+    }
+
+    if let Some(on_render) = &props.on_render {
+        let _ = on_render.unbounded_send(events.get());
+    }
+
+    match &props.render {
+        Some(render) => render(),
+        None => element!(Fragment).into_any(),
+    }
+}
+
+/// Renders the element returned by `render` in a mock terminal which emits the given events,
+/// returning every canvas that was written.
+pub(crate) async fn render_with_events<F>(render: F, events: Vec<TerminalEvent>) -> Vec<Canvas>
+where
+    F: Fn() -> AnyElement<'static> + Send + Sync + 'static,
+{
+    render_with_event_batches(render, vec![events]).await
+}
+
+/// Like [`render_with_events`], but each batch of events is only emitted once the events before it
+/// have been handled and the element has been rendered again.
+pub(crate) async fn render_with_event_batches<F>(
+    render: F,
+    batches: Vec<Vec<TerminalEvent>>,
+) -> Vec<Canvas>
+where
+    F: Fn() -> AnyElement<'static> + Send + Sync + 'static,
+{
+    let event_count: usize = batches.iter().map(Vec::len).sum();
+    let (on_render, renders) = mpsc::unbounded();
+    let events = stream::unfold(
+        (batches.into_iter(), renders, 0),
+        |(mut batches, mut renders, mut emitted)| async move {
+            let batch = batches.next()?;
+            // Wait for a render which follows the handling of every event emitted so far.
+            while renders.next().await? < emitted {}
+            emitted += batch.len();
+            Some((stream::iter(batch), (batches, renders, emitted)))
+        },
+    )
+    .flatten();
+    element!(Harness(
+        render: Arc::new(render) as RenderFn,
+        event_count,
+        on_render,
+    ))
+    .mock_terminal_render_loop(MockTerminalConfig::with_events(events))
+    .collect()
+    .await
+}
+
+/// Renders the element in a mock terminal which emits the given events, returning every canvas that
+/// was written.
+///
+/// Unlike [`render_with_events`], the element is responsible for exiting. Ctrl-C is delivered to it
+/// like any other key, as it is for prompts.
+pub(crate) async fn render_element_with_events<E: ElementExt>(
+    element: E,
+    events: Vec<TerminalEvent>,
+) -> Vec<Canvas> {
+    let (mut terminal, output) =
+        Terminal::mock(MockTerminalConfig::with_events(stream::iter(events)));
+    terminal.ignore_ctrl_c();
+    mock_terminal_render_loop_with_terminal(element, terminal, output)
+        .collect()
+        .await
+}
+
+/// Returns a key press event for the given key code.
+pub(crate) fn key(code: KeyCode) -> TerminalEvent {
+    TerminalEvent::Key(KeyEvent::new(KeyEventKind::Press, code))
+}